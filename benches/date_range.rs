@@ -0,0 +1,52 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use criterion::{criterion_group, criterion_main, Criterion};
+use date_range::daterange::monthly_date_range::MonthlyDateRange;
+use date_range::daterange::weekly_date_range::WeeklyDateRange;
+use date_range::DateRange;
+use std::hint::black_box;
+
+fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+}
+
+fn membership(c: &mut Criterion) {
+    let year = DateRange::new(d(2024, 1, 1), d(2024, 12, 31));
+    let dates: Vec<NaiveDate> = (0..400).map(|offset| d(2023, 12, 15) + Duration::days(offset)).collect();
+    c.bench_function("contains_date", |b| {
+        b.iter(|| dates.iter().filter(|date| black_box(&year).contains_date(**date)).count())
+    });
+    let ranges: Vec<DateRange> =
+        dates.iter().map(|date| WeeklyDateRange::with_start_date(*date)).collect();
+    c.bench_function("overlaps", |b| {
+        b.iter(|| ranges.iter().filter(|range| black_box(&year).overlaps(range)).count())
+    });
+}
+
+fn dates(c: &mut Criterion) {
+    let decade = DateRange::new(d(2020, 1, 1), d(2029, 12, 31));
+    c.bench_function("dates", |b| b.iter(|| black_box(&decade).dates()));
+    c.bench_function("date_at", |b| b.iter(|| black_box(&decade).date_at(black_box(3_000))));
+}
+
+fn range_containing_date(c: &mut Criterion) {
+    let week = WeeklyDateRange::with_start_date(d(2024, 1, 1));
+    let fixed = DateRange::new(d(2024, 1, 1), d(2024, 1, 10));
+    let month = MonthlyDateRange::with_end_date_on_first(d(2024, 1, 31));
+    let target = d(2034, 6, 15);
+    let mut group = c.benchmark_group("range_containing_date");
+    group.bench_function("weekly", |b| b.iter(|| black_box(&week).range_containing_date(target)));
+    group.bench_function("fixed", |b| b.iter(|| black_box(&fixed).range_containing_date(target)));
+    group.bench_function("monthly", |b| b.iter(|| black_box(&month).range_containing_date(target)));
+    group.finish();
+}
+
+fn iteration(c: &mut Criterion) {
+    let decade = DateRange::new(d(2020, 1, 1), d(2029, 12, 31));
+    let mut group = c.benchmark_group("iter");
+    group.bench_function("count", |b| b.iter(|| black_box(&decade).iter().count()));
+    group.bench_function("rev", |b| b.iter(|| black_box(&decade).iter().rev().fold(0, |sum, date| sum ^ date.ordinal())));
+    group.finish();
+}
+
+criterion_group!(benches, membership, dates, range_containing_date, iteration);
+criterion_main!(benches);
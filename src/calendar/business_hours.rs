@@ -0,0 +1,92 @@
+use crate::datetimerange::date_time_range::DateTimeRange;
+use crate::timerange::time_range::TimeRange;
+use chrono::{Datelike, NaiveDate, NaiveTime, Weekday};
+
+/// The opening hours for each day of the week.
+///
+/// Hours that end before they start (or at midnight) close on the following day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BusinessHours {
+    hours: [Option<TimeRange>; 7],
+}
+
+impl BusinessHours {
+    /// Create business hours that are closed every day.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create business hours open Monday through Friday between the given times.
+    pub fn weekdays(open: NaiveTime, close: NaiveTime) -> Self {
+        [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri]
+            .into_iter()
+            .fold(Self::new(), |hours, day| hours.with_hours(day, TimeRange::of(open, close)))
+    }
+
+    /// Set the opening hours for a day of the week.
+    pub fn with_hours(mut self, day: Weekday, hours: TimeRange) -> Self {
+        self.hours[day.num_days_from_monday() as usize] = Some(hours);
+        self
+    }
+
+    /// Mark a day of the week as closed.
+    pub fn closed_on(mut self, day: Weekday) -> Self {
+        self.hours[day.num_days_from_monday() as usize] = None;
+        self
+    }
+
+    /// Get the opening hours for a day of the week, if open.
+    pub fn hours_for(&self, day: Weekday) -> Option<&TimeRange> {
+        self.hours[day.num_days_from_monday() as usize].as_ref()
+    }
+
+    /// Get the window of time the business is open that starts on the given date.
+    pub fn open_window(&self, date: NaiveDate) -> Option<DateTimeRange> {
+        self.hours_for(date.weekday())
+            .map(|hours| DateTimeRange::from_time_range_on_date(hours.start(), hours.end(), date))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BusinessHours;
+    use crate::timerange::time_range::TimeRange;
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+    fn t(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).expect("invalid time")
+    }
+    fn dt(y: i32, m: u32, day: u32, h: u32, mi: u32) -> NaiveDateTime {
+        d(y, m, day).and_time(t(h, mi))
+    }
+
+    #[test]
+    fn weekdays_are_open_and_weekend_is_closed() {
+        let hours = BusinessHours::weekdays(t(9, 0), t(17, 0));
+        assert_eq!(hours.hours_for(Weekday::Mon), Some(&TimeRange::of(t(9, 0), t(17, 0))));
+        assert_eq!(hours.hours_for(Weekday::Sat), None);
+
+        let window = hours.open_window(d(2023, 6, 5)).unwrap(); // Monday
+        assert_eq!(window.start(), dt(2023, 6, 5, 9, 0));
+        assert_eq!(window.end(), dt(2023, 6, 5, 17, 0));
+        assert!(hours.open_window(d(2023, 6, 4)).is_none()); // Sunday
+    }
+
+    #[test]
+    fn overnight_and_midnight_closing_hours_end_next_day() {
+        let hours = BusinessHours::new()
+            .with_hours(Weekday::Fri, TimeRange::of(t(22, 0), t(6, 0)))
+            .with_hours(Weekday::Sat, TimeRange::of(t(18, 0), t(0, 0)));
+
+        let fri = hours.open_window(d(2023, 6, 9)).unwrap();
+        assert_eq!(fri.end(), dt(2023, 6, 10, 6, 0));
+        let sat = hours.open_window(d(2023, 6, 10)).unwrap();
+        assert_eq!(sat.end(), dt(2023, 6, 11, 0, 0));
+
+        let closed = hours.closed_on(Weekday::Fri);
+        assert!(closed.open_window(d(2023, 6, 9)).is_none());
+    }
+}
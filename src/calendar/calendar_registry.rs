@@ -0,0 +1,191 @@
+//! A process-wide registry of holiday calendars and business hours looked up by name.
+//!
+//! Requires the `std` feature, as do the `business_days_named`, `business_day_count_named` and
+//! `working_duration_named` helpers defined here.
+
+use crate::calendar::business_hours::BusinessHours;
+use crate::calendar::holiday_calendar::HolidayCalendar;
+use crate::calendar::working_time::working_duration_between;
+use crate::daterange::date_range::DateRange;
+use crate::datetimerange::date_time_range::DateTimeRange;
+use chrono::{Duration, NaiveDate};
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+/// Holiday calendars and business hours registered by name, such as `"US-NY"`, so they can be
+/// configured in one place and looked up wherever they are needed.
+///
+/// The registry can be shared between threads. Lookups hand out an `Arc` of what was registered,
+/// so registering under the same name later replaces it for new lookups only.
+#[derive(Debug, Default)]
+pub struct CalendarRegistry {
+    calendars: RwLock<BTreeMap<String, Arc<HolidayCalendar>>>,
+    business_hours: RwLock<BTreeMap<String, Arc<BusinessHours>>>,
+}
+
+impl CalendarRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the registry shared by the whole process.
+    pub fn global() -> &'static CalendarRegistry {
+        static GLOBAL: OnceLock<CalendarRegistry> = OnceLock::new();
+        GLOBAL.get_or_init(CalendarRegistry::new)
+    }
+
+    /// Register a holiday calendar, returning the one it replaced.
+    pub fn register_calendar(&self, name: &str, calendar: HolidayCalendar) -> Option<Arc<HolidayCalendar>> {
+        insert(&self.calendars, name, calendar)
+    }
+
+    /// Get the holiday calendar registered under a name.
+    pub fn calendar(&self, name: &str) -> Option<Arc<HolidayCalendar>> {
+        get(&self.calendars, name)
+    }
+
+    /// Remove the holiday calendar registered under a name.
+    pub fn unregister_calendar(&self, name: &str) -> Option<Arc<HolidayCalendar>> {
+        remove(&self.calendars, name)
+    }
+
+    /// Get the names of the registered holiday calendars in order.
+    pub fn calendar_names(&self) -> Vec<String> {
+        names(&self.calendars)
+    }
+
+    /// Register business hours, returning the ones they replaced.
+    pub fn register_business_hours(&self, name: &str, hours: BusinessHours) -> Option<Arc<BusinessHours>> {
+        insert(&self.business_hours, name, hours)
+    }
+
+    /// Get the business hours registered under a name.
+    pub fn business_hours(&self, name: &str) -> Option<Arc<BusinessHours>> {
+        get(&self.business_hours, name)
+    }
+
+    /// Remove the business hours registered under a name.
+    pub fn unregister_business_hours(&self, name: &str) -> Option<Arc<BusinessHours>> {
+        remove(&self.business_hours, name)
+    }
+
+    /// Get the names of the registered business hours in order.
+    pub fn business_hours_names(&self) -> Vec<String> {
+        names(&self.business_hours)
+    }
+}
+
+impl DateRange {
+    /// Get the business days in the range for a calendar in the global registry. None if no
+    /// calendar is registered under the name.
+    pub fn business_days_named(&self, calendar: &str) -> Option<Vec<NaiveDate>> {
+        let calendar = CalendarRegistry::global().calendar(calendar)?;
+        Some(self.iter().filter(|date| calendar.is_business_day(*date)).collect())
+    }
+
+    /// Get the number of business days in the range for a calendar in the global registry. None
+    /// if no calendar is registered under the name.
+    pub fn business_day_count_named(&self, calendar: &str) -> Option<usize> {
+        let calendar = CalendarRegistry::global().calendar(calendar)?;
+        Some(self.iter().filter(|date| calendar.is_business_day(*date)).count())
+    }
+}
+
+impl DateTimeRange {
+    /// Get the amount of the range within business hours, skipping holidays, using business hours
+    /// and a calendar from the global registry. None if either name is not registered.
+    pub fn working_duration_named(&self, hours: &str, calendar: &str) -> Option<Duration> {
+        let registry = CalendarRegistry::global();
+        let hours = registry.business_hours(hours)?;
+        let calendar = registry.calendar(calendar)?;
+        Some(working_duration_between(self.start(), self.end(), &hours, &calendar))
+    }
+}
+
+fn insert<T>(map: &RwLock<BTreeMap<String, Arc<T>>>, name: &str, value: T) -> Option<Arc<T>> {
+    map.write().unwrap_or_else(PoisonError::into_inner).insert(String::from(name), Arc::new(value))
+}
+
+fn get<T>(map: &RwLock<BTreeMap<String, Arc<T>>>, name: &str) -> Option<Arc<T>> {
+    map.read().unwrap_or_else(PoisonError::into_inner).get(name).cloned()
+}
+
+fn remove<T>(map: &RwLock<BTreeMap<String, Arc<T>>>, name: &str) -> Option<Arc<T>> {
+    map.write().unwrap_or_else(PoisonError::into_inner).remove(name)
+}
+
+fn names<T>(map: &RwLock<BTreeMap<String, Arc<T>>>) -> Vec<String> {
+    map.read().unwrap_or_else(PoisonError::into_inner).keys().cloned().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CalendarRegistry;
+    use crate::calendar::business_hours::BusinessHours;
+    use crate::calendar::holiday_calendar::HolidayCalendar;
+    use crate::daterange::date_range::DateRange;
+    use crate::datetimerange::date_time_range::DateTimeRange;
+    use chrono::{Duration, NaiveDate, NaiveTime};
+    use std::thread;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn registers_and_replaces_by_name() {
+        assert_send_sync::<CalendarRegistry>();
+        let registry = CalendarRegistry::new();
+        assert!(registry.register_calendar("US", HolidayCalendar::new()).is_none());
+        let christmas = HolidayCalendar::new().with_holidays([d(2024, 12, 25)]);
+        assert_eq!(registry.register_calendar("US", christmas.clone()).as_deref(), Some(&HolidayCalendar::new()));
+        assert_eq!(registry.calendar("US").as_deref(), Some(&christmas));
+        assert!(registry.calendar("GB").is_none());
+
+        let (nine, five) = (NaiveTime::from_hms_opt(9, 0, 0).unwrap(), NaiveTime::from_hms_opt(17, 0, 0).unwrap());
+        let hours = BusinessHours::weekdays(nine, five);
+        registry.register_business_hours("office", hours.clone());
+        assert_eq!(registry.business_hours("office").as_deref(), Some(&hours));
+        assert_eq!(registry.business_hours_names(), vec!["office"]);
+
+        registry.register_calendar("CA", HolidayCalendar::new());
+        assert_eq!(registry.calendar_names(), vec!["CA", "US"]);
+        assert!(registry.unregister_calendar("CA").is_some());
+        assert!(registry.unregister_business_hours("office").is_some());
+        assert!(registry.business_hours("office").is_none());
+    }
+
+    #[test]
+    fn range_helpers_use_the_global_registry() {
+        let registry = CalendarRegistry::global();
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                thread::spawn(move || {
+                    let name = format!("test-{}", i);
+                    CalendarRegistry::global()
+                        .register_calendar(&name, HolidayCalendar::new().with_holidays([d(2024, 12, 25)]));
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let december = DateRange::new(d(2024, 12, 1), d(2024, 12, 31));
+        assert_eq!(december.business_day_count_named("test-2"), Some(21));
+        assert_eq!(december.business_days_named("test-3").map(|days| days.len()), Some(21));
+        assert_eq!(december.business_day_count_named("missing"), None);
+
+        let (nine, five) = (NaiveTime::from_hms_opt(9, 0, 0).unwrap(), NaiveTime::from_hms_opt(17, 0, 0).unwrap());
+        registry.register_business_hours("test-office", BusinessHours::weekdays(nine, five));
+        let week = DateTimeRange::of(d(2024, 12, 23).and_time(nine), d(2024, 12, 28).and_time(nine));
+        assert_eq!(week.working_duration_named("test-office", "test-0"), Some(Duration::hours(32)));
+        assert_eq!(week.working_duration_named("test-office", "missing"), None);
+        assert_eq!(week.working_duration_named("missing", "test-0"), None);
+    }
+}
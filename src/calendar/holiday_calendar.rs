@@ -0,0 +1,113 @@
+use crate::daterange::date_range::DateRange;
+use chrono::{Datelike, NaiveDate, Weekday};
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A set of holidays plus the days of the week that are treated as the weekend.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HolidayCalendar {
+    holidays: BTreeSet<NaiveDate>,
+    weekend: Vec<Weekday>,
+}
+
+impl HolidayCalendar {
+    /// Create a calendar with no holidays and a Saturday/Sunday weekend.
+    pub fn new() -> Self {
+        Self {
+            holidays: BTreeSet::new(),
+            weekend: vec![Weekday::Sat, Weekday::Sun],
+        }
+    }
+
+    /// Replace the days of the week that are treated as the weekend.
+    pub fn with_weekend(mut self, weekend: &[Weekday]) -> Self {
+        self.weekend = weekend.to_vec();
+        self
+    }
+
+    /// Add holidays to the calendar.
+    pub fn with_holidays<I: IntoIterator<Item = NaiveDate>>(mut self, holidays: I) -> Self {
+        self.holidays.extend(holidays);
+        self
+    }
+
+    /// Add a holiday to the calendar.
+    pub fn add_holiday(&mut self, date: NaiveDate) {
+        self.holidays.insert(date);
+    }
+
+    /// Check if a date is a holiday.
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays.contains(&date)
+    }
+
+    /// Check if a date falls on the weekend.
+    pub fn is_weekend(&self, date: NaiveDate) -> bool {
+        self.weekend.contains(&date.weekday())
+    }
+
+    /// Check if any day of the week is outside the weekend.
+    pub fn has_business_weekdays(&self) -> bool {
+        [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun]
+            .iter()
+            .any(|day| !self.weekend.contains(day))
+    }
+
+    /// Check if a date is neither a holiday nor on the weekend.
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !self.is_holiday(date) && !self.is_weekend(date)
+    }
+
+    /// Get the holidays that fall within a date range.
+    pub fn holidays_in(&self, date_range: &DateRange) -> Vec<NaiveDate> {
+        self.holidays
+            .range(date_range.start_date()..=date_range.end_date())
+            .copied()
+            .collect()
+    }
+}
+
+impl Default for HolidayCalendar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HolidayCalendar;
+    use crate::daterange::date_range::DateRange;
+    use chrono::{NaiveDate, Weekday};
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn business_days_exclude_weekends_and_holidays() {
+        let calendar = HolidayCalendar::new().with_holidays([d(2023, 12, 25)]);
+        assert!(calendar.is_business_day(d(2023, 12, 22))); // Friday
+        assert!(!calendar.is_business_day(d(2023, 12, 23))); // Saturday
+        assert!(!calendar.is_business_day(d(2023, 12, 25))); // Christmas, Monday
+        assert!(calendar.is_holiday(d(2023, 12, 25)));
+        assert!(!calendar.is_weekend(d(2023, 12, 25)));
+    }
+
+    #[test]
+    fn custom_weekend_replaces_default() {
+        let calendar = HolidayCalendar::new().with_weekend(&[Weekday::Fri, Weekday::Sat]);
+        assert!(!calendar.is_business_day(d(2023, 12, 22))); // Friday
+        assert!(calendar.is_business_day(d(2023, 12, 24))); // Sunday
+    }
+
+    #[test]
+    fn holidays_in_returns_sorted_holidays_inside_range() {
+        let mut calendar = HolidayCalendar::new();
+        calendar.add_holiday(d(2024, 1, 1));
+        calendar.add_holiday(d(2023, 12, 25));
+        calendar.add_holiday(d(2023, 11, 23));
+        let december = DateRange::new(d(2023, 12, 1), d(2024, 1, 1));
+        assert_eq!(calendar.holidays_in(&december), vec![d(2023, 12, 25), d(2024, 1, 1)]);
+    }
+}
@@ -0,0 +1,8 @@
+pub mod business_hours;
+#[cfg(feature = "std")]
+pub mod calendar_registry;
+pub mod holiday_calendar;
+pub mod period_lock;
+pub mod premium_windows;
+pub mod rotation_pattern;
+pub mod working_time;
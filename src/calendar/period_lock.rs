@@ -0,0 +1,56 @@
+use crate::calendar::holiday_calendar::HolidayCalendar;
+use crate::calendar::working_time::add_working_days;
+use crate::daterange::date_range::DateRange;
+use chrono::NaiveDate;
+
+impl DateRange {
+    /// Get the last date on which the period can still be edited, `lock_after_days` business
+    /// days after it ends. With zero days the period locks the day after it ends. None if the
+    /// calendar has no business days of the week or the date is past the supported calendar.
+    pub fn lock_date(&self, lock_after_days: u32, calendar: &HolidayCalendar) -> Option<NaiveDate> {
+        add_working_days(self.end_date(), lock_after_days as i64, calendar)
+    }
+
+    /// Check if the period is locked for editing as of a date. A period without a lock date
+    /// never locks.
+    pub fn is_locked(&self, as_of: NaiveDate, lock_after_days: u32, calendar: &HolidayCalendar) -> bool {
+        self.lock_date(lock_after_days, calendar).is_some_and(|lock_date| as_of > lock_date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::calendar::holiday_calendar::HolidayCalendar;
+    use crate::daterange::date_range::DateRange;
+    use chrono::{NaiveDate, Weekday};
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn period_locks_after_business_days() {
+        // The week ends on Friday 2024-03-29; Monday 2024-04-01 is a holiday.
+        let week = DateRange::new(d(2024, 3, 23), d(2024, 3, 29));
+        let calendar = HolidayCalendar::new().with_holidays([d(2024, 4, 1)]);
+
+        assert_eq!(week.lock_date(2, &calendar), Some(d(2024, 4, 3)));
+        assert!(!week.is_locked(d(2024, 4, 3), 2, &calendar));
+        assert!(week.is_locked(d(2024, 4, 4), 2, &calendar));
+
+        assert_eq!(week.lock_date(0, &calendar), Some(d(2024, 3, 29)));
+        assert!(week.is_locked(d(2024, 3, 30), 0, &calendar));
+    }
+
+    #[test]
+    fn period_without_a_lock_date_never_locks() {
+        let week = DateRange::new(d(2024, 3, 23), d(2024, 3, 29));
+        let week_days = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun];
+        let closed = HolidayCalendar::new().with_weekend(&week_days);
+        assert_eq!(week.lock_date(2, &closed), None);
+        assert!(!week.is_locked(d(2025, 1, 1), 2, &closed));
+
+        let last = DateRange::new(NaiveDate::MAX, NaiveDate::MAX);
+        assert_eq!(last.lock_date(1, &HolidayCalendar::new().with_weekend(&[])), None);
+    }
+}
@@ -0,0 +1,147 @@
+use crate::calendar::holiday_calendar::HolidayCalendar;
+use crate::datetimerange::date_time_range::DateTimeRange;
+use crate::timerange::time_range::TimeRange;
+use chrono::{Duration, NaiveDate};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// A catalog of labelled premium windows used to classify worked time, such as night,
+/// weekend and holiday differentials.
+///
+/// Time windows recur every day and close on the following day when they end before they
+/// start. Weekend and holiday premiums cover whole days of the calendar. A stretch of time
+/// can fall in several windows and counts towards each of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PremiumWindows {
+    windows: Vec<(String, TimeRange)>,
+    weekend_label: Option<String>,
+    holiday_label: Option<String>,
+    calendar: HolidayCalendar,
+}
+
+impl PremiumWindows {
+    /// Create a catalog with no premium windows.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a daily recurring window.
+    pub fn with_window(mut self, label: &str, hours: TimeRange) -> Self {
+        self.windows.push((label.to_string(), hours));
+        self
+    }
+
+    /// Label time worked on the calendar's weekend days.
+    pub fn with_weekend(mut self, label: &str) -> Self {
+        self.weekend_label = Some(label.to_string());
+        self
+    }
+
+    /// Label time worked on the calendar's holidays.
+    pub fn with_holidays(mut self, label: &str) -> Self {
+        self.holiday_label = Some(label.to_string());
+        self
+    }
+
+    /// Replace the calendar that decides which days are weekends and holidays.
+    pub fn with_calendar(mut self, calendar: HolidayCalendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// Get the time of a range that falls in each premium window, in the order they were
+    /// added followed by weekend and holiday time. Windows the range doesn't touch are left out.
+    pub fn classify(&self, range: &DateTimeRange) -> Vec<(&str, Duration)> {
+        // Start a day early so windows that open the previous evening are included.
+        let first = range.start().date().pred_opt().unwrap_or(range.start().date());
+        let dates = || first.iter_days().take_while(|date| *date <= range.end().date());
+        let whole_days = |pred: &dyn Fn(NaiveDate) -> bool| -> Duration {
+            dates()
+                .filter(|date| pred(*date))
+                .map(|date| range.overlap_duration(&DateTimeRange::all_day(date)))
+                .sum()
+        };
+
+        let mut result: Vec<(&str, Duration)> = self
+            .windows
+            .iter()
+            .map(|(label, hours)| {
+                let total = dates()
+                    .map(|date| {
+                        let window = DateTimeRange::from_time_range_on_date(hours.start(), hours.end(), date);
+                        range.overlap_duration(&window)
+                    })
+                    .sum();
+                (label.as_str(), total)
+            })
+            .collect();
+        if let Some(label) = &self.weekend_label {
+            result.push((label, whole_days(&|date| self.calendar.is_weekend(date))));
+        }
+        if let Some(label) = &self.holiday_label {
+            result.push((label, whole_days(&|date| self.calendar.is_holiday(date))));
+        }
+
+        result.retain(|(_, duration)| *duration > Duration::zero());
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PremiumWindows;
+    use crate::calendar::holiday_calendar::HolidayCalendar;
+    use crate::datetimerange::date_time_range::DateTimeRange;
+    use crate::timerange::time_range::TimeRange;
+    use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+    fn t(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).expect("invalid time")
+    }
+    fn dt(y: i32, m: u32, day: u32, h: u32, mi: u32) -> NaiveDateTime {
+        d(y, m, day).and_time(t(h, mi))
+    }
+
+    fn catalog() -> PremiumWindows {
+        PremiumWindows::new()
+            .with_window("night", TimeRange::of(t(22, 0), t(6, 0)))
+            .with_window("evening", TimeRange::of(t(18, 0), t(22, 0)))
+            .with_weekend("weekend")
+            .with_holidays("holiday")
+            .with_calendar(HolidayCalendar::new().with_holidays([d(2024, 7, 4)]))
+    }
+
+    #[test]
+    fn classify_splits_overnight_shift_into_windows() {
+        // Friday 20:00 to Saturday 04:00.
+        let shift = DateTimeRange::of(dt(2024, 3, 1, 20, 0), dt(2024, 3, 2, 4, 0));
+        assert_eq!(
+            catalog().classify(&shift),
+            vec![("night", Duration::hours(6)), ("evening", Duration::hours(2)), ("weekend", Duration::hours(4))]
+        );
+    }
+
+    #[test]
+    fn classify_at_the_first_date() {
+        let windows = PremiumWindows::new()
+            .with_window("night", TimeRange::of(t(22, 0), t(6, 0)))
+            .with_window("evening", TimeRange::of(t(18, 0), t(22, 0)));
+        let shift = DateTimeRange::of(NaiveDate::MIN.and_time(t(21, 0)), NaiveDate::MIN.and_time(t(23, 0)));
+        assert_eq!(windows.classify(&shift), vec![("night", Duration::hours(1)), ("evening", Duration::hours(1))]);
+    }
+
+    #[test]
+    fn classify_includes_windows_opened_the_previous_evening() {
+        let shift = DateTimeRange::of(dt(2024, 7, 4, 4, 0), dt(2024, 7, 4, 12, 0));
+        assert_eq!(
+            catalog().classify(&shift),
+            vec![("night", Duration::hours(2)), ("holiday", Duration::hours(8))]
+        );
+
+        let day_shift = DateTimeRange::of(dt(2024, 3, 5, 9, 0), dt(2024, 3, 5, 17, 0));
+        assert!(catalog().classify(&day_shift).is_empty());
+    }
+}
@@ -0,0 +1,151 @@
+use crate::daterange::date_range::DateRange;
+use crate::datetimerange::date_time_range::DateTimeRange;
+use crate::timerange::time_range::TimeRange;
+use chrono::{Duration, NaiveDate};
+use alloc::vec::Vec;
+use core::iter;
+
+/// A repeating cycle of working and rest days, such as 4-on-4-off or the DuPont schedule.
+///
+/// Each day of the cycle either has the hours of the shift starting that day or is a day off.
+/// The cycle starts on the anchor date and repeats in both directions, so crews working the
+/// same pattern are usually modelled with anchors a few days apart. Shifts that end before
+/// they start finish on the following day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RotationPattern {
+    days: Vec<Option<TimeRange>>,
+    anchor: NaiveDate,
+}
+
+impl RotationPattern {
+    /// Create a pattern from the shift hours of each day of the cycle, with `None` for days off.
+    pub fn new(days: Vec<Option<TimeRange>>, anchor: NaiveDate) -> Self {
+        assert!(!days.is_empty(), "a rotation pattern needs at least one day");
+        Self { days, anchor }
+    }
+
+    /// Create a pattern of `on` working days followed by `off` rest days, all with the same hours.
+    pub fn on_off(on: usize, off: usize, hours: TimeRange, anchor: NaiveDate) -> Self {
+        let days = iter::repeat_n(Some(hours), on).chain(iter::repeat_n(None, off)).collect();
+        Self::new(days, anchor)
+    }
+
+    /// Create the four-week DuPont pattern: 4 nights, 3 off, 3 days, 1 off, 3 nights, 3 off,
+    /// 4 days and 7 off.
+    pub fn dupont(day: TimeRange, night: TimeRange, anchor: NaiveDate) -> Self {
+        let blocks = [
+            (Some(&night), 4),
+            (None, 3),
+            (Some(&day), 3),
+            (None, 1),
+            (Some(&night), 3),
+            (None, 3),
+            (Some(&day), 4),
+            (None, 7),
+        ];
+        let days = blocks
+            .iter()
+            .flat_map(|(hours, count)| iter::repeat_n(hours.cloned(), *count))
+            .collect();
+        Self::new(days, anchor)
+    }
+
+    /// Get the same pattern starting `days` later, such as for another crew.
+    pub fn offset_by(&self, days: i64) -> Self {
+        Self::new(self.days.clone(), self.anchor + Duration::days(days))
+    }
+
+    /// Get the number of days in the cycle.
+    pub fn cycle_length(&self) -> usize {
+        self.days.len()
+    }
+
+    /// Get the hours of the shift starting on a date, if it is a working day.
+    pub fn hours_on(&self, date: NaiveDate) -> Option<&TimeRange> {
+        let index = (date - self.anchor).num_days().rem_euclid(self.days.len() as i64);
+        self.days[index as usize].as_ref()
+    }
+
+    /// Check if a shift starts on a date.
+    pub fn is_working_day(&self, date: NaiveDate) -> bool {
+        self.hours_on(date).is_some()
+    }
+
+    /// Get the runs of consecutive working days within a window, clipped to the window.
+    pub fn working_blocks(&self, window: &DateRange) -> Vec<DateRange> {
+        let mut blocks: Vec<DateRange> = Vec::new();
+        for date in window.iter().filter(|date| self.is_working_day(*date)) {
+            match blocks.last_mut() {
+                Some(block) if block.end_date().succ_opt() == Some(date) => {
+                    *block = DateRange::new(block.start_date(), date);
+                }
+                _ => blocks.push(DateRange::new(date, date)),
+            }
+        }
+        blocks
+    }
+
+    /// Get the shifts that start on a date within a window.
+    pub fn shifts(&self, window: &DateRange) -> Vec<DateTimeRange> {
+        window
+            .iter()
+            .filter_map(|date| {
+                self.hours_on(date)
+                    .map(|hours| DateTimeRange::from_time_range_on_date(hours.start(), hours.end(), date))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RotationPattern;
+    use crate::daterange::date_range::DateRange;
+    use crate::timerange::time_range::TimeRange;
+    use chrono::{NaiveDate, NaiveTime};
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+    fn t(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).expect("invalid time")
+    }
+
+    #[test]
+    fn four_on_four_off_repeats_in_both_directions() {
+        let pattern = RotationPattern::on_off(4, 4, TimeRange::of(t(7, 0), t(19, 0)), d(2024, 3, 1));
+        assert_eq!(pattern.cycle_length(), 8);
+
+        let blocks = pattern.working_blocks(&DateRange::new(d(2024, 2, 24), d(2024, 3, 20)));
+        assert_eq!(
+            blocks,
+            vec![
+                DateRange::new(d(2024, 2, 24), d(2024, 2, 25)),
+                DateRange::new(d(2024, 3, 1), d(2024, 3, 4)),
+                DateRange::new(d(2024, 3, 9), d(2024, 3, 12)),
+                DateRange::new(d(2024, 3, 17), d(2024, 3, 20)),
+            ]
+        );
+
+        let crew_b = pattern.offset_by(4);
+        assert!(!crew_b.is_working_day(d(2024, 3, 1)));
+        assert!(crew_b.is_working_day(d(2024, 3, 5)));
+    }
+
+    #[test]
+    fn dupont_nights_end_the_next_morning() {
+        let (day, night) = (TimeRange::of(t(7, 0), t(19, 0)), TimeRange::of(t(19, 0), t(7, 0)));
+        let pattern = RotationPattern::dupont(day, night, d(2024, 3, 4));
+        assert_eq!(pattern.cycle_length(), 28);
+
+        let first_week = pattern.shifts(&DateRange::new(d(2024, 3, 4), d(2024, 3, 10)));
+        assert_eq!(first_week.len(), 4);
+        assert_eq!(first_week[0].start(), d(2024, 3, 4).and_time(t(19, 0)));
+        assert_eq!(first_week[0].end(), d(2024, 3, 5).and_time(t(7, 0)));
+
+        let cycle = DateRange::new(d(2024, 3, 4), d(2024, 3, 31));
+        assert_eq!(pattern.shifts(&cycle).len(), 14);
+        assert_eq!(pattern.hours_on(d(2024, 3, 11)), Some(&TimeRange::of(t(7, 0), t(19, 0))));
+        assert_eq!(pattern.hours_on(d(2024, 4, 1)), pattern.hours_on(d(2024, 3, 4)));
+    }
+}
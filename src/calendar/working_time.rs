@@ -0,0 +1,228 @@
+use crate::calendar::business_hours::BusinessHours;
+use crate::calendar::holiday_calendar::HolidayCalendar;
+use crate::datetimerange::date_time_range::DateTimeRange;
+use chrono::{Duration, NaiveDate, NaiveDateTime, Weekday};
+
+/// Get the amount of time between two datetimes that falls within business hours.
+///
+/// Open windows that start on a holiday are skipped. Returns zero if `end` is not after `start`.
+pub fn working_duration_between(
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    hours: &BusinessHours,
+    calendar: &HolidayCalendar,
+) -> Duration {
+    if end <= start {
+        return Duration::zero();
+    }
+
+    let span = DateTimeRange::of(start, end);
+    // Start a day early so windows that open the previous evening are included.
+    let mut date = start.date().pred_opt().unwrap_or(start.date());
+    let mut total = Duration::zero();
+    while date <= end.date() {
+        if !calendar.is_holiday(date)
+            && let Some(window) = hours.open_window(date)
+        {
+            total += span.overlap_duration(&window);
+        }
+        let Some(next) = date.succ_opt() else { break };
+        date = next;
+    }
+    total
+}
+
+/// Get the datetime reached by adding an amount of working time to a datetime, rolling over
+/// closed periods and holidays.
+///
+/// A zero or negative amount returns `start` unchanged. Returns None if the business hours are
+/// closed, or open for no time, every day of the week, or if the result is past the last date.
+pub fn add_working_hours(
+    start: NaiveDateTime,
+    working_time: Duration,
+    hours: &BusinessHours,
+    calendar: &HolidayCalendar,
+) -> Option<NaiveDateTime> {
+    if working_time <= Duration::zero() {
+        return Some(start);
+    }
+    if !is_ever_open(hours) {
+        return None;
+    }
+
+    let mut remaining = working_time;
+    let mut current = start;
+    let mut date = start.date().pred_opt().unwrap_or(start.date());
+    loop {
+        if !calendar.is_holiday(date)
+            && let Some(window) = hours.open_window(date)
+            && window.end() > current
+        {
+            let from = current.max(window.start());
+            let available = window.end() - from;
+            if available >= remaining {
+                return Some(from + remaining);
+            }
+            remaining -= available;
+            current = window.end();
+        }
+        date = date.succ_opt()?;
+    }
+}
+
+/// Add a number of business days to a date, skipping weekends and holidays. A negative
+/// number moves backwards. Returns None if every day of the week is part of the weekend or the
+/// result would be outside the supported calendar.
+pub fn add_working_days(date: NaiveDate, days: i64, calendar: &HolidayCalendar) -> Option<NaiveDate> {
+    if days != 0 && !calendar.has_business_weekdays() {
+        return None;
+    }
+    let mut remaining = days.unsigned_abs();
+    let mut current = date;
+    while remaining > 0 {
+        current = if days < 0 { current.pred_opt()? } else { current.succ_opt()? };
+        if calendar.is_business_day(current) {
+            remaining -= 1;
+        }
+    }
+    Some(current)
+}
+
+fn is_ever_open(hours: &BusinessHours) -> bool {
+    [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun]
+        .iter()
+        .any(|day| hours.hours_for(*day).is_some_and(|hours| hours.start() != hours.end()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_working_days, add_working_hours, working_duration_between};
+    use crate::calendar::business_hours::BusinessHours;
+    use crate::calendar::holiday_calendar::HolidayCalendar;
+    use crate::timerange::time_range::TimeRange;
+    use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+    fn t(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).expect("invalid time")
+    }
+    fn dt(y: i32, m: u32, day: u32, h: u32, mi: u32) -> NaiveDateTime {
+        d(y, m, day).and_time(t(h, mi))
+    }
+
+    #[test]
+    fn counts_only_open_time_within_a_day() {
+        let hours = BusinessHours::weekdays(t(9, 0), t(17, 0));
+        let calendar = HolidayCalendar::new();
+        // Monday 07:00 .. 12:30 -> 09:00..12:30
+        let worked = working_duration_between(dt(2023, 6, 5, 7, 0), dt(2023, 6, 5, 12, 30), &hours, &calendar);
+        assert_eq!(worked, Duration::hours(3) + Duration::minutes(30));
+    }
+
+    #[test]
+    fn skips_weekends_and_holidays() {
+        let hours = BusinessHours::weekdays(t(9, 0), t(17, 0));
+        let calendar = HolidayCalendar::new().with_holidays([d(2023, 7, 3)]);
+        // Friday 16:00 -> Tuesday 10:00, with Monday a holiday: 1h Friday + 1h Tuesday
+        let worked = working_duration_between(dt(2023, 6, 30, 16, 0), dt(2023, 7, 4, 10, 0), &hours, &calendar);
+        assert_eq!(worked, Duration::hours(2));
+    }
+
+    #[test]
+    fn includes_overnight_window_opened_the_previous_day() {
+        let hours = BusinessHours::new().with_hours(Weekday::Mon, TimeRange::of(t(22, 0), t(6, 0)));
+        let calendar = HolidayCalendar::new();
+        // Tuesday 02:00 .. 08:00 -> Monday's window is open until 06:00
+        let worked = working_duration_between(dt(2023, 6, 6, 2, 0), dt(2023, 6, 6, 8, 0), &hours, &calendar);
+        assert_eq!(worked, Duration::hours(4));
+    }
+
+    #[test]
+    fn reversed_or_empty_span_is_zero() {
+        let hours = BusinessHours::weekdays(t(9, 0), t(17, 0));
+        let calendar = HolidayCalendar::new();
+        let start = dt(2023, 6, 5, 12, 0);
+        assert_eq!(working_duration_between(start, start, &hours, &calendar), Duration::zero());
+        assert_eq!(working_duration_between(start, dt(2023, 6, 5, 10, 0), &hours, &calendar), Duration::zero());
+    }
+
+    #[test]
+    fn spans_at_the_edges_of_the_calendar() {
+        let hours = BusinessHours::new()
+            .with_hours(NaiveDate::MIN.weekday(), TimeRange::of(t(9, 0), t(17, 0)))
+            .with_hours(NaiveDate::MAX.weekday(), TimeRange::of(t(9, 0), t(17, 0)));
+        let calendar = HolidayCalendar::new();
+        let (first, last) = (NaiveDate::MIN, NaiveDate::MAX);
+        let worked = working_duration_between(first.and_time(t(0, 0)), first.and_time(t(10, 0)), &hours, &calendar);
+        assert_eq!(worked, Duration::hours(1));
+        let worked = working_duration_between(last.and_time(t(16, 0)), last.and_time(t(23, 0)), &hours, &calendar);
+        assert_eq!(worked, Duration::hours(1));
+    }
+
+    #[test]
+    fn add_working_hours_rolls_over_closing_time_and_weekend() {
+        let hours = BusinessHours::weekdays(t(9, 0), t(17, 0));
+        let calendar = HolidayCalendar::new();
+        // Friday 15:00 + 4h -> 2h Friday, 2h Monday -> Monday 11:00
+        let due = add_working_hours(dt(2023, 6, 9, 15, 0), Duration::hours(4), &hours, &calendar);
+        assert_eq!(due, Some(dt(2023, 6, 12, 11, 0)));
+        // Starting before opening time counts from opening
+        let due = add_working_hours(dt(2023, 6, 12, 6, 0), Duration::hours(8), &hours, &calendar);
+        assert_eq!(due, Some(dt(2023, 6, 12, 17, 0)));
+    }
+
+    #[test]
+    fn add_working_hours_skips_holidays_and_is_inverse_of_working_duration() {
+        let hours = BusinessHours::weekdays(t(9, 0), t(17, 0));
+        let calendar = HolidayCalendar::new().with_holidays([d(2023, 7, 4)]);
+        let start = dt(2023, 7, 3, 16, 30);
+        let due = add_working_hours(start, Duration::hours(3), &hours, &calendar).unwrap();
+        assert_eq!(due, dt(2023, 7, 5, 11, 30));
+        assert_eq!(working_duration_between(start, due, &hours, &calendar), Duration::hours(3));
+    }
+
+    #[test]
+    fn add_working_hours_edge_cases() {
+        let calendar = HolidayCalendar::new();
+        let start = dt(2023, 6, 10, 12, 0);
+        let closed = BusinessHours::new();
+        assert_eq!(add_working_hours(start, Duration::hours(1), &closed, &calendar), None);
+        assert_eq!(add_working_hours(start, Duration::zero(), &closed, &calendar), Some(start));
+
+        let never_open = BusinessHours::weekdays(t(9, 0), t(9, 0));
+        assert_eq!(add_working_hours(start, Duration::hours(1), &never_open, &calendar), None);
+
+        let hours = BusinessHours::weekdays(t(9, 0), t(17, 0));
+        let first = NaiveDate::MIN.and_time(t(0, 0));
+        assert!(add_working_hours(first, Duration::hours(1), &hours, &calendar).is_some());
+        let last = NaiveDate::MAX.and_time(t(12, 0));
+        assert_eq!(add_working_hours(last, Duration::hours(1000), &hours, &calendar), None);
+    }
+
+    #[test]
+    fn add_working_days_forward_and_backward() {
+        let calendar = HolidayCalendar::new().with_holidays([d(2023, 12, 25), d(2024, 1, 1)]);
+        // Friday Dec 22 + 1 -> Tuesday Dec 26 (weekend + Christmas)
+        assert_eq!(add_working_days(d(2023, 12, 22), 1, &calendar), Some(d(2023, 12, 26)));
+        assert_eq!(add_working_days(d(2023, 12, 22), 5, &calendar), Some(d(2024, 1, 2)));
+        assert_eq!(add_working_days(d(2023, 12, 26), -1, &calendar), Some(d(2023, 12, 22)));
+        // A weekend start with zero days stays put
+        assert_eq!(add_working_days(d(2023, 12, 23), 0, &calendar), Some(d(2023, 12, 23)));
+    }
+
+    #[test]
+    fn add_working_days_without_business_weekdays_or_past_the_calendar() {
+        let week = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun];
+        let closed = HolidayCalendar::new().with_weekend(&week);
+        assert_eq!(add_working_days(d(2024, 3, 1), 1, &closed), None);
+        assert_eq!(add_working_days(d(2024, 3, 1), -1, &closed), None);
+        assert_eq!(add_working_days(d(2024, 3, 1), 0, &closed), Some(d(2024, 3, 1)));
+
+        let calendar = HolidayCalendar::new().with_weekend(&[]);
+        assert_eq!(add_working_days(NaiveDate::MAX, 1, &calendar), None);
+        assert_eq!(add_working_days(NaiveDate::MIN, -1, &calendar), None);
+        assert_eq!(add_working_days(NaiveDate::MAX, -1, &calendar), NaiveDate::MAX.pred_opt());
+    }
+}
@@ -0,0 +1,240 @@
+use crate::daterange::date_range::DateRange;
+use crate::dateutils::date_utils::{add_months, last_day_of_month};
+use chrono::{Datelike, Duration, NaiveDate};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// How a fiscal year is divided into regular accounting periods.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PeriodsPerYear {
+    /// One period per calendar month.
+    Twelve,
+    /// Thirteen four-week periods. The 13th period runs to the end of the fiscal year.
+    Thirteen,
+}
+
+impl PeriodsPerYear {
+    fn count(&self) -> u32 {
+        match self {
+            PeriodsPerYear::Twelve => 12,
+            PeriodsPerYear::Thirteen => 13,
+        }
+    }
+}
+
+/// A general ledger calendar dividing each fiscal year into accounting periods, optionally
+/// followed by an adjusting period for year-end entries.
+///
+/// Fiscal years are named after the calendar year they end in, so with a July start
+/// FY2024 runs from 2023-07-01 to 2024-06-30.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AccountingCalendar {
+    start_month: u32,
+    periods_per_year: PeriodsPerYear,
+    adjusting_period: bool,
+}
+
+impl AccountingCalendar {
+    /// Create a calendar whose fiscal years start on the first of `start_month` (1-12).
+    pub fn new(start_month: u32, periods_per_year: PeriodsPerYear) -> Self {
+        assert!((1..=12).contains(&start_month), "start_month must be between 1 and 12");
+        Self {
+            start_month,
+            periods_per_year,
+            adjusting_period: false,
+        }
+    }
+
+    /// Add an adjusting period after the last regular period of each fiscal year. The
+    /// adjusting period covers the last day of the fiscal year.
+    pub fn with_adjusting_period(mut self) -> Self {
+        self.adjusting_period = true;
+        self
+    }
+
+    /// Get the number of periods in a fiscal year, including any adjusting period.
+    pub fn period_count(&self) -> u32 {
+        self.periods_per_year.count() + if self.adjusting_period { 1 } else { 0 }
+    }
+
+    /// Get the dates covered by a fiscal year.
+    pub fn fiscal_year_range(&self, fiscal_year: i32) -> DateRange {
+        let start_year = if self.start_month == 1 { fiscal_year } else { fiscal_year - 1 };
+        let start = NaiveDate::from_ymd_opt(start_year, self.start_month, 1).unwrap();
+        DateRange::new(start, add_months(start, 12) - Duration::days(1))
+    }
+
+    /// Get the fiscal year that contains a date.
+    pub fn fiscal_year_of(&self, date: NaiveDate) -> i32 {
+        if self.start_month == 1 || date.month() < self.start_month {
+            date.year()
+        } else {
+            date.year() + 1
+        }
+    }
+
+    /// Get a period of a fiscal year by number, starting at 1.
+    pub fn period(&self, fiscal_year: i32, number: u32) -> Option<AccountingPeriod> {
+        if number == 0 || number > self.period_count() {
+            return None;
+        }
+        Some(AccountingPeriod {
+            calendar: *self,
+            fiscal_year,
+            number,
+        })
+    }
+
+    /// Get all periods of a fiscal year, including any adjusting period.
+    pub fn periods(&self, fiscal_year: i32) -> Vec<AccountingPeriod> {
+        (1..=self.period_count())
+            .filter_map(|number| self.period(fiscal_year, number))
+            .collect()
+    }
+
+    /// Get the regular period that contains a date.
+    pub fn period_containing(&self, date: NaiveDate) -> AccountingPeriod {
+        let fiscal_year = self.fiscal_year_of(date);
+        (1..=self.periods_per_year.count())
+            .filter_map(|number| self.period(fiscal_year, number))
+            .find(|period| period.date_range().contains_date(date))
+            .unwrap()
+    }
+
+    fn period_range(&self, fiscal_year: i32, number: u32) -> DateRange {
+        let year = self.fiscal_year_range(fiscal_year);
+        if number > self.periods_per_year.count() {
+            return DateRange::new(year.end_date(), year.end_date());
+        }
+
+        match self.periods_per_year {
+            PeriodsPerYear::Twelve => {
+                let start = add_months(year.start_date(), number as i32 - 1);
+                DateRange::new(start, last_day_of_month(start))
+            }
+            PeriodsPerYear::Thirteen => {
+                let start = year.start_date() + Duration::weeks(4 * (number as i64 - 1));
+                let end = if number == 13 {
+                    year.end_date()
+                } else {
+                    start + Duration::days(27)
+                };
+                DateRange::new(start, end)
+            }
+        }
+    }
+}
+
+/// A single period of an accounting calendar.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AccountingPeriod {
+    calendar: AccountingCalendar,
+    fiscal_year: i32,
+    number: u32,
+}
+
+impl AccountingPeriod {
+    /// Get the fiscal year the period belongs to.
+    pub fn fiscal_year(&self) -> i32 {
+        self.fiscal_year
+    }
+
+    /// Get the number of the period within its fiscal year, starting at 1.
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    /// Check if this is the adjusting period of its fiscal year.
+    pub fn is_adjusting(&self) -> bool {
+        self.number > self.calendar.periods_per_year.count()
+    }
+
+    /// Get the dates covered by the period.
+    pub fn date_range(&self) -> DateRange {
+        self.calendar.period_range(self.fiscal_year, self.number)
+    }
+
+    /// Get a label for the period, such as `FY2024-P03`.
+    pub fn label(&self) -> String {
+        format!("FY{}-P{:02}", self.fiscal_year, self.number)
+    }
+
+    /// Get the period before this one, moving to the last period of the prior fiscal year as needed.
+    pub fn prior(&self) -> AccountingPeriod {
+        if self.number == 1 {
+            self.calendar.period(self.fiscal_year - 1, self.calendar.period_count()).unwrap()
+        } else {
+            self.calendar.period(self.fiscal_year, self.number - 1).unwrap()
+        }
+    }
+
+    /// Get the period after this one, moving to the first period of the next fiscal year as needed.
+    pub fn next(&self) -> AccountingPeriod {
+        if self.number == self.calendar.period_count() {
+            self.calendar.period(self.fiscal_year + 1, 1).unwrap()
+        } else {
+            self.calendar.period(self.fiscal_year, self.number + 1).unwrap()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AccountingCalendar, PeriodsPerYear};
+    use crate::daterange::date_range::DateRange;
+    use chrono::NaiveDate;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn twelve_periods_follow_months_from_fiscal_start() {
+        let calendar = AccountingCalendar::new(7, PeriodsPerYear::Twelve);
+        assert_eq!(calendar.fiscal_year_range(2024), DateRange::new(d(2023, 7, 1), d(2024, 6, 30)));
+
+        let periods = calendar.periods(2024);
+        assert_eq!(periods.len(), 12);
+        assert_eq!(periods[0].date_range(), DateRange::new(d(2023, 7, 1), d(2023, 7, 31)));
+        assert_eq!(periods[7].date_range(), DateRange::new(d(2024, 2, 1), d(2024, 2, 29)));
+        assert_eq!(periods[11].label(), "FY2024-P12");
+    }
+
+    #[test]
+    fn thirteen_periods_are_four_weeks_with_last_running_to_year_end() {
+        let calendar = AccountingCalendar::new(1, PeriodsPerYear::Thirteen);
+        let periods = calendar.periods(2023);
+        assert_eq!(periods.len(), 13);
+        assert_eq!(periods[0].date_range(), DateRange::new(d(2023, 1, 1), d(2023, 1, 28)));
+        assert_eq!(periods[1].date_range(), DateRange::new(d(2023, 1, 29), d(2023, 2, 25)));
+        assert_eq!(periods[12].date_range(), DateRange::new(d(2023, 12, 3), d(2023, 12, 31)));
+        assert_eq!(periods[12].date_range().len(), 29);
+    }
+
+    #[test]
+    fn adjusting_period_covers_last_day_and_links_prior_next() {
+        let calendar = AccountingCalendar::new(1, PeriodsPerYear::Twelve).with_adjusting_period();
+        let p13 = calendar.period(2023, 13).unwrap();
+        assert!(p13.is_adjusting());
+        assert_eq!(p13.date_range(), DateRange::new(d(2023, 12, 31), d(2023, 12, 31)));
+        assert_eq!(p13.label(), "FY2023-P13");
+        assert_eq!(calendar.period(2023, 14), None);
+
+        let next = p13.next();
+        assert_eq!((next.fiscal_year(), next.number()), (2024, 1));
+        assert_eq!(next.prior(), p13);
+        assert_eq!(p13.prior().number(), 12);
+    }
+
+    #[test]
+    fn period_containing_finds_regular_period_across_fiscal_years() {
+        let calendar = AccountingCalendar::new(10, PeriodsPerYear::Twelve).with_adjusting_period();
+        let period = calendar.period_containing(d(2023, 9, 30));
+        assert_eq!((period.fiscal_year(), period.number()), (2023, 12));
+        assert!(!period.is_adjusting());
+
+        let period = calendar.period_containing(d(2023, 10, 1));
+        assert_eq!((period.fiscal_year(), period.number()), (2024, 1));
+    }
+}
@@ -0,0 +1,107 @@
+use chrono::{Duration, NaiveDate};
+use core::error::Error;
+use core::fmt;
+
+const BI_WEEKLY_DAYS: i64 = 14;
+
+/// The first inconsistent boundary found by [`check_bi_weekly_anchor`]. `index` is the
+/// position of the offending end date in the list.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AnchorDrift {
+    /// The end date is not a whole number of bi-weekly periods from the anchor.
+    PhaseDrift { index: usize, date: NaiveDate, offset_days: i64 },
+    /// The end date is in phase but one or more periods before it are missing.
+    SkippedPeriods { index: usize, date: NaiveDate, expected: NaiveDate, skipped: usize },
+    /// The end date is not after the previous one.
+    OutOfOrder { index: usize, date: NaiveDate, previous: NaiveDate },
+}
+
+impl fmt::Display for AnchorDrift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnchorDrift::PhaseDrift { index, date, offset_days } => {
+                write!(f, "end date {} at {} is {} days out of phase with the anchor", date, index, offset_days)
+            }
+            AnchorDrift::SkippedPeriods { index, date, expected, skipped } => write!(
+                f,
+                "end date {} at {} skips {} period(s), expected {}",
+                date, index, skipped, expected
+            ),
+            AnchorDrift::OutOfOrder { index, date, previous } => {
+                write!(f, "end date {} at {} is not after {}", date, index, previous)
+            }
+        }
+    }
+}
+
+impl Error for AnchorDrift {}
+
+/// Check a history of bi-weekly period end dates, such as one migrated from another payroll
+/// system, against the cycle defined by an anchor end date. Every end date must be a whole
+/// number of 14-day periods from the anchor and each must follow the previous one by exactly
+/// one period. The first inconsistency found is returned.
+pub fn check_bi_weekly_anchor(end_dates: &[NaiveDate], anchor: NaiveDate) -> Result<(), AnchorDrift> {
+    let mut previous: Option<NaiveDate> = None;
+    for (index, date) in end_dates.iter().copied().enumerate() {
+        let offset_days = (date - anchor).num_days().rem_euclid(BI_WEEKLY_DAYS);
+        if offset_days != 0 {
+            return Err(AnchorDrift::PhaseDrift { index, date, offset_days });
+        }
+
+        if let Some(previous) = previous {
+            let gap = (date - previous).num_days();
+            if gap <= 0 {
+                return Err(AnchorDrift::OutOfOrder { index, date, previous });
+            }
+            if gap > BI_WEEKLY_DAYS {
+                return Err(AnchorDrift::SkippedPeriods {
+                    index,
+                    date,
+                    expected: previous + Duration::days(BI_WEEKLY_DAYS),
+                    skipped: (gap / BI_WEEKLY_DAYS - 1) as usize,
+                });
+            }
+        }
+        previous = Some(date);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_bi_weekly_anchor, AnchorDrift};
+    use chrono::NaiveDate;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn consistent_history_passes() {
+        let ends = [d(2024, 1, 5), d(2024, 1, 19), d(2024, 2, 2), d(2024, 2, 16)];
+        assert_eq!(check_bi_weekly_anchor(&ends, d(2024, 3, 1)), Ok(()));
+        assert_eq!(check_bi_weekly_anchor(&[], d(2024, 3, 1)), Ok(()));
+    }
+
+    #[test]
+    fn first_inconsistent_boundary_is_reported() {
+        let anchor = d(2024, 1, 5);
+
+        let drifted = [d(2024, 1, 5), d(2024, 1, 19), d(2024, 2, 3)];
+        let error = check_bi_weekly_anchor(&drifted, anchor).unwrap_err();
+        assert_eq!(error, AnchorDrift::PhaseDrift { index: 2, date: d(2024, 2, 3), offset_days: 1 });
+        assert_eq!(error.to_string(), "end date 2024-02-03 at 2 is 1 days out of phase with the anchor");
+
+        let skipped = [d(2024, 1, 5), d(2024, 2, 16), d(2024, 3, 1)];
+        assert_eq!(
+            check_bi_weekly_anchor(&skipped, anchor),
+            Err(AnchorDrift::SkippedPeriods { index: 1, date: d(2024, 2, 16), expected: d(2024, 1, 19), skipped: 2 })
+        );
+
+        let repeated = [d(2024, 1, 5), d(2024, 1, 5)];
+        assert_eq!(
+            check_bi_weekly_anchor(&repeated, anchor),
+            Err(AnchorDrift::OutOfOrder { index: 1, date: d(2024, 1, 5), previous: d(2024, 1, 5) })
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::daterange::date_range::DateRange;
-use crate::dateutils::date_utils::{add_years, subtract_years};
+use crate::daterange::frequency::Frequency;
+use crate::dateutils::date_utils::{add_years, add_years_with_policy, subtract_years, LeapDayPolicy};
 use chrono::{Datelike, Duration, NaiveDate};
 
 pub struct AnnualDateRange;
@@ -13,7 +14,7 @@ impl AnnualDateRange {
             end_date,
             AnnualDateRange::prior,
             AnnualDateRange::next,
-        )
+        ).with_frequency(Frequency::Annual)
     }
 
     pub fn with_end_date(end_date: NaiveDate) -> DateRange {
@@ -24,25 +25,35 @@ impl AnnualDateRange {
             end_date,
             AnnualDateRange::prior,
             AnnualDateRange::next,
-        )
+        ).with_frequency(Frequency::Annual)
+    }
+
+    /// Create an annual range from a start date, using the leap day policy for anniversaries of
+    /// Feb 29. With `RollToMar1` a range starting on Feb 29 ends on Feb 28 and the following
+    /// ranges start on Mar 1.
+    pub fn with_start_date_and_policy(start_date: NaiveDate, policy: LeapDayPolicy) -> DateRange {
+        match policy {
+            LeapDayPolicy::ClampToFeb28 => Self::with_start_date(start_date),
+            LeapDayPolicy::RollToMar1 => Self::rolled(start_date, Self::end_for_start_rolled(start_date)),
+        }
     }
 
     /// Returns the previous year.
     pub fn prior(date_range: &DateRange) -> DateRange {
         let start = subtract_years(date_range.start_date(), 1);
-        let end = Self::end_for_start(start);
+        let end = date_range.start_date() - Duration::days(1);
 
         DateRange::new_with_prior_next(
             start,
             end,
             AnnualDateRange::prior,
             AnnualDateRange::next,
-        )
+        ).with_frequency(Frequency::Annual)
     }
 
     /// Returns the next year.
     pub fn next(date_range: &DateRange) -> DateRange {
-        let start = add_years(date_range.start_date(), 1);
+        let start = date_range.end_date() + Duration::days(1);
         let end = Self::end_for_start(start);
 
         DateRange::new_with_prior_next(
@@ -50,7 +61,30 @@ impl AnnualDateRange {
             end,
             AnnualDateRange::prior,
             AnnualDateRange::next,
-        )
+        ).with_frequency(Frequency::Annual)
+    }
+
+    fn rolled(start_date: NaiveDate, end_date: NaiveDate) -> DateRange {
+        DateRange::new_with_prior_next(
+            start_date,
+            end_date,
+            AnnualDateRange::prior_rolled,
+            AnnualDateRange::next_rolled,
+        ).with_frequency(Frequency::Annual)
+    }
+
+    fn prior_rolled(date_range: &DateRange) -> DateRange {
+        let start = add_years_with_policy(date_range.start_date(), -1, LeapDayPolicy::RollToMar1);
+        Self::rolled(start, date_range.start_date() - Duration::days(1))
+    }
+
+    fn next_rolled(date_range: &DateRange) -> DateRange {
+        let start = date_range.end_date() + Duration::days(1);
+        Self::rolled(start, Self::end_for_start_rolled(start))
+    }
+
+    fn end_for_start_rolled(start_date: NaiveDate) -> NaiveDate {
+        add_years_with_policy(start_date, 1, LeapDayPolicy::RollToMar1) - Duration::days(1)
     }
 
     fn end_for_start(start_date: NaiveDate) -> NaiveDate {
@@ -69,6 +103,7 @@ impl AnnualDateRange {
 #[cfg(test)]
 mod tests {
     use super::AnnualDateRange;
+    use crate::dateutils::date_utils::LeapDayPolicy;
     use chrono::NaiveDate;
 
     // Helper to parse YYYY-MM-DD easily
@@ -109,6 +144,22 @@ mod tests {
         assert_eq!(next.end_date(), d(2024, 12, 31));
     }
 
+    #[test]
+    fn roll_to_mar_1_policy_moves_leap_day_anniversaries() {
+        let dr = AnnualDateRange::with_start_date_and_policy(d(2024, 2, 29), LeapDayPolicy::RollToMar1);
+        assert_eq!((dr.start_date(), dr.end_date()), (d(2024, 2, 29), d(2025, 2, 28)));
+
+        let next = dr.next();
+        assert_eq!((next.start_date(), next.end_date()), (d(2025, 3, 1), d(2026, 2, 28)));
+        assert_eq!(dr.prior().next(), dr);
+
+        let prior = dr.prior();
+        assert_eq!((prior.start_date(), prior.end_date()), (d(2023, 3, 1), d(2024, 2, 28)));
+
+        let clamped = AnnualDateRange::with_start_date_and_policy(d(2024, 2, 29), LeapDayPolicy::ClampToFeb28);
+        assert_eq!(clamped, AnnualDateRange::with_start_date(d(2024, 2, 29)));
+    }
+
     #[test]
     fn leap_year_with_start_date_feb_29() {
         // Starting on leap day 2020-02-29 should end on 2021-02-28
@@ -1,4 +1,5 @@
 use crate::daterange::date_range::DateRange;
+use crate::daterange::frequency::Frequency;
 use chrono::{Datelike, Duration, NaiveDate, Weekday};
 
 pub struct BiWeeklyDateRange;
@@ -6,19 +7,19 @@ pub struct BiWeeklyDateRange;
 impl BiWeeklyDateRange {
     pub fn with_start_date(start_date: NaiveDate) -> DateRange {
         let end = start_date + Duration::days(13);
-        DateRange::new(start_date, end)
+        DateRange::new(start_date, end).with_frequency(Frequency::BiWeekly)
     }
 
     pub fn with_end_date(end_date: NaiveDate) -> DateRange {
         let start = end_date - Duration::days(13);
-        DateRange::new(start, end_date)
+        DateRange::new(start, end_date).with_frequency(Frequency::BiWeekly)
     }
 
     pub fn with_target_date(target: NaiveDate, end_day: Weekday) -> DateRange {
         let offset = calculate_day_of_week_offset(target, end_day);
-        let end = target + Duration::days(offset as i64);
+        let end = target + Duration::days(offset);
         let start = end - Duration::days(13);
-        DateRange::new(start, end)
+        DateRange::new(start, end).with_frequency(Frequency::BiWeekly)
     }
 }
 
@@ -0,0 +1,130 @@
+use crate::daterange::date_range::DateRange;
+use crate::dateutils::date_utils::{add_months, first_day_of_month, last_day_of_month};
+use chrono::{Datelike, Duration, NaiveDate};
+
+/// How a signup on a day that some months don't have is carried through later cycles.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EndOfMonthPolicy {
+    /// Cycles start on the signup day, or the last day of months that are too short.
+    /// A signup on Feb 28 renews on Mar 28.
+    Clamp,
+    /// Like `Clamp`, but a signup on the last day of a month renews on the last day of
+    /// every month. A signup on Feb 28 renews on Mar 31.
+    EndOfMonth,
+}
+
+pub struct BillingCycle;
+
+impl BillingCycle {
+    /// Creates the first monthly billing cycle for a signup date, clamping the anchor day
+    /// in short months.
+    pub fn monthly_anchored(signup_date: NaiveDate) -> DateRange {
+        Self::monthly_anchored_with_policy(signup_date, EndOfMonthPolicy::Clamp)
+    }
+
+    /// Creates the first monthly billing cycle for a signup date using the given policy.
+    pub fn monthly_anchored_with_policy(signup_date: NaiveDate, policy: EndOfMonthPolicy) -> DateRange {
+        let anchor_day = if policy == EndOfMonthPolicy::EndOfMonth && signup_date == last_day_of_month(signup_date) {
+            31
+        } else {
+            signup_date.day() as usize
+        };
+        Self::cycle_starting(signup_date, anchor_day)
+    }
+
+    /// Get the cycle of a billing plan that contains a date.
+    pub fn cycle_containing(cycle: &DateRange, date: NaiveDate) -> DateRange {
+        let anchor_day = cycle.start_day().unwrap_or(cycle.start_date().day() as usize);
+        let mut start = anchored_date(first_day_of_month(date), anchor_day);
+        if start > date {
+            start = anchored_date(add_months(first_day_of_month(date), -1), anchor_day);
+        }
+        Self::cycle_starting(start, anchor_day)
+    }
+
+    /// Returns the previous billing cycle.
+    fn prior(date_range: &DateRange) -> DateRange {
+        let anchor_day = date_range.start_day().unwrap();
+        let start = anchored_date(add_months(first_day_of_month(date_range.start_date()), -1), anchor_day);
+        Self::cycle_starting(start, anchor_day)
+    }
+
+    /// Returns the next billing cycle.
+    fn next(date_range: &DateRange) -> DateRange {
+        Self::cycle_starting(date_range.end_date() + Duration::days(1), date_range.start_day().unwrap())
+    }
+
+    fn cycle_starting(start_date: NaiveDate, anchor_day: usize) -> DateRange {
+        let next_start = anchored_date(add_months(first_day_of_month(start_date), 1), anchor_day);
+        DateRange::new_with_prior_next_start_day(
+            start_date,
+            next_start - Duration::days(1),
+            BillingCycle::prior,
+            BillingCycle::next,
+            Some(anchor_day),
+        )
+    }
+}
+
+/// Get the anchor day in the month of the given date, clamped to the last day of the month.
+pub(crate) fn anchored_date(date: NaiveDate, anchor_day: usize) -> NaiveDate {
+    let last = last_day_of_month(date);
+    last.with_day((anchor_day as u32).min(last.day())).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BillingCycle, EndOfMonthPolicy};
+    use chrono::NaiveDate;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn signup_on_31st_clamps_in_short_months_without_drifting() {
+        let first = BillingCycle::monthly_anchored(d(2023, 1, 31));
+        assert_eq!(first.start_date(), d(2023, 1, 31));
+        assert_eq!(first.end_date(), d(2023, 2, 27));
+
+        let second = first.next();
+        assert_eq!(second.start_date(), d(2023, 2, 28));
+        assert_eq!(second.end_date(), d(2023, 3, 30));
+
+        let third = second.next();
+        assert_eq!(third.start_date(), d(2023, 3, 31));
+        assert_eq!(third.end_date(), d(2023, 4, 29));
+
+        assert_eq!(third.prior(), second);
+        assert_eq!(second.prior(), first);
+    }
+
+    #[test]
+    fn end_of_month_policy_keeps_last_day_signups_on_last_day() {
+        let clamp = BillingCycle::monthly_anchored_with_policy(d(2023, 2, 28), EndOfMonthPolicy::Clamp);
+        assert_eq!(clamp.next().start_date(), d(2023, 3, 28));
+
+        let eom = BillingCycle::monthly_anchored_with_policy(d(2023, 2, 28), EndOfMonthPolicy::EndOfMonth);
+        assert_eq!(eom.end_date(), d(2023, 3, 30));
+        assert_eq!(eom.next().start_date(), d(2023, 3, 31));
+        assert_eq!(eom.next().next().start_date(), d(2023, 4, 30));
+
+        // A mid-month signup is unaffected by the policy
+        let mid = BillingCycle::monthly_anchored_with_policy(d(2023, 2, 15), EndOfMonthPolicy::EndOfMonth);
+        assert_eq!(mid.next().start_date(), d(2023, 3, 15));
+    }
+
+    #[test]
+    fn cycle_containing_finds_cycle_from_anchor() {
+        let first = BillingCycle::monthly_anchored(d(2023, 1, 31));
+
+        let cycle = BillingCycle::cycle_containing(&first, d(2024, 2, 29));
+        assert_eq!(cycle.start_date(), d(2024, 2, 29));
+        assert_eq!(cycle.end_date(), d(2024, 3, 30));
+
+        let cycle = BillingCycle::cycle_containing(&first, d(2024, 2, 28));
+        assert_eq!(cycle.start_date(), d(2024, 1, 31));
+        assert_eq!(cycle.end_date(), d(2024, 2, 28));
+        assert_eq!(cycle, first.range_containing_date(d(2024, 2, 28)));
+    }
+}
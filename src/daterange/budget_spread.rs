@@ -0,0 +1,150 @@
+use crate::calendar::holiday_calendar::HolidayCalendar;
+use crate::daterange::date_range::DateRange;
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::{BigDecimal, RoundingMode, ToPrimitive, Zero};
+use alloc::vec::Vec;
+
+/// How an amount is weighted across periods.
+#[derive(Copy, Clone, Debug)]
+pub enum SpreadMethod<'a> {
+    /// Every period receives the same share.
+    Equal,
+    /// Each period's share is proportional to its number of days.
+    DayWeighted,
+    /// Each period's share is proportional to its number of business days in the calendar.
+    BusinessDayWeighted(&'a HolidayCalendar),
+}
+
+/// Which periods receive the units left over after each share is rounded down.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RemainderRule {
+    /// The whole remainder goes to the first period.
+    First,
+    /// The whole remainder goes to the last period.
+    Last,
+    /// One unit each goes to the periods that lost the most to rounding, earlier periods first
+    /// on ties.
+    LargestRemainder,
+}
+
+/// Spread an amount across periods, rounding each share to `scale` decimal places so that the
+/// shares add up exactly to the amount rounded to the same scale. Returns `None` if there are no
+/// periods or the periods have no weight, e.g. business-day weighting over only holidays.
+pub fn spread_evenly(
+    amount: &BigDecimal,
+    periods: &[DateRange],
+    method: SpreadMethod,
+    scale: i64,
+    remainder: RemainderRule,
+) -> Option<Vec<BigDecimal>> {
+    let weights: Vec<u64> = periods
+        .iter()
+        .map(|period| match method {
+            SpreadMethod::Equal => 1,
+            SpreadMethod::DayWeighted => period.len() as u64,
+            SpreadMethod::BusinessDayWeighted(calendar) => {
+                period.iter().filter(|date| calendar.is_business_day(*date)).count() as u64
+            }
+        })
+        .collect();
+    let total_weight: u64 = weights.iter().sum();
+    if total_weight == 0 {
+        return None;
+    }
+
+    let amount = amount.with_scale_round(scale, RoundingMode::HalfEven);
+    let exact: Vec<BigDecimal> = weights
+        .iter()
+        .map(|weight| &amount * BigDecimal::from(*weight) / BigDecimal::from(total_weight))
+        .collect();
+    let mut shares: Vec<BigDecimal> = exact
+        .iter()
+        .map(|share| share.with_scale_round(scale, RoundingMode::Down))
+        .collect();
+    let left_over = shares.iter().fold(amount, |left, share| left - share);
+    if left_over.is_zero() {
+        return Some(shares);
+    }
+
+    match remainder {
+        RemainderRule::First => shares[0] += left_over,
+        RemainderRule::Last => *shares.last_mut()? += left_over,
+        RemainderRule::LargestRemainder => {
+            let unit = BigDecimal::new(BigInt::from(1), scale);
+            let units = (left_over.abs() / &unit).to_usize()?;
+            let unit = if left_over < BigDecimal::zero() { -unit } else { unit };
+
+            let mut order: Vec<usize> = (0..shares.len()).collect();
+            order.sort_by(|a, b| (&exact[*b] - &shares[*b]).abs().cmp(&(&exact[*a] - &shares[*a]).abs()));
+            for index in order.into_iter().take(units) {
+                shares[index] += &unit;
+            }
+        }
+    }
+    Some(shares)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{spread_evenly, RemainderRule, SpreadMethod};
+    use crate::calendar::holiday_calendar::HolidayCalendar;
+    use crate::daterange::date_range::DateRange;
+    use bigdecimal::BigDecimal;
+    use chrono::NaiveDate;
+    use std::str::FromStr;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+    fn bd(value: &str) -> BigDecimal {
+        BigDecimal::from_str(value).unwrap()
+    }
+    fn thirds() -> Vec<DateRange> {
+        vec![
+            DateRange::new(d(2024, 1, 1), d(2024, 1, 31)),
+            DateRange::new(d(2024, 2, 1), d(2024, 2, 29)),
+            DateRange::new(d(2024, 3, 1), d(2024, 3, 31)),
+        ]
+    }
+
+    #[test]
+    fn equal_spread_assigns_remainder_by_rule() {
+        let amount = bd("100.00");
+        let first = spread_evenly(&amount, &thirds(), SpreadMethod::Equal, 2, RemainderRule::First).unwrap();
+        assert_eq!(first, vec![bd("33.34"), bd("33.33"), bd("33.33")]);
+        let last = spread_evenly(&amount, &thirds(), SpreadMethod::Equal, 2, RemainderRule::Last).unwrap();
+        assert_eq!(last, vec![bd("33.33"), bd("33.33"), bd("33.34")]);
+    }
+
+    #[test]
+    fn day_weighted_spread_uses_largest_remainders() {
+        let shares =
+            spread_evenly(&bd("1000"), &thirds(), SpreadMethod::DayWeighted, 0, RemainderRule::LargestRemainder)
+                .unwrap();
+        // 340.659..., 318.681..., 340.659... leaves two units for February then January
+        assert_eq!(shares, vec![bd("341"), bd("319"), bd("340")]);
+        assert_eq!(shares.iter().sum::<BigDecimal>(), bd("1000"));
+
+        let negative =
+            spread_evenly(&bd("-1000"), &thirds(), SpreadMethod::DayWeighted, 0, RemainderRule::LargestRemainder)
+                .unwrap();
+        assert_eq!(negative, vec![bd("-341"), bd("-319"), bd("-340")]);
+    }
+
+    #[test]
+    fn business_day_weighted_spread_skips_non_working_periods() {
+        let calendar = HolidayCalendar::new().with_holidays([d(2024, 3, 4)]);
+        // Mon 2024-03-04 is a holiday, so the first week has 4 business days to the second's 5.
+        let weeks = [
+            DateRange::new(d(2024, 3, 4), d(2024, 3, 10)),
+            DateRange::new(d(2024, 3, 11), d(2024, 3, 17)),
+            DateRange::new(d(2024, 3, 16), d(2024, 3, 17)),
+        ];
+        let method = SpreadMethod::BusinessDayWeighted(&calendar);
+        let shares = spread_evenly(&bd("90"), &weeks, method, 2, RemainderRule::First).unwrap();
+        assert_eq!(shares, vec![bd("40.00"), bd("50.00"), bd("0.00")]);
+
+        assert_eq!(spread_evenly(&bd("90"), &weeks[2..], method, 2, RemainderRule::First), None);
+        assert_eq!(spread_evenly(&bd("90"), &[], SpreadMethod::Equal, 2, RemainderRule::First), None);
+    }
+}
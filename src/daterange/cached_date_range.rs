@@ -0,0 +1,122 @@
+use crate::calendar::holiday_calendar::HolidayCalendar;
+use crate::daterange::date_range::DateRange;
+use chrono::{Datelike, NaiveDate, Weekday};
+use alloc::vec::Vec;
+use std::sync::OnceLock;
+
+/// A DateRange paired with a holiday calendar that computes derived data on first use and
+/// reuses it afterwards. The cache is filled at most once even when shared between threads.
+///
+/// DateRange itself stays `Copy`; wrap a range in this only where the derived data is read
+/// repeatedly.
+#[derive(Clone, Debug)]
+pub struct CachedDateRange {
+    range: DateRange,
+    calendar: HolidayCalendar,
+    weekday_counts: OnceLock<[usize; 7]>,
+    business_days: OnceLock<Vec<NaiveDate>>,
+}
+
+impl CachedDateRange {
+    /// Creates a cached range whose business days exclude only Saturday and Sunday.
+    pub fn new(range: DateRange) -> Self {
+        Self::with_calendar(range, HolidayCalendar::new())
+    }
+
+    /// Creates a cached range whose business days are determined by a holiday calendar.
+    pub fn with_calendar(range: DateRange, calendar: HolidayCalendar) -> Self {
+        Self {
+            range,
+            calendar,
+            weekday_counts: OnceLock::new(),
+            business_days: OnceLock::new(),
+        }
+    }
+
+    pub fn range(&self) -> &DateRange {
+        &self.range
+    }
+
+    pub fn calendar(&self) -> &HolidayCalendar {
+        &self.calendar
+    }
+
+    /// Get the number of each weekday in the range, indexed from Monday.
+    pub fn weekday_counts(&self) -> &[usize; 7] {
+        self.weekday_counts.get_or_init(|| {
+            let mut counts = [0; 7];
+            for date in self.range.iter() {
+                counts[date.weekday().num_days_from_monday() as usize] += 1;
+            }
+            counts
+        })
+    }
+
+    /// Get the number of times a weekday occurs in the range.
+    pub fn count_of(&self, day: Weekday) -> usize {
+        self.weekday_counts()[day.num_days_from_monday() as usize]
+    }
+
+    /// Get the business days in the range.
+    pub fn business_days(&self) -> &[NaiveDate] {
+        self.business_days
+            .get_or_init(|| self.range.iter().filter(|date| self.calendar.is_business_day(*date)).collect())
+    }
+
+    /// Get the number of business days in the range.
+    pub fn business_day_count(&self) -> usize {
+        self.business_days().len()
+    }
+}
+
+impl From<DateRange> for CachedDateRange {
+    fn from(range: DateRange) -> Self {
+        Self::new(range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CachedDateRange;
+    use crate::calendar::holiday_calendar::HolidayCalendar;
+    use crate::daterange::date_range::DateRange;
+    use chrono::{NaiveDate, Weekday};
+    use std::sync::Arc;
+    use std::thread;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn weekday_counts_and_business_days() {
+        let march = DateRange::new(d(2024, 3, 1), d(2024, 3, 31));
+        let calendar = HolidayCalendar::new().with_holidays([d(2024, 3, 29)]);
+        let cached = CachedDateRange::with_calendar(march, calendar);
+
+        assert_eq!(cached.weekday_counts(), &[4, 4, 4, 4, 5, 5, 5]);
+        assert_eq!(cached.count_of(Weekday::Fri), 5);
+        assert_eq!(cached.business_day_count(), 20);
+        assert!(!cached.business_days().contains(&d(2024, 3, 29)));
+        assert_eq!(cached.range(), &march);
+
+        let plain = CachedDateRange::from(march);
+        assert_eq!(plain.business_day_count(), 21);
+    }
+
+    #[test]
+    fn cache_is_shared_between_threads() {
+        let cached = Arc::new(CachedDateRange::new(DateRange::new(d(2024, 1, 1), d(2024, 12, 31))));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let cached = Arc::clone(&cached);
+                thread::spawn(move || cached.business_day_count())
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), 262);
+        }
+        assert!(std::ptr::eq(cached.business_days(), cached.business_days()));
+    }
+}
@@ -0,0 +1,92 @@
+use crate::daterange::date_range::DateRange;
+use alloc::vec::Vec;
+
+/// The years over which a carryover limit is applied.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CarryoverPolicy {
+    /// Calendar years, January to December.
+    CalendarYear,
+    /// Fiscal years starting on the first of a month (1-12).
+    FiscalYear { start_month: u32 },
+}
+
+/// The part of an accrual year that falls in one policy year.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CarryoverWindow {
+    /// The policy year the days fall in.
+    pub policy_year: DateRange,
+    /// The days of the accrual year within the policy year.
+    pub overlap: DateRange,
+    /// The number of days in the overlap.
+    pub days: usize,
+    /// The share of the accrual year's days in the overlap.
+    pub fraction: f64,
+}
+
+/// Split an accrual year, such as an anniversary-based `AnnualDateRange`, into the parts that
+/// fall in each policy year, for prorating accruals against carryover limits. Returns the
+/// windows in date order, or None if the policy's start month is invalid.
+pub fn carryover_windows(accrual_year: &DateRange, policy: CarryoverPolicy) -> Option<Vec<CarryoverWindow>> {
+    let start_month = match policy {
+        CarryoverPolicy::CalendarYear => 1,
+        CarryoverPolicy::FiscalYear { start_month } => start_month,
+    };
+    let mut policy_year = DateRange::builder()
+        .annual()
+        .fiscal_start(start_month)
+        .containing(accrual_year.start_date())
+        .build()?;
+
+    let mut windows = Vec::new();
+    while let Some(clipped) = accrual_year.clip_to(&policy_year) {
+        windows.push(CarryoverWindow {
+            policy_year,
+            overlap: clipped.range,
+            days: clipped.range.len(),
+            fraction: clipped.fraction(),
+        });
+        policy_year = policy_year.next();
+    }
+    Some(windows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{carryover_windows, CarryoverPolicy};
+    use crate::daterange::annual_date_range::AnnualDateRange;
+    use crate::daterange::date_range::DateRange;
+    use chrono::NaiveDate;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn anniversary_year_splits_at_calendar_year_end() {
+        let accrual_year = AnnualDateRange::with_start_date(d(2023, 6, 15));
+        let windows = carryover_windows(&accrual_year, CarryoverPolicy::CalendarYear).unwrap();
+
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows[0].overlap, DateRange::new(d(2023, 6, 15), d(2023, 12, 31)));
+        assert_eq!(windows[0].days, 200);
+        assert!(windows[0].policy_year.same_bounds_as(&DateRange::new(d(2023, 1, 1), d(2023, 12, 31))));
+        assert_eq!(windows[1].overlap, DateRange::new(d(2024, 1, 1), d(2024, 6, 14)));
+        assert_eq!(windows[1].days, 166);
+        assert!((windows[0].fraction + windows[1].fraction - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn fiscal_policy_years_and_aligned_years() {
+        let accrual_year = AnnualDateRange::with_start_date(d(2023, 1, 1));
+        let windows = carryover_windows(&accrual_year, CarryoverPolicy::FiscalYear { start_month: 7 }).unwrap();
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows[0].overlap, DateRange::new(d(2023, 1, 1), d(2023, 6, 30)));
+        assert_eq!(windows[1].policy_year.end_date(), d(2024, 6, 30));
+
+        let aligned = carryover_windows(&accrual_year, CarryoverPolicy::CalendarYear).unwrap();
+        assert_eq!(aligned.len(), 1);
+        assert_eq!(aligned[0].fraction, 1.0);
+
+        assert!(carryover_windows(&accrual_year, CarryoverPolicy::FiscalYear { start_month: 13 }).is_none());
+    }
+}
@@ -0,0 +1,92 @@
+use crate::daterange::date_range::DateRange;
+use core::cmp::{max, min};
+
+/// A range clipped to some bounds, recording how much was cut from each end.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ClippedRange {
+    /// The part of the original range within the bounds.
+    pub range: DateRange,
+    /// The range before clipping.
+    pub original: DateRange,
+    /// The number of days removed from the start.
+    pub start_days_cut: usize,
+    /// The number of days removed from the end.
+    pub end_days_cut: usize,
+}
+
+impl ClippedRange {
+    /// Check if the start of the original range was cut off.
+    pub fn is_start_truncated(&self) -> bool {
+        self.start_days_cut > 0
+    }
+
+    /// Check if the end of the original range was cut off.
+    pub fn is_end_truncated(&self) -> bool {
+        self.end_days_cut > 0
+    }
+
+    /// Check if only part of the original range remains.
+    pub fn is_partial(&self) -> bool {
+        self.is_start_truncated() || self.is_end_truncated()
+    }
+
+    /// Get the fraction of the original range's days that remain.
+    pub fn fraction(&self) -> f64 {
+        self.range.len() as f64 / self.original.len() as f64
+    }
+}
+
+impl DateRange {
+    /// Clip the range to some bounds, such as employment dates, keeping a record of whether it
+    /// became a partial period. Returns `None` if the range and bounds don't overlap.
+    pub fn clip_to(&self, bounds: &DateRange) -> Option<ClippedRange> {
+        if !self.overlaps(bounds) {
+            return None;
+        }
+
+        let start = max(self.start_date(), bounds.start_date());
+        let end = min(self.end_date(), bounds.end_date());
+        Some(ClippedRange {
+            range: DateRange::new(start, end),
+            original: *self,
+            start_days_cut: (start - self.start_date()).num_days() as usize,
+            end_days_cut: (self.end_date() - end).num_days() as usize,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::daterange::date_range::DateRange;
+    use chrono::NaiveDate;
+
+    fn d(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn clip_to_records_truncation() {
+        let month = DateRange::new(d(2024, 3, 1), d(2024, 3, 31));
+        let employment = DateRange::new(d(2024, 3, 11), d(2025, 1, 1));
+
+        let clipped = month.clip_to(&employment).unwrap();
+        assert_eq!(clipped.range, DateRange::new(d(2024, 3, 11), d(2024, 3, 31)));
+        assert_eq!((clipped.start_days_cut, clipped.end_days_cut), (10, 0));
+        assert!(clipped.is_start_truncated() && !clipped.is_end_truncated() && clipped.is_partial());
+        assert_eq!(clipped.fraction(), 21.0 / 31.0);
+
+        let both = month.clip_to(&DateRange::new(d(2024, 3, 5), d(2024, 3, 20))).unwrap();
+        assert_eq!((both.start_days_cut, both.end_days_cut), (4, 11));
+    }
+
+    #[test]
+    fn clip_to_inside_bounds_is_whole() {
+        let month = DateRange::new(d(2024, 3, 1), d(2024, 3, 31));
+        let clipped = month.clip_to(&DateRange::new(d(2024, 1, 1), d(2024, 12, 31))).unwrap();
+        assert!(!clipped.is_partial());
+        assert_eq!(clipped.range, month);
+        assert_eq!(clipped.fraction(), 1.0);
+
+        assert_eq!(month.clip_to(&DateRange::new(d(2024, 4, 1), d(2024, 4, 30))), None);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::dateutils::date_utils::last_day_of_month;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+/// The convention used to find the comparable period a year earlier.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComparablePolicy {
+    /// The period containing the same calendar date a year earlier.
+    CalendarDate,
+    /// The range moved back 52 weeks, keeping the same weekdays.
+    ShiftedWeeks,
+    /// The same fiscal week of the prior year in a 52/53-week retail calendar such as the
+    /// NRF 4-5-4 calendar. Each fiscal year ends on the `week_end` weekday nearest the last
+    /// day of `year_end_month`.
+    ///
+    /// In the year after a 53-week year the prior year is restated by one week, so week N
+    /// compares to week N + 1 of the prior year. Week 53 itself compares to the first week
+    /// of its own year, as the prior year has no week 53.
+    Retail {
+        year_end_month: u32,
+        week_end: Weekday,
+    },
+}
+
+impl ComparablePolicy {
+    /// The NRF 4-5-4 retail calendar, with years ending on the Saturday nearest the end of
+    /// January.
+    pub fn nrf() -> Self {
+        ComparablePolicy::Retail {
+            year_end_month: 1,
+            week_end: Weekday::Sat,
+        }
+    }
+}
+
+/// Get the number of days between the retail week containing a date and its comparable week.
+pub(crate) fn retail_comparable_offset(date: NaiveDate, year_end_month: u32, week_end: Weekday) -> i64 {
+    let mut year = date.year();
+    while retail_year_end(year - 1, year_end_month, week_end) >= date {
+        year -= 1;
+    }
+    while retail_year_end(year, year_end_month, week_end) < date {
+        year += 1;
+    }
+
+    let start = retail_year_end(year - 1, year_end_month, week_end) + Duration::days(1);
+    let prior_start = retail_year_end(year - 2, year_end_month, week_end) + Duration::days(1);
+    let week = (date - start).num_days() / 7;
+    let weeks_in_prior_year = (start - prior_start).num_days() / 7;
+    let comparable_week = if weeks_in_prior_year == 53 { week + 1 } else { week };
+
+    (prior_start + Duration::weeks(comparable_week) - (start + Duration::weeks(week))).num_days()
+}
+
+fn retail_year_end(year: i32, year_end_month: u32, week_end: Weekday) -> NaiveDate {
+    let month_end = last_day_of_month(NaiveDate::from_ymd_opt(year, year_end_month, 1).unwrap());
+    let forward = (week_end.num_days_from_monday() + 7 - month_end.weekday().num_days_from_monday()) % 7;
+    if forward <= 3 {
+        month_end + Duration::days(forward as i64)
+    } else {
+        month_end - Duration::days(7 - forward as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn retail_year_ends_on_nearest_week_end() {
+        assert_eq!(retail_year_end(2023, 1, Weekday::Sat), d(2023, 1, 28));
+        assert_eq!(retail_year_end(2024, 1, Weekday::Sat), d(2024, 2, 3));
+        assert_eq!(retail_year_end(2025, 1, Weekday::Sat), d(2025, 2, 1));
+    }
+
+    #[test]
+    fn retail_offset_restates_the_year_after_a_53_week_year() {
+        // FY2023 (2023-01-29 to 2024-02-03) had 53 weeks, so FY2024 compares to it restated.
+        assert_eq!(retail_comparable_offset(d(2023, 1, 29), 1, Weekday::Sat), -364);
+        assert_eq!(retail_comparable_offset(d(2024, 2, 3), 1, Weekday::Sat), -364);
+        assert_eq!(retail_comparable_offset(d(2024, 2, 4), 1, Weekday::Sat), -364);
+        assert_eq!(retail_comparable_offset(d(2025, 3, 1), 1, Weekday::Sat), -364);
+    }
+}
@@ -0,0 +1,74 @@
+use crate::daterange::date_range::DateRange;
+use chrono::{Duration, NaiveDate};
+use core::cmp::min;
+
+/// The ranges behind a typical period-over-period comparison, each covering the same number of
+/// elapsed days wherever the compared period is long enough.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ComparisonSet {
+    /// The current period from its start up to the as of date.
+    pub to_date: DateRange,
+    /// The start of the prior period, covering the same number of days.
+    pub prior_to_date: DateRange,
+    /// The start of the same period last year, covering the same number of days.
+    pub last_year_to_date: DateRange,
+}
+
+impl DateRange {
+    /// Get the period-to-date, prior-period-to-date and last-year-to-date ranges as of a date.
+    /// The comparison ranges are cut short when the compared period has fewer days, e.g. a
+    /// March-to-date of 31 days compares against all 29 days of February. Returns `None` if the
+    /// date is before the range starts.
+    pub fn comparison_set(&self, as_of: NaiveDate) -> Option<ComparisonSet> {
+        let to_date = self.to_date(as_of)?;
+        let elapsed = Duration::days(to_date.len() as i64 - 1);
+        let same_elapsed = |period: DateRange| {
+            DateRange::new(period.start_date(), min(period.start_date() + elapsed, period.end_date()))
+        };
+
+        Some(ComparisonSet {
+            to_date,
+            prior_to_date: same_elapsed(self.prior()),
+            last_year_to_date: same_elapsed(self.same_period_last_year()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ComparisonSet;
+    use crate::daterange::date_range::DateRange;
+    use chrono::NaiveDate;
+
+    fn d(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn comparison_set_matches_elapsed_days() {
+        let march = DateRange::builder().monthly().containing(d(2024, 3, 10)).build().unwrap();
+        assert_eq!(
+            march.comparison_set(d(2024, 3, 14)),
+            Some(ComparisonSet {
+                to_date: DateRange::new(d(2024, 3, 1), d(2024, 3, 14)),
+                prior_to_date: DateRange::new(d(2024, 2, 1), d(2024, 2, 14)),
+                last_year_to_date: DateRange::new(d(2023, 3, 1), d(2023, 3, 14)),
+            })
+        );
+        assert_eq!(march.comparison_set(d(2024, 2, 29)), None);
+    }
+
+    #[test]
+    fn comparison_set_clamps_to_shorter_periods() {
+        let march = DateRange::builder().monthly().containing(d(2024, 3, 10)).build().unwrap();
+        let set = march.comparison_set(d(2024, 3, 31)).unwrap();
+        assert_eq!(set.to_date.len(), 31);
+        assert_eq!(set.prior_to_date, DateRange::new(d(2024, 2, 1), d(2024, 2, 29)));
+        assert_eq!(set.last_year_to_date, DateRange::new(d(2023, 3, 1), d(2023, 3, 31)));
+
+        let week = DateRange::builder().weekly().containing(d(2024, 3, 13)).build().unwrap();
+        let set = week.comparison_set(week.start_date()).unwrap();
+        assert_eq!(set.prior_to_date.len(), 1);
+        assert_eq!(set.last_year_to_date.start_date(), week.start_date() - chrono::Duration::weeks(52));
+    }
+}
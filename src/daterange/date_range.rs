@@ -1,8 +1,33 @@
-use chrono::{Datelike, Duration, NaiveDate, Weekday};
-use std::cmp::Ordering;
+use crate::daterange::comparable_policy::{retail_comparable_offset, ComparablePolicy};
+use crate::daterange::date_range_builder::DateRangeBuilder;
+use crate::daterange::eligibility_rule::EligibilityRule;
+use crate::daterange::frequency::Frequency;
+use crate::daterange::period_status::PeriodStatus;
+use crate::daterange::range_error::RangeError;
+use crate::daterange::tenor::Tenor;
+use crate::daterange::week_year_policy::WeekYearPolicy;
+use crate::datetimerange::date_time_range::DateTimeRange;
+use crate::dateutils::date_utils::{stable_hash, subtract_years, tenure, YearsMonthsDays};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::str::FromStr;
+
+/// The number of prior or next steps `try_range_containing_date` takes before giving up.
+pub const MAX_CONTAINING_STEPS: usize = 1_000_000;
 
 /// Represents a range of dates.
+///
+/// Performance contract: `len`, `contains_date`, `contains_range`, `overlaps` and `date_at` are
+/// O(1) and don't allocate. `iter` yields each date in O(1) without allocating. For ranges
+/// without a custom prior/next, `range_containing_date` is O(1); otherwise it takes one step
+/// per period between this range and the date.
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "diesel", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Daterange))]
 pub struct DateRange {
     start_date: NaiveDate,
     end_date: NaiveDate,
@@ -10,6 +35,8 @@ pub struct DateRange {
     prior_fn: Option<fn(&DateRange) -> DateRange>,
     next_fn: Option<fn(&DateRange) -> DateRange>,
     start_day: Option<usize>,
+    frequency: Option<Frequency>,
+    repeating: bool,
 }
 
 impl DateRange {
@@ -22,9 +49,16 @@ impl DateRange {
             prior_fn: None,
             next_fn: None,
             start_day: None,
+            frequency: None,
+            repeating: false,
         }
     }
 
+    /// Start building a periodic range. See [`DateRangeBuilder`].
+    pub fn builder() -> DateRangeBuilder {
+        DateRangeBuilder::new()
+    }
+
     pub(crate) fn new_with_prior_next(
         start_date: NaiveDate,
         end_date: NaiveDate,
@@ -39,6 +73,8 @@ impl DateRange {
             prior_fn: Some(prior_fn),
             next_fn: Some(next_fn),
             start_day: None,
+            frequency: None,
+            repeating: false,
         }
     }
 
@@ -57,29 +93,50 @@ impl DateRange {
             prior_fn: Some(prior_fn),
             next_fn: Some(next_fn),
             start_day,
+            frequency: None,
+            repeating: false,
+        }
+    }
+
+    /// Get a trial range of the given tenor beginning on a start date. Returns None if the
+    /// tenor does not cover at least one day.
+    pub fn trial(start_date: NaiveDate, tenor: Tenor) -> Option<DateRange> {
+        if tenor.is_positive() {
+            Some(DateRange::new(start_date, tenor.end_date_from(start_date)))
+        } else {
+            None
         }
     }
 
     /// Get the starting date in the range.
+    #[inline]
     pub fn start_date(&self) -> NaiveDate {
         self.start_date
     }
 
     /// Get the ending date in the range.
+    #[inline]
     pub fn end_date(&self) -> NaiveDate {
         self.end_date
     }
 
     /// Get the number of days in the range.
+    #[inline]
     pub fn len(&self) -> usize {
         self.len
     }
 
+    /// Check if the range contains no days.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
     /// Get an iterator over the dates in the range.
     pub fn iter(&self) -> DateRangeIter {
         DateRangeIter {
-            current: self.start_date,
-            end: self.end_date,
+            front: self.start_date,
+            back: self.end_date,
+            remaining: if self.start_date <= self.end_date { self.len } else { 0 },
         }
     }
 
@@ -88,42 +145,193 @@ impl DateRange {
         self.start_day
     }
 
+    /// Get the frequency of the factory that created the range, or None for ranges created
+    /// directly or with a custom prior/next.
+    pub fn frequency(&self) -> Option<Frequency> {
+        self.frequency
+    }
+
+    pub(crate) fn with_frequency(mut self, frequency: Frequency) -> DateRange {
+        self.frequency = Some(frequency);
+        self
+    }
+
+    /// Opt into treating a range without a periodicity as repeating back to back, so that
+    /// `try_range_containing_date` shifts it by its length instead of returning an error.
+    pub fn repeating(mut self) -> DateRange {
+        self.repeating = true;
+        self
+    }
+
+    /// Check if the range has a periodicity: it came from a periodic factory, has a custom
+    /// prior/next, or was marked as `repeating`.
+    pub fn is_periodic(&self) -> bool {
+        self.prior_fn.is_some() || self.frequency.is_some() || self.repeating
+    }
+
     /// Get the dates contained in the range in a vec.
     pub fn dates(&self) -> Vec<NaiveDate> {
-        let mut dates = Vec::with_capacity(self.len());
-        let mut current = self.start_date();
-        while current <= self.end_date() {
-            dates.push(current);
-            current += Duration::days(1);
-        }
-        dates
+        self.iter().collect()
+    }
+
+    /// Get a midnight-to-midnight DateTimeRange for each date in the range.
+    pub fn days_as_datetime_ranges(&self) -> Vec<DateTimeRange> {
+        self.iter().map(DateTimeRange::all_day).collect()
+    }
+
+    /// Get the bounds of the range as datetimes for a half-open `>= start AND < end` query:
+    /// midnight at the start of the start date, and midnight after the end date.
+    pub fn as_query_bounds(&self) -> (NaiveDateTime, NaiveDateTime) {
+        (
+            self.start_date().and_time(NaiveTime::MIN),
+            (self.end_date() + Duration::days(1)).and_time(NaiveTime::MIN),
+        )
     }
 
     /// Get the date at the specified index. If the index is outside the bounds
     //  an error will be returned.
     pub fn date_at(&self, index: usize) -> Option<NaiveDate> {
-        self.dates().get(index).copied()
+        (index < self.iter().len()).then(|| self.start_date() + Duration::days(index as i64))
     }
 
     /// Get a list of dates from the range that match the specified DayOfWeek
     pub fn dates_for_day(&self, day: Weekday) -> Vec<NaiveDate> {
-        self.dates()
-            .into_iter()
-            .filter(|d| d.weekday() == day)
-            .collect()
+        let first = day.days_since(self.start_date().weekday()) as usize;
+        self.iter().skip(first).step_by(7).collect()
+    }
+
+    /// Get every nth date of the range, starting with the start date. Empty if `n` is zero.
+    pub fn every_nth_date(&self, n: usize) -> Vec<NaiveDate> {
+        if n == 0 {
+            return Vec::new();
+        }
+        self.iter().step_by(n).collect()
+    }
+
+    /// Get a range `n` times as long as this one with the same start date. None if `n` is zero
+    /// or the end would be out of range.
+    pub fn scale(&self, n: usize) -> Option<DateRange> {
+        let days = (self.len() as i64).checked_mul(i64::try_from(n).ok()?)?.checked_sub(1).filter(|days| *days >= 0)?;
+        let end = self.start_date().checked_add_signed(Duration::try_days(days)?)?;
+        Some(DateRange::new(self.start_date(), end))
+    }
+
+    /// Get the dates of the range laid out in week rows that start on `week_start`, as for a
+    /// month calendar. Days of the first and last week that fall outside the range are None.
+    pub fn calendar_grid(&self, week_start: Weekday) -> Vec<Vec<Option<NaiveDate>>> {
+        let lead = self.start_date().weekday().days_since(week_start) as i64;
+        let mut day = self.start_date() - Duration::days(lead);
+        let mut rows = Vec::new();
+        while day <= self.end_date() {
+            let row = (0..7)
+                .map(|_| {
+                    let cell = self.contains_date(day).then_some(day);
+                    day += Duration::days(1);
+                    cell
+                })
+                .collect();
+            rows.push(row);
+        }
+        rows
+    }
+
+    /// Get the number of pages of `chunk_days` days needed to cover the range.
+    pub fn page_count(&self, chunk_days: usize) -> usize {
+        if chunk_days == 0 {
+            0
+        } else {
+            self.len().div_ceil(chunk_days)
+        }
+    }
+
+    /// Get a zero-based page of `chunk_days` days counted from the start of the range. The last
+    /// page ends on the end date of the range and may be shorter. Returns None if the page is
+    /// past the end of the range.
+    pub fn paginate(&self, chunk_days: usize, page: usize) -> Option<DateRange> {
+        if page >= self.page_count(chunk_days) {
+            return None;
+        }
+        let chunk_days = chunk_days.min(self.len());
+        let first = page * chunk_days;
+        let last = (first + chunk_days - 1).min(self.len() - 1);
+        Some(DateRange::new(
+            self.start_date() + Duration::days(first as i64),
+            self.start_date() + Duration::days(last as i64),
+        ))
+    }
+
+    /// Split the range at effective dates, such as the dates of mid-period rate changes. Each
+    /// date inside the range after its start begins a new piece, so the pieces are inclusive,
+    /// don't overlap and cover the range. Dates may be unsorted or repeated, and dates outside
+    /// the range are ignored.
+    pub fn split_on(&self, dates: &[NaiveDate]) -> Vec<DateRange> {
+        let mut cuts: Vec<NaiveDate> = dates
+            .iter()
+            .copied()
+            .filter(|date| *date > self.start_date() && *date <= self.end_date())
+            .collect();
+        cuts.sort();
+        cuts.dedup();
+
+        let mut pieces = Vec::with_capacity(cuts.len() + 1);
+        let mut start = self.start_date();
+        for cut in cuts {
+            pieces.push(DateRange::new(start, cut - Duration::days(1)));
+            start = cut;
+        }
+        pieces.push(DateRange::new(start, self.end_date()));
+        pieces
+    }
+
+    /// Get an iterator over the trailing window of `window_days` days ending at each date in the
+    /// range. Windows for the first dates reach back before the start of the range. A window
+    /// of zero days yields nothing.
+    pub fn rolling_windows(&self, window_days: usize) -> impl Iterator<Item = DateRange> {
+        let lookback = Duration::days(window_days as i64 - 1);
+        self.iter()
+            .take(if window_days == 0 { 0 } else { self.len() })
+            .map(move |date| DateRange::new(date - lookback, date))
     }
 
     /// Check if a date is contained in the range.
+    #[inline]
     pub fn contains_date(&self, date: NaiveDate) -> bool {
         date >= self.start_date() && date <= self.end_date()
     }
 
     /// Check if a date range is fully contained in the range.
+    #[inline]
     pub fn contains_range(&self, date_range: &DateRange) -> bool {
         date_range.start_date() >= self.start_date() && date_range.end_date() <= self.end_date()
     }
 
+    /// Check if a date range has the same start and end dates as this range, regardless of
+    /// how either range moves to its prior or next range.
+    pub fn same_bounds_as(&self, date_range: &DateRange) -> bool {
+        self.start_date() == date_range.start_date() && self.end_date() == date_range.end_date()
+    }
+
+    /// Get a deterministic 64-bit id for the start and end dates, suitable for idempotency and
+    /// cache keys shared between processes. Ranges with the same bounds have the same id.
+    pub fn stable_id(&self) -> u64 {
+        let mut bytes = [0u8; 8];
+        bytes[..4].copy_from_slice(&self.start_date.num_days_from_ce().to_le_bytes());
+        bytes[4..].copy_from_slice(&self.end_date.num_days_from_ce().to_le_bytes());
+        stable_hash(&bytes)
+    }
+
+    /// Compare ranges by end date, then by start date. Usable with `sort_by`.
+    pub fn cmp_by_end(a: &DateRange, b: &DateRange) -> Ordering {
+        a.end_date().cmp(&b.end_date()).then_with(|| a.start_date().cmp(&b.start_date()))
+    }
+
+    /// Compare ranges by number of days, then by start date. Usable with `sort_by`.
+    pub fn cmp_by_duration(a: &DateRange, b: &DateRange) -> Ordering {
+        a.len().cmp(&b.len()).then_with(|| a.start_date().cmp(&b.start_date()))
+    }
+
     /// Check if a date range is partially contained in the range.
+    #[inline]
     pub fn overlaps(&self, date_range: &DateRange) -> bool {
         self.start_date() <= date_range.end_date() && self.end_date() >= date_range.start_date()
     }
@@ -133,9 +341,44 @@ impl DateRange {
         date_ranges.iter().any(|range| self.overlaps(range))
     }
 
+    /// Get whether the range is past, current or in the future relative to a date.
+    pub fn status(&self, as_of: NaiveDate) -> PeriodStatus {
+        if as_of > self.end_date() {
+            PeriodStatus::Past
+        } else if as_of < self.start_date() {
+            PeriodStatus::Future
+        } else {
+            PeriodStatus::Current
+        }
+    }
+
+    /// Get the number of days from a date until the range starts. Negative once the range has started.
+    pub fn days_until_start(&self, as_of: NaiveDate) -> i64 {
+        (self.start_date() - as_of).num_days()
+    }
+
+    /// Get the number of days from a date until the range ends. Negative once the range has ended.
+    pub fn days_until_end(&self, as_of: NaiveDate) -> i64 {
+        (self.end_date() - as_of).num_days()
+    }
+
+    /// Get the part of the range from its start up to and including a date, e.g. month-to-date
+    /// within a monthly range. None if the date is before the range starts.
+    pub fn to_date(&self, as_of: NaiveDate) -> Option<DateRange> {
+        if as_of < self.start_date() {
+            return None;
+        }
+        Some(DateRange::new(self.start_date(), as_of.min(self.end_date())))
+    }
+
     /// Get the DateRange that contains the specified date.
+    ///
+    /// This steps through prior or next ranges until the date is reached, and will not return
+    /// if a custom prior/next never lands on the date. A range without a periodicity is shifted
+    /// by multiples of its length. Use `try_range_containing_date` when the ranges come from an
+    /// untrusted strategy or may not be periodic.
     pub fn range_containing_date(&self, date: NaiveDate) -> DateRange {
-        let mut range = self.create_new_date_range(self.start_date(), self.end_date());
+        let mut range = self.closest_range_to(date);
         while !range.contains_date(date) {
             if date > range.end_date() {
                 range = range.next();
@@ -146,10 +389,58 @@ impl DateRange {
         range
     }
 
+    /// Get the DateRange that contains the specified date, or an error if it isn't reached
+    /// within `MAX_CONTAINING_STEPS` steps or the range is not periodic. See `repeating`.
+    pub fn try_range_containing_date(&self, date: NaiveDate) -> Result<DateRange, RangeError> {
+        self.try_range_containing_date_within(date, MAX_CONTAINING_STEPS)
+    }
+
+    /// Get the DateRange that contains the specified date, or an error if it isn't reached
+    /// within `limit` steps or the range is not periodic.
+    pub fn try_range_containing_date_within(
+        &self,
+        date: NaiveDate,
+        limit: usize,
+    ) -> Result<DateRange, RangeError> {
+        if !self.is_periodic() {
+            return Err(RangeError::NotPeriodic);
+        }
+        let mut range = self.closest_range_to(date);
+        for _ in 0..limit {
+            if range.contains_date(date) {
+                return Ok(range);
+            }
+            range = if date > range.end_date() { range.next() } else { range.prior() };
+        }
+        if range.contains_date(date) {
+            Ok(range)
+        } else {
+            Err(RangeError::IterationLimitExceeded { limit })
+        }
+    }
+
+    /// Check if this range is one of the periods of another range's cycle, with the period after
+    /// it also matching that cycle. Two bi-weekly cycles offset by a week do not align.
+    pub fn aligns_with(&self, other: &DateRange) -> bool {
+        let counterpart = other.range_containing_date(self.start_date());
+        counterpart.same_bounds_as(self) && counterpart.next().same_bounds_as(&self.next())
+    }
+
+    /// Get the period of the reference range's cycle that contains the start of this range.
+    pub fn snap_to(&self, reference: &DateRange) -> DateRange {
+        reference.range_containing_date(self.start_date())
+    }
+
     /// Get a DateRange that represents the prior range to this dateRange.
     pub fn prior(&self) -> DateRange {
-        if self.prior_fn.is_some() {
-            self.prior_fn.unwrap()(self)
+        if let Some(prior_fn) = self.prior_fn {
+            let prior = prior_fn(self);
+            debug_assert!(
+                prior.start_date() < self.start_date(),
+                "prior range must start before {}",
+                self.start_date()
+            );
+            prior
         } else {
             self.create_new_date_range(
                 self.start_date() - Duration::days(self.len() as i64),
@@ -169,8 +460,14 @@ impl DateRange {
 
     /// Get a DateRange that represents the next range to this dateRange.
     pub fn next(&self) -> DateRange {
-        if self.next_fn.is_some() {
-            self.next_fn.unwrap()(self)
+        if let Some(next_fn) = self.next_fn {
+            let next = next_fn(self);
+            debug_assert!(
+                next.start_date() > self.start_date(),
+                "next range must start after {}",
+                self.start_date()
+            );
+            next
         } else {
             self.create_new_date_range(
                 self.start_date() + Duration::days(self.len() as i64),
@@ -208,6 +505,95 @@ impl DateRange {
         self.ranges_after_impl(number, true)
     }
 
+    /// Get the DateRanges after this DateRange, not including this DateRange, for as long as
+    /// the predicate holds. Collection stops at the first range the predicate rejects.
+    pub fn next_while<P>(&self, mut predicate: P) -> Vec<DateRange>
+    where
+        P: FnMut(&DateRange) -> bool,
+    {
+        let mut ranges = Vec::new();
+        let mut current = self.next();
+        while predicate(&current) {
+            ranges.push(current);
+            current = current.next();
+        }
+        ranges
+    }
+
+    /// Get the DateRanges before this DateRange, not including this DateRange, for as long as
+    /// the predicate holds, in chronological order.
+    pub fn prior_while<P>(&self, mut predicate: P) -> Vec<DateRange>
+    where
+        P: FnMut(&DateRange) -> bool,
+    {
+        let mut ranges = Vec::new();
+        let mut current = self.prior();
+        while predicate(&current) {
+            ranges.push(current);
+            current = current.prior();
+        }
+        ranges.reverse();
+        ranges
+    }
+
+    /// Get the first DateRange after this DateRange that matches the predicate, checking at most
+    /// `limit` ranges.
+    pub fn first_next_matching<P>(&self, mut predicate: P, limit: usize) -> Option<DateRange>
+    where
+        P: FnMut(&DateRange) -> bool,
+    {
+        let mut current = *self;
+        for _ in 0..limit {
+            current = current.next();
+            if predicate(&current) {
+                return Some(current);
+            }
+        }
+        None
+    }
+
+    /// Get the first DateRange before this DateRange that matches the predicate, checking at
+    /// most `limit` ranges.
+    pub fn first_prior_matching<P>(&self, mut predicate: P, limit: usize) -> Option<DateRange>
+    where
+        P: FnMut(&DateRange) -> bool,
+    {
+        let mut current = *self;
+        for _ in 0..limit {
+            current = current.prior();
+            if predicate(&current) {
+                return Some(current);
+            }
+        }
+        None
+    }
+
+    /// Get an iterator over `n` adjacent (range, next range) pairs, starting with this range
+    /// and the range after it.
+    pub fn iter_pairs(&self, n: usize) -> impl Iterator<Item = (DateRange, DateRange)> {
+        let mut current = *self;
+        (0..n).map(move |_| {
+            let next = current.next();
+            let pair = (current, next);
+            current = next;
+            pair
+        })
+    }
+
+    /// Get an iterator over `count` rolling windows of `size` consecutive ranges. The first
+    /// window starts with this range and each following window starts one range later.
+    pub fn period_windows(&self, size: usize, count: usize) -> impl Iterator<Item = Vec<DateRange>> {
+        let mut window = if size == 0 { Vec::new() } else { self.ranges_after_inclusive(size - 1) };
+        (0..count).map(move |i| {
+            if i > 0 && !window.is_empty() {
+                let next = window[window.len() - 1].next();
+                window.remove(0);
+                window.push(next);
+            }
+            window.clone()
+        })
+    }
+
     // Get a list of DateRanges that includes the current DateRange, N DateRanges before
     // this DateRange and N DateRanges after this date range.
     pub fn ranges_window(&self, before: usize, after: usize) -> Vec<DateRange> {
@@ -235,17 +621,103 @@ impl DateRange {
 
         let mut ranges = Vec::new();
         let mut range = self.range_containing_date(from_date);
-        ranges.push(range.clone());
+        ranges.push(range);
 
         while range.end_date() < to_date {
             range = range.next();
-            ranges.push(range.clone());
+            ranges.push(range);
         }
 
         ranges.sort_by_key(|r| r.start_date());
         ranges
     }
 
+    /// Get the equivalent period one year earlier. See `n_years_ago_with_policy`.
+    pub fn same_period_last_year(&self) -> DateRange {
+        self.n_years_ago(1)
+    }
+
+    /// Get the equivalent period `n` years earlier, matching weeks by moving back 52 weeks per
+    /// year. See `n_years_ago_with_policy`.
+    pub fn n_years_ago(&self, n: i32) -> DateRange {
+        self.n_years_ago_with_policy(n, WeekYearPolicy::default())
+    }
+
+    /// Get the equivalent period `n` years earlier using the range's own periodicity.
+    ///
+    /// Weekly and bi-weekly ranges are matched according to the policy. Other factory-built
+    /// ranges use the period of the same cycle that contains the start date `n` years earlier,
+    /// so a month-aligned range maps to the same month. Ranges without a frequency have both
+    /// bounds moved back `n` years.
+    pub fn n_years_ago_with_policy(&self, n: i32, policy: WeekYearPolicy) -> DateRange {
+        match (self.frequency, policy) {
+            (Some(Frequency::Weekly | Frequency::BiWeekly), WeekYearPolicy::FiftyTwoWeeks) => {
+                self.range_containing_date(self.start_date() - Duration::weeks(52 * n as i64))
+            }
+            (Some(Frequency::Weekly | Frequency::BiWeekly), WeekYearPolicy::SameIsoWeek) => {
+                let week = self.end_date().iso_week();
+                let year = week.year() - n;
+                let date = NaiveDate::from_isoywd_opt(year, week.week(), self.end_date().weekday())
+                    .or_else(|| NaiveDate::from_isoywd_opt(year, 52, self.end_date().weekday()))
+                    .unwrap();
+                self.range_containing_date(date)
+            }
+            (Some(_), _) => self.range_containing_date(subtract_years(self.start_date(), n)),
+            (None, _) => DateRange::new(subtract_years(self.start_date(), n), subtract_years(self.end_date(), n)),
+        }
+    }
+
+    /// Get the comparable period a year earlier under a reporting convention. Under the retail
+    /// policy the offset is taken from the fiscal week containing the start date, so weekly
+    /// ranges should end on the calendar's week end.
+    pub fn comparable_range(&self, policy: ComparablePolicy) -> DateRange {
+        let days = match policy {
+            ComparablePolicy::CalendarDate => {
+                return match self.frequency {
+                    Some(_) => self.range_containing_date(subtract_years(self.start_date(), 1)),
+                    None => self.n_years_ago(1),
+                };
+            }
+            ComparablePolicy::ShiftedWeeks => -364,
+            ComparablePolicy::Retail {
+                year_end_month,
+                week_end,
+            } => retail_comparable_offset(self.start_date(), year_end_month, week_end),
+        };
+
+        let shift = Duration::days(days);
+        match self.frequency {
+            Some(_) => self.range_containing_date(self.start_date() + shift),
+            None => DateRange::new(self.start_date() + shift, self.end_date() + shift),
+        }
+    }
+
+    /// Get the tenure from the start date of the range as of the end date of the range.
+    pub fn tenure_at_end(&self) -> YearsMonthsDays {
+        tenure(self.start_date(), self.end_date())
+    }
+
+    /// Get the part of the range during which the rule makes someone eligible, treating the
+    /// start date as the start of employment. Returns None if eligibility begins after the range ends.
+    pub fn eligibility_window(&self, rule: EligibilityRule) -> Option<DateRange> {
+        let eligible = rule.eligibility_date(self.start_date());
+        if eligible > self.end_date() {
+            None
+        } else {
+            Some(DateRange::new(eligible, self.end_date()))
+        }
+    }
+
+    /// Get a grace period of `days` days beginning the day after the range ends. Returns None
+    /// if `days` is not positive.
+    pub fn grace_period_after(&self, days: i64) -> Option<DateRange> {
+        if days <= 0 {
+            return None;
+        }
+        let start = self.end_date() + Duration::days(1);
+        Some(DateRange::new(start, start + Duration::days(days - 1)))
+    }
+
     fn ranges_before_impl(&self, number: usize, include_self: bool) -> Vec<DateRange> {
         let mut ranges = Vec::with_capacity(number + 1);
         if include_self {
@@ -273,14 +745,30 @@ impl DateRange {
         ranges
     }
 
+    /// Jump straight to the period containing a date when ranges are shifted by their length,
+    /// leaving custom prior/next strategies to be stepped through.
+    fn closest_range_to(&self, date: NaiveDate) -> DateRange {
+        if self.prior_fn.is_some() || self.next_fn.is_some() || self.start_date > self.end_date {
+            return self.create_new_date_range(self.start_date(), self.end_date());
+        }
+        let periods = (date - self.start_date()).num_days().div_euclid(self.len as i64);
+        let shift = Duration::days(periods * self.len as i64);
+        match (self.start_date().checked_add_signed(shift), self.end_date().checked_add_signed(shift)) {
+            (Some(start), Some(end)) => self.create_new_date_range(start, end),
+            _ => self.create_new_date_range(self.start_date(), self.end_date()),
+        }
+    }
+
     fn create_new_date_range(&self, start: NaiveDate, end: NaiveDate) -> DateRange {
         Self {
             start_date: start,
             end_date: end,
             len: (end - start).num_days() as usize + 1,
-            prior_fn: self.prior_fn.clone(),
-            next_fn: self.next_fn.clone(),
-            start_day: self.start_day.clone(),
+            prior_fn: self.prior_fn,
+            next_fn: self.next_fn,
+            start_day: self.start_day,
+            frequency: self.frequency,
+            repeating: self.repeating,
         }
     }
 }
@@ -305,29 +793,98 @@ impl Ord for DateRange {
     }
 }
 
+/// Formats the range as an ISO 8601 interval of dates, such as `2023-01-01/2023-01-31`.
+impl fmt::Display for DateRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.start_date(), self.end_date())
+    }
+}
+
+/// Parses an ISO 8601 interval of two calendar dates, such as `2023-01-01/2023-01-31`. The end
+/// must not be before the start.
+impl FromStr for DateRange {
+    type Err = RangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || RangeError::InvalidInterval(s.to_string());
+        let (start, end) = s.split_once('/').ok_or_else(invalid)?;
+        let start = NaiveDate::from_str(start).map_err(|_| invalid())?;
+        let end = NaiveDate::from_str(end).map_err(|_| invalid())?;
+        if end < start {
+            return Err(invalid());
+        }
+        Ok(DateRange::new(start, end))
+    }
+}
+
 pub struct DateRangeIter {
-    current: NaiveDate,
-    end: NaiveDate,
+    front: NaiveDate,
+    back: NaiveDate,
+    remaining: usize,
 }
 
 impl Iterator for DateRangeIter {
     type Item = NaiveDate;
 
+    #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > self.end {
-            None
-        } else {
-            let result = self.current;
-            self.current += Duration::days(1);
-            Some(result)
+        if self.remaining == 0 {
+            return None;
+        }
+        let result = self.front;
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            self.front = self.front.succ_opt().unwrap();
+        }
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.remaining {
+            self.remaining = 0;
+            return None;
         }
+        self.front += Duration::days(n as i64);
+        self.remaining -= n;
+        self.next()
     }
 }
 
+impl DoubleEndedIterator for DateRangeIter {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let result = self.back;
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            self.back = self.back.pred_opt().unwrap();
+        }
+        Some(result)
+    }
+}
+
+impl ExactSizeIterator for DateRangeIter {}
+
+impl FusedIterator for DateRangeIter {}
+
 #[cfg(test)]
 mod tests {
     use super::DateRange;
-    use chrono::{NaiveDate, Weekday};
+    use crate::daterange::comparable_policy::ComparablePolicy;
+    use crate::daterange::eligibility_rule::EligibilityRule;
+    use crate::daterange::frequency::Frequency;
+    use crate::daterange::period_status::PeriodStatus;
+    use crate::daterange::range_error::RangeError;
+    use crate::daterange::tenor::Tenor;
+    use crate::daterange::week_year_policy::WeekYearPolicy;
+    use crate::datetimerange::date_time_range::DateTimeRange;
+    use chrono::{Datelike, Duration, NaiveDate, Weekday};
 
     fn d(y: i32, m: u32, day: u32) -> NaiveDate {
         NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
@@ -379,6 +936,28 @@ mod tests {
         assert_eq!(r.date_at(r.len()), None);
     }
 
+    #[test]
+    fn days_as_datetime_ranges_covers_each_day_midnight_to_midnight() {
+        let r = DateRange::new(d(2024, 2, 28), d(2024, 3, 1));
+        let days = r.days_as_datetime_ranges();
+        assert_eq!(days.len(), 3);
+        assert_eq!(days[0], DateTimeRange::all_day(d(2024, 2, 28)));
+        assert_eq!(days[1].start(), d(2024, 2, 29).and_hms_opt(0, 0, 0).unwrap());
+        assert_eq!(days[1].end(), d(2024, 3, 1).and_hms_opt(0, 0, 0).unwrap());
+        assert_eq!(days[2].end(), d(2024, 3, 2).and_hms_opt(0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn as_query_bounds_is_half_open_through_midnight_after_end() {
+        let r = DateRange::new(d(2023, 12, 1), d(2023, 12, 31));
+        let (from, to) = r.as_query_bounds();
+        assert_eq!(from, d(2023, 12, 1).and_hms_opt(0, 0, 0).unwrap());
+        assert_eq!(to, d(2024, 1, 1).and_hms_opt(0, 0, 0).unwrap());
+
+        let last_row = d(2023, 12, 31).and_hms_opt(23, 59, 59).unwrap();
+        assert!(last_row >= from && last_row < to);
+    }
+
     #[test]
     fn dates_for_day_filters_correct_weekdays() {
         // Week spanning Mon..Sun
@@ -471,6 +1050,121 @@ mod tests {
         assert_eq!(b.end_date(), d(2022, 12, 24));
     }
 
+    fn next_skipping_a_week(date_range: &DateRange) -> DateRange {
+        DateRange::new_with_prior_next(
+            date_range.start_date() + Duration::days(14),
+            date_range.end_date() + Duration::days(14),
+            prior_skipping_a_week,
+            next_skipping_a_week,
+        )
+    }
+
+    fn prior_skipping_a_week(date_range: &DateRange) -> DateRange {
+        DateRange::new_with_prior_next(
+            date_range.start_date() - Duration::days(14),
+            date_range.end_date() - Duration::days(14),
+            prior_skipping_a_week,
+            next_skipping_a_week,
+        )
+    }
+
+    #[test]
+    fn try_range_containing_date_caps_iterations() {
+        let base = DateRange::new(d(2023, 1, 1), d(2023, 1, 7)).repeating();
+        assert_eq!(
+            base.try_range_containing_date(d(2023, 1, 19)),
+            Ok(DateRange::new(d(2023, 1, 15), d(2023, 1, 21)))
+        );
+
+        let shifting = DateRange::new_with_prior_next_start_day(
+            d(2023, 1, 1),
+            d(2023, 1, 7),
+            prior_shift_by_one,
+            next_shift_by_one,
+            None,
+        );
+        assert_eq!(
+            shifting.try_range_containing_date_within(d(2023, 1, 19), 11),
+            Err(RangeError::IterationLimitExceeded { limit: 11 })
+        );
+        assert!(shifting.try_range_containing_date_within(d(2023, 1, 19), 12).is_ok());
+
+        let gappy = DateRange::new_with_prior_next(
+            d(2023, 1, 1),
+            d(2023, 1, 7),
+            prior_skipping_a_week,
+            next_skipping_a_week,
+        );
+        let err = gappy.try_range_containing_date_within(d(2023, 1, 10), 50).unwrap_err();
+        assert_eq!(err.to_string(), "no containing range found within 50 steps");
+    }
+
+    #[test]
+    #[should_panic(expected = "next range must start after")]
+    #[cfg(debug_assertions)]
+    fn next_asserts_progress_in_debug_builds() {
+        fn stuck(date_range: &DateRange) -> DateRange {
+            DateRange::new(date_range.start_date(), date_range.end_date())
+        }
+        DateRange::new_with_prior_next(d(2023, 1, 1), d(2023, 1, 7), stuck, stuck).next();
+    }
+
+    #[test]
+    fn try_range_containing_date_requires_a_periodicity() {
+        let plain = DateRange::new(d(2023, 1, 1), d(2023, 1, 10));
+        assert!(!plain.is_periodic());
+        assert_eq!(plain.try_range_containing_date(d(2023, 1, 25)), Err(RangeError::NotPeriodic));
+        assert_eq!(plain.try_range_containing_date(d(2023, 1, 5)), Err(RangeError::NotPeriodic));
+
+        let repeating = plain.repeating();
+        assert!(repeating.is_periodic());
+        assert!(repeating.next().is_periodic());
+        assert_eq!(
+            repeating.try_range_containing_date(d(2023, 1, 25)),
+            Ok(DateRange::new(d(2023, 1, 21), d(2023, 1, 30)))
+        );
+
+        let week = DateRange::builder().weekly().containing(d(2023, 1, 5)).build().unwrap();
+        assert!(week.is_periodic());
+        assert!(week.try_range_containing_date(d(2023, 3, 1)).is_ok());
+    }
+
+    #[test]
+    fn range_containing_date_jumps_for_plain_ranges() {
+        let base = DateRange::new(d(2023, 1, 1), d(2023, 1, 7));
+        let far = base.range_containing_date(d(9000, 6, 15));
+        assert!(far.contains_date(d(9000, 6, 15)));
+        assert_eq!(far.len(), 7);
+        assert_eq!((far.start_date() - base.start_date()).num_days() % 7, 0);
+
+        let early = base.repeating().try_range_containing_date_within(d(1, 1, 1), 0).unwrap();
+        assert!(early.contains_date(d(1, 1, 1)));
+        assert_eq!((base.start_date() - early.start_date()).num_days() % 7, 0);
+    }
+
+    #[test]
+    fn iter_is_exact_size_and_double_ended() {
+        let range = DateRange::new(d(2024, 2, 27), d(2024, 3, 2));
+        let mut iter = range.iter();
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next(), Some(d(2024, 2, 27)));
+        assert_eq!(iter.next_back(), Some(d(2024, 3, 2)));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.nth(1), Some(d(2024, 2, 29)));
+        assert_eq!(iter.next(), Some(d(2024, 3, 1)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let reversed: Vec<_> = range.iter().rev().collect();
+        assert_eq!(reversed.first(), Some(&d(2024, 3, 2)));
+        assert_eq!(reversed.len(), 5);
+
+        let last_day = DateRange::new(NaiveDate::MAX, NaiveDate::MAX);
+        assert_eq!(last_day.iter().collect::<Vec<_>>(), vec![NaiveDate::MAX]);
+        assert_eq!(range.date_at(4), Some(d(2024, 3, 2)));
+        assert_eq!(range.date_at(5), None);
+    }
+
     #[test]
     fn ranges_before_and_after_and_window_orders() {
         let base = DateRange::new(d(2023, 1, 1), d(2023, 1, 7));
@@ -524,6 +1218,369 @@ mod tests {
         assert!(ranges.last().unwrap().contains_date(d(2023, 1, 25)));
     }
 
+    #[test]
+    fn tenure_at_end_measures_start_to_end() {
+        let r = DateRange::new(d(2019, 3, 10), d(2024, 6, 12));
+        let t = r.tenure_at_end();
+        assert_eq!((t.years, t.months, t.days), (5, 3, 2));
+
+        // A calendar year ends one day short of a full year
+        let year = DateRange::new(d(2023, 1, 1), d(2023, 12, 31));
+        let t = year.tenure_at_end();
+        assert_eq!((t.years, t.months, t.days), (0, 11, 30));
+    }
+
+    #[test]
+    fn eligibility_window_starts_at_rule_date_or_is_none() {
+        let employment = DateRange::new(d(2024, 1, 10), d(2024, 12, 31));
+        let window = employment.eligibility_window(EligibilityRule::FirstOfMonthFollowing(60)).unwrap();
+        assert_eq!(window.start_date(), d(2024, 4, 1));
+        assert_eq!(window.end_date(), d(2024, 12, 31));
+
+        let short = DateRange::new(d(2024, 1, 10), d(2024, 3, 31));
+        assert_eq!(short.eligibility_window(EligibilityRule::FirstOfMonthFollowing(60)), None);
+        assert_eq!(short.eligibility_window(EligibilityRule::Immediate), Some(short));
+    }
+
+    #[test]
+    fn next_while_and_prior_while_stop_at_first_rejected_range() {
+        let week = DateRange::new(d(2024, 1, 1), d(2024, 1, 7));
+
+        let upcoming = week.next_while(|r| r.start_date() < d(2024, 1, 29));
+        assert_eq!(
+            upcoming.iter().map(|r| r.start_date()).collect::<Vec<_>>(),
+            vec![d(2024, 1, 8), d(2024, 1, 15), d(2024, 1, 22)]
+        );
+
+        let earlier = week.prior_while(|r| r.end_date() >= d(2023, 12, 20));
+        assert_eq!(
+            earlier.iter().map(|r| r.start_date()).collect::<Vec<_>>(),
+            vec![d(2023, 12, 18), d(2023, 12, 25)]
+        );
+
+        assert!(week.next_while(|_| false).is_empty());
+    }
+
+    #[test]
+    fn first_matching_searches_within_limit() {
+        let week = DateRange::new(d(2024, 1, 1), d(2024, 1, 7));
+        let ends_in_february = |r: &DateRange| r.end_date().month() == 2;
+
+        let found = week.first_next_matching(ends_in_february, 10).unwrap();
+        assert_eq!(found.start_date(), d(2024, 1, 29));
+        assert_eq!(week.first_next_matching(ends_in_february, 3), None);
+
+        let prior = week.first_prior_matching(|r| r.start_date().month() == 11, 10).unwrap();
+        assert_eq!(prior.start_date(), d(2023, 11, 27));
+        assert_eq!(week.first_prior_matching(|_| true, 0), None);
+    }
+
+    #[test]
+    fn aligns_with_requires_same_period_and_phase() {
+        let cycle = DateRange::new(d(2024, 1, 1), d(2024, 1, 14));
+        let same_phase = DateRange::new(d(2024, 3, 11), d(2024, 3, 24));
+        let offset = DateRange::new(d(2024, 1, 8), d(2024, 1, 21));
+        let week = DateRange::new(d(2024, 1, 1), d(2024, 1, 7));
+
+        assert!(same_phase.aligns_with(&cycle));
+        assert!(cycle.aligns_with(&same_phase));
+        assert!(!offset.aligns_with(&cycle));
+        assert!(!week.aligns_with(&cycle));
+        assert!(!cycle.aligns_with(&week));
+    }
+
+    #[test]
+    fn snap_to_rephases_onto_reference_cycle() {
+        let cycle = DateRange::new(d(2024, 1, 1), d(2024, 1, 14));
+        let offset = DateRange::new(d(2024, 1, 8), d(2024, 1, 21));
+
+        let snapped = offset.snap_to(&cycle);
+        assert_eq!(snapped, DateRange::new(d(2024, 1, 1), d(2024, 1, 14)));
+        assert!(snapped.aligns_with(&cycle));
+        assert_eq!(snapped.next().start_date(), d(2024, 1, 15));
+    }
+
+    #[test]
+    fn calendar_grid_pads_partial_weeks() {
+        let february = DateRange::new(d(2024, 2, 1), d(2024, 2, 29));
+        let grid = february.calendar_grid(Weekday::Sun);
+
+        assert_eq!(grid.len(), 5);
+        assert!(grid.iter().all(|row| row.len() == 7));
+        assert_eq!(grid[0][..5], [None, None, None, None, Some(d(2024, 2, 1))]);
+        assert_eq!(grid[0][6], Some(d(2024, 2, 3)));
+        assert_eq!(grid[4][4], Some(d(2024, 2, 29)));
+        assert_eq!(grid[4][5..], [None, None]);
+
+        let monday_grid = february.calendar_grid(Weekday::Mon);
+        assert_eq!(monday_grid[0][3], Some(d(2024, 2, 1)));
+        assert_eq!(monday_grid.iter().flatten().flatten().count(), 29);
+
+        let aligned = DateRange::new(d(2024, 1, 1), d(2024, 1, 7)).calendar_grid(Weekday::Mon);
+        assert_eq!(aligned.len(), 1);
+        assert!(aligned[0].iter().all(Option::is_some));
+    }
+
+    #[test]
+    fn iter_pairs_yields_adjacent_ranges() {
+        let week = DateRange::new(d(2024, 1, 1), d(2024, 1, 7));
+        let pairs: Vec<_> = week.iter_pairs(3).collect();
+
+        assert_eq!(pairs.len(), 3);
+        assert_eq!(pairs[0], (week, week.next()));
+        assert_eq!(pairs[2].0.start_date(), d(2024, 1, 15));
+        assert_eq!(pairs[2].1.start_date(), d(2024, 1, 22));
+        assert!(pairs.iter().all(|(a, b)| a.next() == *b));
+        assert_eq!(week.iter_pairs(0).count(), 0);
+    }
+
+    #[test]
+    fn period_windows_roll_forward_one_range_at_a_time() {
+        let week = DateRange::new(d(2024, 1, 1), d(2024, 1, 7));
+        let windows: Vec<_> = week.period_windows(3, 2).collect();
+
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows[0], week.ranges_after_inclusive(2));
+        assert_eq!(windows[1], week.next().ranges_after_inclusive(2));
+        assert!(week.period_windows(0, 2).all(|w| w.is_empty()));
+    }
+
+    #[test]
+    fn same_period_last_year_follows_periodicity() {
+        let week = DateRange::builder().weekly().containing(d(2024, 3, 13)).build().unwrap();
+        let last_year = week.same_period_last_year();
+        assert_eq!((last_year.start_date(), last_year.end_date()), (d(2023, 3, 13), d(2023, 3, 19)));
+        assert_eq!(last_year.frequency(), week.frequency());
+
+        let sixteenth = DateRange::builder().monthly().start_day(16).containing(d(2024, 3, 20)).build().unwrap();
+        let last_year = sixteenth.same_period_last_year();
+        assert_eq!((last_year.start_date(), last_year.end_date()), (d(2023, 3, 16), d(2023, 4, 15)));
+
+        let leap_february = DateRange::builder().monthly().containing(d(2024, 2, 10)).build().unwrap();
+        assert_eq!(leap_february.n_years_ago(1).end_date(), d(2023, 2, 28));
+        assert_eq!(leap_february.n_years_ago(4).end_date(), d(2020, 2, 29));
+
+        let plain = DateRange::new(d(2024, 2, 26), d(2024, 2, 29));
+        assert_eq!(plain.same_period_last_year(), DateRange::new(d(2023, 2, 26), d(2023, 2, 28)));
+    }
+
+    #[test]
+    fn week_year_policy_handles_53_week_years() {
+        // 2020 had an ISO week 53, which 2021 doesn't.
+        let week_53 = DateRange::builder().weekly().containing(d(2020, 12, 30)).build().unwrap();
+        assert_eq!(week_53.end_date().iso_week().week(), 53);
+
+        let shifted = week_53.n_years_ago_with_policy(-1, WeekYearPolicy::FiftyTwoWeeks);
+        assert_eq!(shifted.end_date(), d(2022, 1, 2));
+        let iso = week_53.n_years_ago_with_policy(-1, WeekYearPolicy::SameIsoWeek);
+        assert_eq!(iso.end_date(), d(2022, 1, 2));
+        assert_eq!(iso.end_date().iso_week().week(), 52);
+
+        // After a 53-week year, 52 weeks back lands one ISO week early.
+        let week_10 = DateRange::builder().weekly().containing(d(2021, 3, 10)).build().unwrap();
+        assert_eq!(week_10.same_period_last_year().end_date().iso_week().week(), 11);
+        let iso = week_10.n_years_ago_with_policy(1, WeekYearPolicy::SameIsoWeek);
+        assert_eq!(iso.end_date().iso_week().week(), 10);
+    }
+
+    #[test]
+    fn comparable_range_follows_policy() {
+        let week = DateRange::builder().weekly().ending(Weekday::Sat).containing(d(2023, 1, 30)).build().unwrap();
+        assert_eq!(week.start_date(), d(2023, 1, 29));
+
+        let calendar = week.comparable_range(ComparablePolicy::CalendarDate);
+        assert_eq!((calendar.start_date(), calendar.end_date()), (d(2022, 1, 23), d(2022, 1, 29)));
+        let shifted = week.comparable_range(ComparablePolicy::ShiftedWeeks);
+        assert_eq!((shifted.start_date(), shifted.end_date()), (d(2022, 1, 30), d(2022, 2, 5)));
+        let retail = week.comparable_range(ComparablePolicy::nrf());
+        assert_eq!((retail.start_date(), retail.end_date()), (d(2022, 1, 30), d(2022, 2, 5)));
+        assert_eq!(retail.frequency(), Some(Frequency::Weekly));
+
+        let plain = DateRange::new(d(2024, 2, 4), d(2024, 2, 17));
+        let retail = plain.comparable_range(ComparablePolicy::nrf());
+        assert_eq!(retail, DateRange::new(d(2023, 2, 5), d(2023, 2, 18)));
+    }
+
+    #[test]
+    fn stable_id_depends_only_on_bounds() {
+        let plain = DateRange::new(d(2024, 1, 1), d(2024, 1, 31));
+        let custom = DateRange::new_with_prior_next_start_day(
+            d(2024, 1, 1),
+            d(2024, 1, 31),
+            prior_shift_by_one,
+            next_shift_by_one,
+            None,
+        );
+        assert_eq!(plain.stable_id(), 0x372d_c7b8_c2ee_29f3);
+        assert_eq!(plain.stable_id(), custom.stable_id());
+        assert_ne!(plain.stable_id(), custom.next().stable_id());
+        assert_ne!(plain.stable_id(), DateRange::new(d(2024, 1, 31), d(2024, 1, 1)).stable_id());
+    }
+
+    #[test]
+    fn same_bounds_and_comparators() {
+        let plain = DateRange::new(d(2023, 6, 10), d(2023, 6, 15));
+        let custom = DateRange::new_with_prior_next_start_day(
+            d(2023, 6, 10),
+            d(2023, 6, 15),
+            prior_shift_by_one,
+            next_shift_by_one,
+            None,
+        );
+        assert!(plain.same_bounds_as(&custom));
+        assert!(!plain.same_bounds_as(&custom.next()));
+
+        let week = DateRange::new(d(2023, 1, 1), d(2023, 1, 7));
+        let day = DateRange::new(d(2023, 1, 3), d(2023, 1, 3));
+        let month = DateRange::new(d(2022, 12, 15), d(2023, 1, 14));
+
+        let mut by_end = vec![month, week, day];
+        by_end.sort_by(DateRange::cmp_by_end);
+        assert_eq!(by_end, vec![day, week, month]);
+
+        let mut by_duration = vec![month, week, day];
+        by_duration.sort_by(DateRange::cmp_by_duration);
+        assert_eq!(by_duration, vec![day, week, month]);
+    }
+
+    #[test]
+    fn trial_and_grace_period_are_inclusive() {
+        let trial = DateRange::trial(d(2024, 1, 15), Tenor::Days(14)).unwrap();
+        assert_eq!(trial.start_date(), d(2024, 1, 15));
+        assert_eq!(trial.end_date(), d(2024, 1, 28));
+        assert_eq!(trial.len(), 14);
+
+        let monthly = DateRange::trial(d(2024, 1, 31), Tenor::Months(1)).unwrap();
+        assert_eq!(monthly.end_date(), d(2024, 2, 28));
+        assert_eq!(DateRange::trial(d(2024, 1, 31), Tenor::Days(0)), None);
+
+        let grace = trial.grace_period_after(3).unwrap();
+        assert_eq!(grace.start_date(), d(2024, 1, 29));
+        assert_eq!(grace.end_date(), d(2024, 1, 31));
+        assert_eq!(grace.len(), 3);
+        assert_eq!(trial.grace_period_after(0), None);
+    }
+
+    #[test]
+    fn status_and_days_until_relative_to_as_of() {
+        let r = DateRange::new(d(2024, 3, 1), d(2024, 3, 31));
+        assert_eq!(r.status(d(2024, 2, 29)), PeriodStatus::Future);
+        assert_eq!(r.status(d(2024, 3, 1)), PeriodStatus::Current);
+        assert_eq!(r.status(d(2024, 3, 31)), PeriodStatus::Current);
+        assert_eq!(r.status(d(2024, 4, 1)), PeriodStatus::Past);
+
+        assert_eq!(r.days_until_start(d(2024, 2, 25)), 5);
+        assert_eq!(r.days_until_end(d(2024, 2, 25)), 35);
+        assert_eq!(r.days_until_start(d(2024, 3, 10)), -9);
+        assert_eq!(r.days_until_end(d(2024, 3, 31)), 0);
+        assert_eq!(r.days_until_end(d(2024, 4, 2)), -2);
+    }
+
+    #[test]
+    fn split_on_cuts_at_effective_dates() {
+        let period = DateRange::new(d(2024, 3, 1), d(2024, 3, 15));
+        assert_eq!(
+            period.split_on(&[d(2024, 3, 11), d(2024, 2, 1), d(2024, 3, 6), d(2024, 3, 11)]),
+            vec![
+                DateRange::new(d(2024, 3, 1), d(2024, 3, 5)),
+                DateRange::new(d(2024, 3, 6), d(2024, 3, 10)),
+                DateRange::new(d(2024, 3, 11), d(2024, 3, 15)),
+            ]
+        );
+        assert_eq!(
+            period.split_on(&[d(2024, 3, 15)]),
+            vec![DateRange::new(d(2024, 3, 1), d(2024, 3, 14)), DateRange::new(d(2024, 3, 15), d(2024, 3, 15))]
+        );
+        assert_eq!(period.split_on(&[d(2024, 3, 1), d(2024, 3, 16)]), vec![period]);
+        assert_eq!(period.split_on(&[]), vec![period]);
+    }
+
+    #[test]
+    fn to_date_is_clamped_to_the_range() {
+        let month = DateRange::new(d(2024, 3, 1), d(2024, 3, 31));
+        assert_eq!(month.to_date(d(2024, 3, 14)), Some(DateRange::new(d(2024, 3, 1), d(2024, 3, 14))));
+        assert_eq!(month.to_date(d(2024, 3, 1)), Some(DateRange::new(d(2024, 3, 1), d(2024, 3, 1))));
+        assert_eq!(month.to_date(d(2024, 5, 1)), Some(month));
+        assert_eq!(month.to_date(d(2024, 2, 29)), None);
+    }
+
+    #[test]
+    fn every_nth_date_and_scale() {
+        let quarter = DateRange::new(d(2024, 1, 1), d(2024, 3, 31));
+        let check_ins = quarter.every_nth_date(7);
+        assert_eq!(check_ins.len(), 13);
+        assert_eq!(check_ins[1], d(2024, 1, 8));
+        assert_eq!(check_ins[12], d(2024, 3, 25));
+        assert_eq!(quarter.every_nth_date(91), vec![d(2024, 1, 1)]);
+        assert!(quarter.every_nth_date(0).is_empty());
+
+        let week = DateRange::new(d(2024, 2, 26), d(2024, 3, 3));
+        assert_eq!(week.scale(2), Some(DateRange::new(d(2024, 2, 26), d(2024, 3, 10))));
+        assert_eq!(week.scale(1), Some(week));
+        assert_eq!(week.scale(0), None);
+        assert_eq!(week.scale(usize::MAX), None);
+    }
+
+    #[test]
+    fn rolling_windows_trail_each_date() {
+        let r = DateRange::new(d(2024, 3, 1), d(2024, 3, 4));
+        let windows: Vec<DateRange> = r.rolling_windows(3).collect();
+        assert_eq!(
+            windows,
+            vec![
+                DateRange::new(d(2024, 2, 28), d(2024, 3, 1)),
+                DateRange::new(d(2024, 2, 29), d(2024, 3, 2)),
+                DateRange::new(d(2024, 3, 1), d(2024, 3, 3)),
+                DateRange::new(d(2024, 3, 2), d(2024, 3, 4)),
+            ]
+        );
+        assert!(windows.iter().all(|w| w.len() == 3));
+        assert_eq!(r.rolling_windows(1).collect::<Vec<_>>()[3], DateRange::new(d(2024, 3, 4), d(2024, 3, 4)));
+        assert_eq!(r.rolling_windows(0).count(), 0);
+    }
+
+    #[test]
+    fn paginate_covers_range_in_stable_chunks() {
+        let r = DateRange::new(d(2024, 1, 1), d(2024, 1, 31));
+        assert_eq!(r.page_count(7), 5);
+        assert_eq!(r.page_count(31), 1);
+        assert_eq!(r.page_count(0), 0);
+
+        assert_eq!(r.paginate(7, 0), Some(DateRange::new(d(2024, 1, 1), d(2024, 1, 7))));
+        assert_eq!(r.paginate(7, 3), Some(DateRange::new(d(2024, 1, 22), d(2024, 1, 28))));
+        assert_eq!(r.paginate(7, 4), Some(DateRange::new(d(2024, 1, 29), d(2024, 1, 31))));
+        assert_eq!(r.paginate(7, 5), None);
+        assert_eq!(r.paginate(0, 0), None);
+
+        let total: usize = (0..r.page_count(7)).map(|p| r.paginate(7, p).unwrap().len()).sum();
+        assert_eq!(total, r.len());
+    }
+
+    #[test]
+    fn paginate_with_a_chunk_larger_than_the_range_returns_the_whole_range() {
+        let r = DateRange::new(d(2024, 1, 1), d(2024, 1, 10));
+        assert_eq!(r.page_count(usize::MAX), 1);
+        assert_eq!(r.paginate(usize::MAX, 0), Some(r));
+        assert_eq!(r.paginate(300_000_000, 0), Some(r));
+        assert_eq!(r.paginate(usize::MAX, 1), None);
+
+        let last = DateRange::new(NaiveDate::MAX - Duration::days(9), NaiveDate::MAX);
+        assert_eq!(last.paginate(300_000_000, 0), Some(last));
+        assert_eq!(last.paginate(4, 2), Some(DateRange::new(NaiveDate::MAX - Duration::days(1), NaiveDate::MAX)));
+    }
+
+    #[test]
+    fn display_and_parse_iso_intervals() {
+        let january = DateRange::new(d(2023, 1, 1), d(2023, 1, 31));
+        assert_eq!(january.to_string(), "2023-01-01/2023-01-31");
+        assert_eq!("2023-01-01/2023-01-31".parse::<DateRange>(), Ok(january));
+        assert_eq!(january.to_string().parse::<DateRange>(), Ok(january));
+
+        for bad in ["2023-01-01", "2023-01-31/2023-01-01", "2023-01-01/P1M", "2023-02-30/2023-03-01", ""] {
+            assert_eq!(bad.parse::<DateRange>(), Err(RangeError::InvalidInterval(bad.to_string())));
+        }
+    }
+
     #[test]
     fn ordering_and_equality_semantics() {
         let a = DateRange::new(d(2023, 1, 1), d(2023, 1, 7));
@@ -0,0 +1,324 @@
+use crate::daterange::annual_date_range::AnnualDateRange;
+use crate::daterange::bi_weekly_date_range::BiWeeklyDateRange;
+use crate::daterange::date_range::DateRange;
+use crate::daterange::frequency::Frequency;
+use crate::daterange::monthly_date_range::MonthlyDateRange;
+use crate::daterange::quarterly_date_range::QuarterlyDateRange;
+use crate::daterange::semi_annual_date_range::SemiAnnualDateRange;
+use crate::daterange::semi_monthly_date_range::SemiMonthlyDateRange;
+use crate::daterange::weekly_date_range::WeeklyDateRange;
+use crate::dateutils::date_utils::last_day_of_month;
+use chrono::{Datelike, NaiveDate, Weekday};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Anchor {
+    Starting(NaiveDate),
+    Ending(NaiveDate),
+    Containing(NaiveDate),
+}
+
+/// Fluent entry point to the periodic range factories.
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use date_range::DateRange;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 3, 13).unwrap();
+/// let week = DateRange::builder().weekly().ending(Weekday::Sat).containing(date).build().unwrap();
+/// assert_eq!(week.start_date(), NaiveDate::from_ymd_opt(2024, 3, 10).unwrap());
+/// assert_eq!(week.end_date(), NaiveDate::from_ymd_opt(2024, 3, 16).unwrap());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DateRangeBuilder {
+    frequency: Option<Frequency>,
+    end_day: Weekday,
+    start_day: usize,
+    fiscal_start_month: u32,
+    anchor: Option<Anchor>,
+}
+
+impl DateRangeBuilder {
+    /// Creates a builder with weeks ending on Sunday, months starting on the 1st and years
+    /// starting in January.
+    pub fn new() -> Self {
+        Self {
+            frequency: None,
+            end_day: Weekday::Sun,
+            start_day: 1,
+            fiscal_start_month: 1,
+            anchor: None,
+        }
+    }
+
+    pub fn frequency(mut self, frequency: Frequency) -> Self {
+        self.frequency = Some(frequency);
+        self
+    }
+
+    pub fn weekly(self) -> Self {
+        self.frequency(Frequency::Weekly)
+    }
+
+    pub fn bi_weekly(self) -> Self {
+        self.frequency(Frequency::BiWeekly)
+    }
+
+    pub fn semi_monthly(self) -> Self {
+        self.frequency(Frequency::SemiMonthly)
+    }
+
+    pub fn monthly(self) -> Self {
+        self.frequency(Frequency::Monthly)
+    }
+
+    pub fn quarterly(self) -> Self {
+        self.frequency(Frequency::Quarterly)
+    }
+
+    pub fn semi_annual(self) -> Self {
+        self.frequency(Frequency::SemiAnnual)
+    }
+
+    pub fn annual(self) -> Self {
+        self.frequency(Frequency::Annual)
+    }
+
+    /// Set the weekday that weekly and bi-weekly ranges end on.
+    pub fn ending(mut self, end_day: Weekday) -> Self {
+        self.end_day = end_day;
+        self
+    }
+
+    /// Set the day of the month (1-31) that monthly ranges start on.
+    pub fn start_day(mut self, start_day: usize) -> Self {
+        self.start_day = start_day;
+        self
+    }
+
+    /// Set the month (1-12) that quarterly, semi-annual and annual ranges are aligned to.
+    pub fn fiscal_start(mut self, month: u32) -> Self {
+        self.fiscal_start_month = month;
+        self
+    }
+
+    /// Build the range that starts on a date. Semi-monthly ranges use the half-month containing
+    /// the date, and monthly ranges start on the day of the month of the date.
+    pub fn starting_on(mut self, date: NaiveDate) -> Self {
+        self.anchor = Some(Anchor::Starting(date));
+        self
+    }
+
+    /// Build the range that ends on a date. Semi-monthly ranges use the half-month containing
+    /// the date.
+    pub fn ending_on(mut self, date: NaiveDate) -> Self {
+        self.anchor = Some(Anchor::Ending(date));
+        self
+    }
+
+    /// Build the range that contains a date, aligned to the configured end day, start day or
+    /// fiscal start.
+    pub fn containing(mut self, date: NaiveDate) -> Self {
+        self.anchor = Some(Anchor::Containing(date));
+        self
+    }
+
+    /// Build the range, or None if the frequency or the date has not been set, or a monthly
+    /// range has a start day outside 1-31.
+    pub fn build(&self) -> Option<DateRange> {
+        let frequency = self.frequency?;
+        let anchor = self.anchor?;
+        if frequency == Frequency::Monthly && !(1..=31).contains(&self.start_day) {
+            return None;
+        }
+
+        let range = match (frequency, anchor) {
+            (Frequency::Weekly, Anchor::Starting(date)) => WeeklyDateRange::with_start_date(date),
+            (Frequency::Weekly, Anchor::Ending(date)) => WeeklyDateRange::with_end_date(date),
+            (Frequency::Weekly, Anchor::Containing(date)) => WeeklyDateRange::with_target_date(date, self.end_day),
+            (Frequency::BiWeekly, Anchor::Starting(date)) => BiWeeklyDateRange::with_start_date(date),
+            (Frequency::BiWeekly, Anchor::Ending(date)) => BiWeeklyDateRange::with_end_date(date),
+            (Frequency::BiWeekly, Anchor::Containing(date)) => BiWeeklyDateRange::with_target_date(date, self.end_day),
+            (
+                Frequency::SemiMonthly,
+                Anchor::Starting(date) | Anchor::Ending(date) | Anchor::Containing(date),
+            ) => {
+                let end_date = if date.day() <= 15 { date.with_day(15).unwrap() } else { last_day_of_month(date) };
+                SemiMonthlyDateRange::with_end_date(end_date)
+            }
+            (Frequency::Monthly, Anchor::Starting(date)) => {
+                MonthlyDateRange::containing_with_start_day(date, date.day() as usize)
+            }
+            (Frequency::Monthly, Anchor::Ending(date)) => {
+                MonthlyDateRange::with_end_date_and_start_day(date, self.start_day)
+            }
+            (Frequency::Monthly, Anchor::Containing(date)) => {
+                MonthlyDateRange::containing_with_start_day(date, self.start_day)
+            }
+            (Frequency::Quarterly, Anchor::Starting(date)) => QuarterlyDateRange::with_start_date(date),
+            (Frequency::Quarterly, Anchor::Ending(date)) => QuarterlyDateRange::with_end_date(date),
+            (Frequency::SemiAnnual, Anchor::Starting(date)) => SemiAnnualDateRange::with_start_date(date),
+            (Frequency::SemiAnnual, Anchor::Ending(date)) => SemiAnnualDateRange::with_end_date(date),
+            (Frequency::Annual, Anchor::Starting(date)) => AnnualDateRange::with_start_date(date),
+            (Frequency::Annual, Anchor::Ending(date)) => AnnualDateRange::with_end_date(date),
+            (Frequency::Quarterly | Frequency::SemiAnnual | Frequency::Annual, Anchor::Containing(date)) => {
+                let fiscal_start = NaiveDate::from_ymd_opt(date.year(), self.fiscal_start_month, 1)?;
+                let seed = match frequency {
+                    Frequency::Quarterly => QuarterlyDateRange::with_start_date(fiscal_start),
+                    Frequency::SemiAnnual => SemiAnnualDateRange::with_start_date(fiscal_start),
+                    _ => AnnualDateRange::with_start_date(fiscal_start),
+                };
+                seed.range_containing_date(date)
+            }
+        };
+
+        Some(range)
+    }
+}
+
+impl Default for DateRangeBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DateRangeBuilder;
+    use crate::daterange::date_range::DateRange;
+    use crate::daterange::frequency::Frequency;
+    use chrono::{Datelike, NaiveDate, Weekday};
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    fn bounds(range: DateRange) -> (NaiveDate, NaiveDate) {
+        (range.start_date(), range.end_date())
+    }
+
+    #[test]
+    fn build_requires_frequency_and_date() {
+        assert_eq!(DateRangeBuilder::new().containing(d(2024, 3, 13)).build(), None);
+        assert_eq!(DateRange::builder().monthly().build(), None);
+    }
+
+    #[test]
+    fn weekly_and_bi_weekly_follow_end_day() {
+        let week = DateRange::builder().weekly().ending(Weekday::Sat).containing(d(2024, 3, 13)).build().unwrap();
+        assert_eq!(bounds(week), (d(2024, 3, 10), d(2024, 3, 16)));
+        assert_eq!(bounds(week.next()), (d(2024, 3, 17), d(2024, 3, 23)));
+
+        let default_week = DateRange::builder().weekly().containing(d(2024, 3, 13)).build().unwrap();
+        assert_eq!(default_week.end_date().weekday(), Weekday::Sun);
+
+        let two_weeks = DateRange::builder().bi_weekly().starting_on(d(2024, 3, 4)).build().unwrap();
+        assert_eq!(bounds(two_weeks), (d(2024, 3, 4), d(2024, 3, 17)));
+    }
+
+    #[test]
+    fn semi_monthly_uses_half_month_containing_date() {
+        let first = DateRange::builder().semi_monthly().containing(d(2024, 2, 10)).build().unwrap();
+        assert_eq!(bounds(first), (d(2024, 2, 1), d(2024, 2, 15)));
+
+        let second = DateRange::builder().semi_monthly().starting_on(d(2024, 2, 20)).build().unwrap();
+        assert_eq!(bounds(second), (d(2024, 2, 16), d(2024, 2, 29)));
+        assert_eq!(bounds(second.next()), (d(2024, 3, 1), d(2024, 3, 15)));
+    }
+
+    #[test]
+    fn monthly_honors_start_day() {
+        let calendar = DateRange::builder().monthly().containing(d(2024, 2, 10)).build().unwrap();
+        assert_eq!(bounds(calendar), (d(2024, 2, 1), d(2024, 2, 29)));
+
+        let sixteenth = DateRange::builder().monthly().start_day(16).containing(d(2024, 2, 10)).build().unwrap();
+        assert_eq!(bounds(sixteenth), (d(2024, 1, 16), d(2024, 2, 15)));
+        assert_eq!(sixteenth.start_day(), Some(16));
+
+        let late = |start_day: usize, date: NaiveDate| {
+            DateRange::builder().monthly().start_day(start_day).containing(date).build().unwrap()
+        };
+        assert_eq!(bounds(late(29, d(2023, 2, 20))), (d(2023, 1, 29), d(2023, 2, 27)));
+        assert_eq!(bounds(late(30, d(2023, 2, 20))), (d(2023, 1, 30), d(2023, 2, 27)));
+        assert_eq!(bounds(late(31, d(2023, 2, 20))), (d(2023, 1, 31), d(2023, 2, 27)));
+        assert_eq!(bounds(late(29, d(2023, 2, 28))), (d(2023, 2, 28), d(2023, 3, 28)));
+        assert_eq!(bounds(late(31, d(2024, 2, 29))), (d(2024, 2, 29), d(2024, 3, 30)));
+        for start_day in 29..=31 {
+            let range = late(start_day, d(2024, 2, 15));
+            assert_eq!(range.prior().next(), range);
+            assert_eq!(range.next().prior(), range);
+        }
+
+        let starting = DateRange::builder().frequency(Frequency::Monthly).starting_on(d(2024, 3, 5)).build().unwrap();
+        assert_eq!(bounds(starting), (d(2024, 3, 5), d(2024, 4, 4)));
+    }
+
+    #[test]
+    fn monthly_starting_on_late_days_keeps_the_start() {
+        let starting = |date: NaiveDate| DateRange::builder().monthly().starting_on(date).build().unwrap();
+        assert_eq!(bounds(starting(d(2024, 1, 29))), (d(2024, 1, 29), d(2024, 2, 28)));
+        assert_eq!(bounds(starting(d(2024, 1, 30))), (d(2024, 1, 30), d(2024, 2, 28)));
+        assert_eq!(bounds(starting(d(2024, 1, 31))), (d(2024, 1, 31), d(2024, 2, 28)));
+        assert_eq!(bounds(starting(d(2023, 3, 31))), (d(2023, 3, 31), d(2023, 4, 29)));
+
+        let range = starting(d(2024, 1, 31));
+        assert_eq!(range.start_day(), Some(31));
+        assert_eq!(bounds(range.next()), (d(2024, 2, 29), d(2024, 3, 30)));
+        assert_eq!(range.next().prior(), range);
+    }
+
+    #[test]
+    fn monthly_start_day_must_be_a_day_of_the_month() {
+        for start_day in [0, 32, 40] {
+            let builder = DateRange::builder().monthly().start_day(start_day);
+            assert_eq!(builder.ending_on(d(2024, 3, 15)).build(), None, "{}", start_day);
+            assert_eq!(builder.starting_on(d(2024, 3, 15)).build(), None, "{}", start_day);
+            assert_eq!(builder.containing(d(2024, 3, 15)).build(), None, "{}", start_day);
+        }
+        assert!(DateRange::builder().weekly().start_day(0).containing(d(2024, 3, 15)).build().is_some());
+    }
+
+    #[test]
+    fn quarterly_semi_annual_and_annual_follow_fiscal_start() {
+        let quarter = DateRange::builder().quarterly().containing(d(2024, 5, 20)).build().unwrap();
+        assert_eq!(bounds(quarter), (d(2024, 4, 1), d(2024, 6, 30)));
+
+        let fiscal_quarter =
+            DateRange::builder().quarterly().fiscal_start(2).containing(d(2024, 1, 20)).build().unwrap();
+        assert_eq!(bounds(fiscal_quarter), (d(2023, 11, 1), d(2024, 1, 31)));
+
+        let half = DateRange::builder().semi_annual().fiscal_start(7).containing(d(2024, 5, 20)).build().unwrap();
+        assert_eq!(bounds(half), (d(2024, 1, 1), d(2024, 6, 30)));
+
+        let year = DateRange::builder().annual().fiscal_start(10).containing(d(2024, 5, 20)).build().unwrap();
+        assert_eq!(bounds(year), (d(2023, 10, 1), d(2024, 9, 30)));
+
+        let ending = DateRange::builder().annual().ending_on(d(2024, 6, 30)).build().unwrap();
+        assert_eq!(bounds(ending), (d(2023, 7, 1), d(2024, 6, 30)));
+    }
+
+    #[test]
+    fn built_ranges_carry_their_frequency_through_navigation() {
+        let frequencies = [
+            Frequency::Weekly,
+            Frequency::BiWeekly,
+            Frequency::SemiMonthly,
+            Frequency::Monthly,
+            Frequency::Quarterly,
+            Frequency::SemiAnnual,
+            Frequency::Annual,
+        ];
+        for frequency in frequencies {
+            let range = DateRange::builder().frequency(frequency).containing(d(2024, 5, 20)).build().unwrap();
+            assert_eq!(range.frequency(), Some(frequency));
+            assert_eq!(range.next().frequency(), Some(frequency));
+            assert_eq!(range.prior_n(3).frequency(), Some(frequency));
+            assert_eq!(range.range_containing_date(d(2030, 1, 1)).frequency(), Some(frequency));
+        }
+        assert_eq!(DateRange::new(d(2024, 5, 1), d(2024, 5, 31)).frequency(), None);
+    }
+
+    #[test]
+    fn invalid_fiscal_start_builds_nothing() {
+        assert_eq!(DateRange::builder().annual().fiscal_start(13).containing(d(2024, 5, 20)).build(), None);
+    }
+}
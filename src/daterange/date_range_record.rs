@@ -0,0 +1,87 @@
+use crate::daterange::date_range::DateRange;
+use chrono::NaiveDate;
+use alloc::string::{String, ToString};
+
+/// A flat, plain-value view of a DateRange for CSV and columnar exports.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DateRangeRecord {
+    /// The start date as `YYYY-MM-DD`.
+    pub start_date: String,
+    /// The end date as `YYYY-MM-DD`.
+    pub end_date: String,
+    /// The start date as days since 1970-01-01.
+    pub start_epoch_day: i64,
+    /// The end date as days since 1970-01-01.
+    pub end_epoch_day: i64,
+    /// The number of days in the range, inclusive.
+    pub days: usize,
+}
+
+impl DateRange {
+    /// Get a flat record of the range for exporting.
+    pub fn to_record(&self) -> DateRangeRecord {
+        DateRangeRecord {
+            start_date: self.start_date().format("%Y-%m-%d").to_string(),
+            end_date: self.end_date().format("%Y-%m-%d").to_string(),
+            start_epoch_day: epoch_day(self.start_date()),
+            end_epoch_day: epoch_day(self.end_date()),
+            days: self.len(),
+        }
+    }
+
+    /// Rebuild a range from the ISO dates of a record, or None if they can't be parsed or the
+    /// end is before the start. The epoch and length columns are derived values and are ignored.
+    pub fn from_record(record: &DateRangeRecord) -> Option<DateRange> {
+        let start = NaiveDate::parse_from_str(&record.start_date, "%Y-%m-%d").ok()?;
+        let end = NaiveDate::parse_from_str(&record.end_date, "%Y-%m-%d").ok()?;
+        (start <= end).then(|| DateRange::new(start, end))
+    }
+}
+
+fn epoch_day(date: NaiveDate) -> i64 {
+    (date - NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()).num_days()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DateRangeRecord;
+    use crate::daterange::date_range::DateRange;
+    use chrono::NaiveDate;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn to_record_flattens_bounds() {
+        let record = DateRange::new(d(2024, 3, 1), d(2024, 3, 31)).to_record();
+        assert_eq!(
+            record,
+            DateRangeRecord {
+                start_date: "2024-03-01".to_string(),
+                end_date: "2024-03-31".to_string(),
+                start_epoch_day: 19783,
+                end_epoch_day: 19813,
+                days: 31,
+            }
+        );
+    }
+
+    #[test]
+    fn from_record_round_trips_and_rejects_bad_input() {
+        let range = DateRange::new(d(1969, 12, 25), d(1970, 1, 5));
+        let record = range.to_record();
+        assert_eq!(record.start_epoch_day, -7);
+        assert_eq!(DateRange::from_record(&record), Some(range));
+
+        let reversed = DateRangeRecord {
+            start_date: record.end_date.clone(),
+            end_date: record.start_date.clone(),
+            ..record.clone()
+        };
+        assert_eq!(DateRange::from_record(&reversed), None);
+
+        let garbled = DateRangeRecord { start_date: "2024-13-01".to_string(), ..record };
+        assert_eq!(DateRange::from_record(&garbled), None);
+    }
+}
@@ -0,0 +1,256 @@
+use crate::daterange::date_range::DateRange;
+use chrono::{Datelike, Duration, NaiveDate};
+use alloc::vec::Vec;
+
+/// The `num_days_from_ce` of 1970-01-01.
+const UNIX_EPOCH_DAYS_FROM_CE: i64 = 719_163;
+
+/// A compact set of dates stored as one bit per day, keyed by days since the Unix epoch. The
+/// bitmap covers the span from its earliest to its latest date, so it suits large, dense sets.
+#[derive(Clone, Debug, Default)]
+pub struct DayBitmap {
+    /// The index of the first word, in 64-day blocks from the epoch.
+    first_word: i64,
+    words: Vec<u64>,
+}
+
+impl DayBitmap {
+    pub fn new() -> Self {
+        Self { first_word: 0, words: Vec::new() }
+    }
+
+    /// Create a bitmap holding every date of some ranges.
+    pub fn from_ranges(ranges: &[DateRange]) -> Self {
+        let mut bitmap = Self::new();
+        for range in ranges {
+            bitmap.insert_range(range);
+        }
+        bitmap
+    }
+
+    /// Check if a date is in the set.
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        let (word, bit) = position(date);
+        self.word_index(word).is_some_and(|index| self.words[index] & (1 << bit) != 0)
+    }
+
+    /// Add a date, returning true if it was not already in the set.
+    pub fn insert(&mut self, date: NaiveDate) -> bool {
+        let (word, bit) = position(date);
+        let index = self.reserve(word, word);
+        let added = self.words[index] & (1 << bit) == 0;
+        self.words[index] |= 1 << bit;
+        added
+    }
+
+    /// Add every date of a range.
+    pub fn insert_range(&mut self, range: &DateRange) {
+        let (first_word, first_bit) = position(range.start_date());
+        let (last_word, last_bit) = position(range.end_date());
+        let start = self.reserve(first_word, last_word);
+        for word in first_word..=last_word {
+            let low = if word == first_word { first_bit } else { 0 };
+            let high = if word == last_word { last_bit } else { 63 };
+            self.words[start + (word - first_word) as usize] |= (u64::MAX >> (63 - high)) & (u64::MAX << low);
+        }
+    }
+
+    /// Remove a date, returning true if it was in the set.
+    pub fn remove(&mut self, date: NaiveDate) -> bool {
+        let (word, bit) = position(date);
+        match self.word_index(word) {
+            Some(index) if self.words[index] & (1 << bit) != 0 => {
+                self.words[index] &= !(1 << bit);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Get the number of dates in the set.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    /// Get the dates in both sets.
+    pub fn intersection(&self, other: &DayBitmap) -> DayBitmap {
+        let first_word = self.first_word.max(other.first_word);
+        let end_word = self.end_word().min(other.end_word());
+        let words = (first_word..end_word.max(first_word))
+            .map(|word| self.word(word) & other.word(word))
+            .collect();
+        DayBitmap { first_word, words }
+    }
+
+    /// Get the dates in either set.
+    pub fn union(&self, other: &DayBitmap) -> DayBitmap {
+        if self.words.is_empty() {
+            return other.clone();
+        }
+        if other.words.is_empty() {
+            return self.clone();
+        }
+        let first_word = self.first_word.min(other.first_word);
+        let end_word = self.end_word().max(other.end_word());
+        let words = (first_word..end_word).map(|word| self.word(word) | other.word(word)).collect();
+        DayBitmap { first_word, words }
+    }
+
+    /// Get the dates in this set but not the other.
+    pub fn difference(&self, other: &DayBitmap) -> DayBitmap {
+        let words = (self.first_word..self.end_word()).map(|word| self.word(word) & !other.word(word)).collect();
+        DayBitmap { first_word: self.first_word, words }
+    }
+
+    /// Get the dates in the set in order.
+    pub fn iter(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.words.iter().enumerate().flat_map(move |(index, word)| {
+            let base = (self.first_word + index as i64) * 64;
+            (0..64).filter(move |bit| word & (1 << bit) != 0).map(move |bit| date_at(base + bit))
+        })
+    }
+
+    /// Get the set as the fewest ranges of consecutive dates, in order.
+    pub fn to_ranges(&self) -> Vec<DateRange> {
+        let mut ranges: Vec<DateRange> = Vec::new();
+        for date in self.iter() {
+            match ranges.last_mut() {
+                Some(last) if last.end_date() + Duration::days(1) == date => {
+                    *last = DateRange::new(last.start_date(), date);
+                }
+                _ => ranges.push(DateRange::new(date, date)),
+            }
+        }
+        ranges
+    }
+
+    /// Get the first word and the words between the first and last that hold any dates.
+    fn trimmed(&self) -> (i64, &[u64]) {
+        let Some(first) = self.words.iter().position(|word| *word != 0) else {
+            return (0, &[]);
+        };
+        let last = self.words.iter().rposition(|word| *word != 0).unwrap();
+        (self.first_word + first as i64, &self.words[first..=last])
+    }
+
+    fn end_word(&self) -> i64 {
+        self.first_word + self.words.len() as i64
+    }
+
+    fn word(&self, word: i64) -> u64 {
+        self.word_index(word).map_or(0, |index| self.words[index])
+    }
+
+    fn word_index(&self, word: i64) -> Option<usize> {
+        (word >= self.first_word && word < self.end_word()).then(|| (word - self.first_word) as usize)
+    }
+
+    /// Grow the bitmap to cover a span of words, returning the index of the first.
+    fn reserve(&mut self, first_word: i64, last_word: i64) -> usize {
+        if self.words.is_empty() {
+            self.first_word = first_word;
+        }
+        if first_word < self.first_word {
+            let extra = (self.first_word - first_word) as usize;
+            self.words.splice(0..0, core::iter::repeat_n(0, extra));
+            self.first_word = first_word;
+        }
+        if last_word >= self.end_word() {
+            let len = (last_word - self.first_word + 1) as usize;
+            self.words.resize(len, 0);
+        }
+        (first_word - self.first_word) as usize
+    }
+}
+
+impl PartialEq for DayBitmap {
+    fn eq(&self, other: &Self) -> bool {
+        self.trimmed() == other.trimmed()
+    }
+}
+
+impl Eq for DayBitmap {}
+
+impl FromIterator<NaiveDate> for DayBitmap {
+    fn from_iter<I: IntoIterator<Item = NaiveDate>>(iter: I) -> Self {
+        let mut bitmap = Self::new();
+        for date in iter {
+            bitmap.insert(date);
+        }
+        bitmap
+    }
+}
+
+fn position(date: NaiveDate) -> (i64, u32) {
+    let day = date.num_days_from_ce() as i64 - UNIX_EPOCH_DAYS_FROM_CE;
+    (day.div_euclid(64), day.rem_euclid(64) as u32)
+}
+
+fn date_at(day: i64) -> NaiveDate {
+    NaiveDate::from_num_days_from_ce_opt((day + UNIX_EPOCH_DAYS_FROM_CE) as i32).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DayBitmap;
+    use crate::daterange::date_range::DateRange;
+    use chrono::{Duration, NaiveDate};
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn membership_and_counting() {
+        let mut bitmap = DayBitmap::new();
+        assert!(bitmap.is_empty());
+        assert!(bitmap.insert(d(2024, 3, 1)));
+        assert!(!bitmap.insert(d(2024, 3, 1)));
+        assert!(bitmap.insert(d(1969, 12, 31)));
+        assert!(bitmap.insert(d(2100, 1, 1)));
+        assert_eq!(bitmap.len(), 3);
+        assert!(bitmap.contains(d(1969, 12, 31)));
+        assert!(!bitmap.contains(d(1970, 1, 1)));
+        assert!(bitmap.remove(d(2100, 1, 1)));
+        assert!(!bitmap.remove(d(2100, 1, 1)));
+        assert_eq!(bitmap.iter().collect::<Vec<_>>(), vec![d(1969, 12, 31), d(2024, 3, 1)]);
+    }
+
+    #[test]
+    fn converts_to_and_from_ranges() {
+        let ranges = [
+            DateRange::new(d(2024, 1, 1), d(2024, 12, 31)),
+            DateRange::new(d(2025, 1, 1), d(2025, 1, 3)),
+            DateRange::new(d(2023, 6, 1), d(2023, 6, 1)),
+        ];
+        let bitmap = DayBitmap::from_ranges(&ranges);
+        assert_eq!(bitmap.len(), 366 + 3 + 1);
+        assert_eq!(
+            bitmap.to_ranges(),
+            vec![DateRange::new(d(2023, 6, 1), d(2023, 6, 1)), DateRange::new(d(2024, 1, 1), d(2025, 1, 3))]
+        );
+        let collected: DayBitmap = ranges[1].iter().collect();
+        assert_eq!(collected.to_ranges(), vec![ranges[1]]);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = DayBitmap::from_ranges(&[DateRange::new(d(2024, 1, 1), d(2024, 6, 30))]);
+        let b = DayBitmap::from_ranges(&[DateRange::new(d(2024, 6, 1), d(2024, 9, 30))]);
+        assert_eq!(a.intersection(&b).to_ranges(), vec![DateRange::new(d(2024, 6, 1), d(2024, 6, 30))]);
+        assert_eq!(a.union(&b).to_ranges(), vec![DateRange::new(d(2024, 1, 1), d(2024, 9, 30))]);
+        assert_eq!(a.difference(&b).to_ranges(), vec![DateRange::new(d(2024, 1, 1), d(2024, 5, 31))]);
+        assert!(a.intersection(&DayBitmap::from_ranges(&[DateRange::new(d(2030, 1, 1), d(2030, 1, 1))])).is_empty());
+
+        let big = DayBitmap::from_ranges(&[DateRange::new(d(1900, 1, 1), d(3199, 12, 31))]);
+        let every_other: DayBitmap = (0..200_000).map(|i| d(2000, 1, 1) + Duration::days(2 * i)).collect();
+        assert_eq!(big.intersection(&every_other).len(), 200_000);
+        assert_eq!(DayBitmap::new().union(&a), a);
+        assert_eq!(a.union(&b).difference(&b), a.difference(&b));
+        assert_eq!(a.difference(&a), DayBitmap::new());
+    }
+}
@@ -0,0 +1,140 @@
+use crate::daterange::date_range::DateRange;
+use chrono::{Duration, NaiveDate};
+use alloc::vec::Vec;
+
+/// Values that are effective over non-overlapping date ranges, such as pay rates or tax tables.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EffectiveDated<T> {
+    entries: Vec<(DateRange, T)>,
+}
+
+impl<T: Clone> EffectiveDated<T> {
+    pub fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    /// Get the entries, sorted by start date.
+    pub fn entries(&self) -> &[(DateRange, T)] {
+        &self.entries
+    }
+
+    /// Make a value effective over a date range. Any existing entries that overlap the range
+    /// are trimmed or split so the new value takes effect over the whole range.
+    pub fn insert(&mut self, date_range: DateRange, value: T) {
+        let mut entries = Vec::with_capacity(self.entries.len() + 2);
+        for (existing, existing_value) in self.entries.drain(..) {
+            if !existing.overlaps(&date_range) {
+                entries.push((existing, existing_value));
+                continue;
+            }
+            if existing.start_date() < date_range.start_date() {
+                let end = date_range.start_date() - Duration::days(1);
+                entries.push((DateRange::new(existing.start_date(), end), existing_value.clone()));
+            }
+            if existing.end_date() > date_range.end_date() {
+                let start = date_range.end_date() + Duration::days(1);
+                entries.push((DateRange::new(start, existing.end_date()), existing_value));
+            }
+        }
+        entries.push((date_range, value));
+        entries.sort_by_key(|(range, _)| range.start_date());
+        self.entries = entries;
+    }
+
+    /// Get the value effective on a date.
+    pub fn value_at(&self, date: NaiveDate) -> Option<&T> {
+        let index = self.entries.partition_point(|(range, _)| range.start_date() <= date);
+        if index == 0 {
+            return None;
+        }
+        let (range, value) = &self.entries[index - 1];
+        if range.contains_date(date) { Some(value) } else { None }
+    }
+
+    /// Split a date range at each date where the effective value changes, returning the
+    /// pieces that have a value along with that value. Parts of the range with no value are omitted.
+    pub fn split_on_change(&self, date_range: &DateRange) -> Vec<(DateRange, &T)> {
+        self.entries
+            .iter()
+            .filter(|(range, _)| range.overlaps(date_range))
+            .map(|(range, value)| {
+                let start = range.start_date().max(date_range.start_date());
+                let end = range.end_date().min(date_range.end_date());
+                (DateRange::new(start, end), value)
+            })
+            .collect()
+    }
+}
+
+impl<T: Clone> Default for EffectiveDated<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EffectiveDated;
+    use crate::daterange::date_range::DateRange;
+    use chrono::NaiveDate;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn insert_splits_existing_entry_around_new_range() {
+        let mut rates = EffectiveDated::new();
+        rates.insert(DateRange::new(d(2024, 1, 1), d(2024, 12, 31)), 20);
+        rates.insert(DateRange::new(d(2024, 4, 1), d(2024, 6, 30)), 25);
+
+        assert_eq!(rates.entries(), &[
+            (DateRange::new(d(2024, 1, 1), d(2024, 3, 31)), 20),
+            (DateRange::new(d(2024, 4, 1), d(2024, 6, 30)), 25),
+            (DateRange::new(d(2024, 7, 1), d(2024, 12, 31)), 20),
+        ]);
+    }
+
+    #[test]
+    fn insert_trims_and_replaces_overlapped_entries() {
+        let mut rates = EffectiveDated::new();
+        rates.insert(DateRange::new(d(2024, 1, 1), d(2024, 1, 31)), "a");
+        rates.insert(DateRange::new(d(2024, 2, 1), d(2024, 2, 29)), "b");
+        rates.insert(DateRange::new(d(2024, 3, 1), d(2024, 3, 31)), "c");
+        rates.insert(DateRange::new(d(2024, 1, 20), d(2024, 3, 10)), "x");
+
+        assert_eq!(rates.entries(), &[
+            (DateRange::new(d(2024, 1, 1), d(2024, 1, 19)), "a"),
+            (DateRange::new(d(2024, 1, 20), d(2024, 3, 10)), "x"),
+            (DateRange::new(d(2024, 3, 11), d(2024, 3, 31)), "c"),
+        ]);
+    }
+
+    #[test]
+    fn value_at_finds_effective_value_or_none_in_gaps() {
+        let mut rates = EffectiveDated::new();
+        rates.insert(DateRange::new(d(2024, 1, 1), d(2024, 1, 31)), 10);
+        rates.insert(DateRange::new(d(2024, 3, 1), d(2024, 3, 31)), 30);
+
+        assert_eq!(rates.value_at(d(2023, 12, 31)), None);
+        assert_eq!(rates.value_at(d(2024, 1, 1)), Some(&10));
+        assert_eq!(rates.value_at(d(2024, 1, 31)), Some(&10));
+        assert_eq!(rates.value_at(d(2024, 2, 15)), None);
+        assert_eq!(rates.value_at(d(2024, 3, 31)), Some(&30));
+        assert_eq!(rates.value_at(d(2024, 4, 1)), None);
+    }
+
+    #[test]
+    fn split_on_change_clips_entries_to_requested_range() {
+        let mut rates = EffectiveDated::new();
+        rates.insert(DateRange::new(d(2024, 1, 1), d(2024, 3, 14)), 10);
+        rates.insert(DateRange::new(d(2024, 3, 15), d(2024, 12, 31)), 12);
+
+        let pay_period = DateRange::new(d(2024, 3, 1), d(2024, 3, 31));
+        let pieces = rates.split_on_change(&pay_period);
+        assert_eq!(pieces, vec![
+            (DateRange::new(d(2024, 3, 1), d(2024, 3, 14)), &10),
+            (DateRange::new(d(2024, 3, 15), d(2024, 3, 31)), &12),
+        ]);
+    }
+}
@@ -0,0 +1,42 @@
+use crate::dateutils::date_utils::{add_days, first_of_month_following};
+use chrono::NaiveDate;
+
+/// A rule that determines when someone becomes eligible, relative to a start date.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EligibilityRule {
+    /// Eligible on the start date.
+    Immediate,
+    /// Eligible once the given number of days have passed since the start date.
+    AfterDays(i64),
+    /// Eligible on the first of the month on or after the given number of days have passed.
+    FirstOfMonthFollowing(i64),
+}
+
+impl EligibilityRule {
+    /// Get the date eligibility begins for the given start date.
+    pub fn eligibility_date(&self, start_date: NaiveDate) -> NaiveDate {
+        match *self {
+            EligibilityRule::Immediate => start_date,
+            EligibilityRule::AfterDays(days) => add_days(start_date, days),
+            EligibilityRule::FirstOfMonthFollowing(days) => first_of_month_following(start_date, days),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EligibilityRule;
+    use chrono::NaiveDate;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn eligibility_date_for_each_rule() {
+        let hire = d(2024, 1, 10);
+        assert_eq!(EligibilityRule::Immediate.eligibility_date(hire), hire);
+        assert_eq!(EligibilityRule::AfterDays(30).eligibility_date(hire), d(2024, 2, 9));
+        assert_eq!(EligibilityRule::FirstOfMonthFollowing(60).eligibility_date(hire), d(2024, 4, 1));
+    }
+}
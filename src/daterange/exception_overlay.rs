@@ -0,0 +1,120 @@
+use crate::daterange::date_range::DateRange;
+use chrono::NaiveDate;
+use alloc::vec::Vec;
+
+/// What to do with a period that overlaps a blackout range.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BlackoutPolicy {
+    /// Remove the period.
+    Skip,
+    /// Extend the period by absorbing the periods that follow it until every blackout it
+    /// overlaps falls entirely within it.
+    Extend,
+}
+
+/// Applies blackout ranges to the periods generated by a periodic DateRange.
+#[derive(Clone, Debug)]
+pub struct ExceptionOverlay {
+    blackouts: Vec<DateRange>,
+    policy: BlackoutPolicy,
+}
+
+impl ExceptionOverlay {
+    pub fn new(blackouts: Vec<DateRange>, policy: BlackoutPolicy) -> Self {
+        Self { blackouts, policy }
+    }
+
+    /// Get the blackout ranges.
+    pub fn blackouts(&self) -> &[DateRange] {
+        &self.blackouts
+    }
+
+    /// Get the policy applied to colliding periods.
+    pub fn policy(&self) -> BlackoutPolicy {
+        self.policy
+    }
+
+    /// Check if a period overlaps any blackout range.
+    pub fn collides(&self, date_range: &DateRange) -> bool {
+        date_range.overlaps_any(&self.blackouts)
+    }
+
+    /// Get `number` periods starting with `first`, following its next() chain and applying
+    /// the blackout policy to each period.
+    pub fn periods_from(&self, first: &DateRange, number: usize) -> Vec<DateRange> {
+        let mut periods = Vec::with_capacity(number);
+        let mut current = *first;
+        while periods.len() < number {
+            if !self.collides(&current) {
+                periods.push(current);
+            } else if self.policy == BlackoutPolicy::Extend {
+                let start = current.start_date();
+                while let Some(end) = self.uncovered_blackout_end(start, current.end_date()) {
+                    while current.end_date() < end {
+                        current = current.next();
+                    }
+                }
+                periods.push(DateRange::new(start, current.end_date()));
+            }
+            current = current.next();
+        }
+        periods
+    }
+
+    fn uncovered_blackout_end(&self, start: NaiveDate, end: NaiveDate) -> Option<NaiveDate> {
+        let range = DateRange::new(start, end);
+        self.blackouts
+            .iter()
+            .filter(|blackout| range.overlaps(blackout) && blackout.end_date() > end)
+            .map(|blackout| blackout.end_date())
+            .max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BlackoutPolicy, ExceptionOverlay};
+    use crate::daterange::date_range::DateRange;
+    use crate::daterange::semi_monthly_date_range::SemiMonthlyDateRange;
+    use chrono::NaiveDate;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn skip_removes_colliding_periods() {
+        let shutdown = DateRange::new(d(2023, 12, 20), d(2024, 1, 2));
+        let overlay = ExceptionOverlay::new(vec![shutdown], BlackoutPolicy::Skip);
+        let first = SemiMonthlyDateRange::with_end_date(d(2023, 12, 15));
+
+        let periods = overlay.periods_from(&first, 3);
+        assert_eq!(periods, vec![
+            DateRange::new(d(2023, 12, 1), d(2023, 12, 15)),
+            DateRange::new(d(2024, 1, 16), d(2024, 1, 31)),
+            DateRange::new(d(2024, 2, 1), d(2024, 2, 15)),
+        ]);
+        assert!(overlay.collides(&DateRange::new(d(2023, 12, 16), d(2023, 12, 31))));
+    }
+
+    #[test]
+    fn extend_absorbs_following_periods_until_blackout_is_covered() {
+        let shutdown = DateRange::new(d(2023, 12, 20), d(2024, 1, 2));
+        let overlay = ExceptionOverlay::new(vec![shutdown], BlackoutPolicy::Extend);
+        let first = SemiMonthlyDateRange::with_end_date(d(2023, 12, 15));
+
+        let periods = overlay.periods_from(&first, 3);
+        assert_eq!(periods, vec![
+            DateRange::new(d(2023, 12, 1), d(2023, 12, 15)),
+            DateRange::new(d(2023, 12, 16), d(2024, 1, 15)),
+            DateRange::new(d(2024, 1, 16), d(2024, 1, 31)),
+        ]);
+    }
+
+    #[test]
+    fn no_blackouts_follows_the_underlying_chain() {
+        let overlay = ExceptionOverlay::new(vec![], BlackoutPolicy::Skip);
+        let first = SemiMonthlyDateRange::with_end_date(d(2023, 12, 15));
+        assert_eq!(overlay.periods_from(&first, 2), first.ranges_after_inclusive(1));
+    }
+}
@@ -0,0 +1,34 @@
+/// How often a periodic range repeats.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Frequency {
+    /// Seven-day periods ending on a chosen weekday.
+    Weekly,
+    /// Fourteen-day periods ending on a chosen weekday.
+    BiWeekly,
+    /// The 1st through the 15th, and the 16th through the end of the month.
+    SemiMonthly,
+    /// Month-long periods starting on a chosen day of the month.
+    Monthly,
+    /// Three-month periods.
+    Quarterly,
+    /// Six-month periods.
+    SemiAnnual,
+    /// Year-long periods.
+    Annual,
+}
+
+impl Frequency {
+    /// Get the usual number of periods in a year. Weekly and bi-weekly calendars occasionally
+    /// have one more.
+    pub fn nominal_periods_per_year(&self) -> usize {
+        match self {
+            Frequency::Weekly => 52,
+            Frequency::BiWeekly => 26,
+            Frequency::SemiMonthly => 24,
+            Frequency::Monthly => 12,
+            Frequency::Quarterly => 4,
+            Frequency::SemiAnnual => 2,
+            Frequency::Annual => 1,
+        }
+    }
+}
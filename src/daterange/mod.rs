@@ -6,3 +6,38 @@ pub mod monthly_date_range;
 pub mod quarterly_date_range;
 pub mod annual_date_range;
 pub mod semi_annual_date_range;
+pub mod eligibility_rule;
+pub mod exception_overlay;
+pub mod effective_dated;
+pub mod accounting_calendar;
+pub mod anchor_drift;
+pub mod billing_cycle;
+#[cfg(feature = "bigdecimal")]
+pub mod budget_spread;
+pub mod carryover;
+pub mod clipped_range;
+pub mod day_bitmap;
+pub mod comparable_policy;
+pub mod comparison_set;
+pub mod tenor;
+pub mod term;
+pub mod tagged;
+pub mod timeline;
+pub mod rate_bands;
+pub mod period_mapping;
+pub mod period_calendar;
+pub mod period_table;
+pub mod range_cursor;
+pub mod period_status;
+pub mod week_year_policy;
+pub mod range_error;
+pub mod periodicity_invariants;
+#[cfg(feature = "std")]
+pub mod cached_date_range;
+pub mod period_cache;
+pub mod frequency;
+pub mod date_range_builder;
+pub mod date_range_record;
+pub mod period_id;
+pub mod partition_key;
+pub mod range_spec;
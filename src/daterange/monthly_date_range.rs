@@ -1,5 +1,7 @@
+use crate::daterange::billing_cycle::anchored_date;
 use crate::daterange::date_range::DateRange;
-use crate::dateutils::date_utils::{add_months, last_day_of_month, subtract_months};
+use crate::daterange::frequency::Frequency;
+use crate::dateutils::date_utils::{add_months, add_months_eom, first_day_of_month, last_day_of_month, subtract_months};
 use chrono::{Datelike, Duration, Months, NaiveDate};
 
 pub struct MonthlyDateRange;
@@ -16,7 +18,49 @@ impl MonthlyDateRange {
                                                  end_date,
                                                  MonthlyDateRange::prior,
                                                  MonthlyDateRange::next,
-                                                 Some(start_day))
+                                                 Some(start_day)).with_frequency(Frequency::Monthly)
+    }
+
+    /// Create a monthly range with known bounds on the chain for a start day.
+    pub(crate) fn with_bounds_and_start_day(start_date: NaiveDate, end_date: NaiveDate, start_day: usize) -> DateRange {
+        DateRange::new_with_prior_next_start_day(start_date,
+                                                 end_date,
+                                                 MonthlyDateRange::prior,
+                                                 MonthlyDateRange::next,
+                                                 Some(start_day)).with_frequency(Frequency::Monthly)
+    }
+
+    /// Create the monthly range on the chain for a start day that contains a date.
+    pub(crate) fn containing_with_start_day(date: NaiveDate, start_day: usize) -> DateRange {
+        let boundary = anchored_date(date, start_day);
+        let start_date = if boundary <= date {
+            boundary
+        } else {
+            anchored_date(subtract_months(first_day_of_month(date), 1), start_day)
+        };
+        let end_date = boundary_after(start_date, start_day) - Duration::days(1);
+        Self::with_bounds_and_start_day(start_date, end_date, start_day)
+    }
+
+    /// Create a monthly range ending on a date, stepping the end date with `add_months_eom` so
+    /// that ends on the last day of a month stay on the last day of later months. An end that
+    /// isn't a month end moves onto one once clamped, e.g. Jan 30, Feb 28, Mar 31.
+    pub fn with_end_date_eom(end_date: NaiveDate) -> DateRange {
+        Self::eom(add_months_eom(end_date, -1) + Duration::days(1), end_date)
+    }
+
+    fn eom(start_date: NaiveDate, end_date: NaiveDate) -> DateRange {
+        DateRange::new_with_prior_next(start_date, end_date, MonthlyDateRange::prior_eom, MonthlyDateRange::next_eom)
+            .with_frequency(Frequency::Monthly)
+    }
+
+    fn prior_eom(date_range: &DateRange) -> DateRange {
+        let new_end = date_range.start_date() - Duration::days(1);
+        Self::eom(add_months_eom(new_end, -1) + Duration::days(1), new_end)
+    }
+
+    fn next_eom(date_range: &DateRange) -> DateRange {
+        Self::eom(date_range.end_date() + Duration::days(1), add_months_eom(date_range.end_date(), 1))
     }
 
     fn prior(date_range: &DateRange) -> DateRange {
@@ -28,17 +72,18 @@ impl MonthlyDateRange {
                                                      new_end,
                                                      MonthlyDateRange::prior,
                                                      MonthlyDateRange::next,
-                                                     date_range.start_day())
+                                                     date_range.start_day()).with_frequency(Frequency::Monthly)
 
         } else {
-            let new_start = subtract_months(date_range.start_date(), 1);
+            let start_day = date_range.start_day().unwrap();
+            let new_start = anchored_date(subtract_months(first_day_of_month(date_range.start_date()), 1), start_day);
             let new_end = date_range.start_date() - Duration::days(1);
 
             DateRange::new_with_prior_next_start_day(new_start,
                                                      new_end,
                                                      MonthlyDateRange::prior,
                                                      MonthlyDateRange::next,
-                                                     date_range.start_day())
+                                                     date_range.start_day()).with_frequency(Frequency::Monthly)
         }
     }
 
@@ -51,20 +96,29 @@ impl MonthlyDateRange {
                                                      new_end,
                                                      MonthlyDateRange::prior,
                                                      MonthlyDateRange::next,
-                                                     date_range.start_day())
+                                                     date_range.start_day()).with_frequency(Frequency::Monthly)
         } else {
+            // Derive the end from the start day rather than the previous end, so a start day
+            // that some months don't have doesn't drift after passing through a short month.
+            let start_day = date_range.start_day().unwrap();
             let new_start = date_range.end_date() + Duration::days(1);
-            let new_end = add_months(date_range.end_date(), 1);
+            let new_end = boundary_after(new_start, start_day) - Duration::days(1);
 
             DateRange::new_with_prior_next_start_day(new_start,
                                                      new_end,
                                                      MonthlyDateRange::prior,
                                                      MonthlyDateRange::next,
-                                                     date_range.start_day())
+                                                     date_range.start_day()).with_frequency(Frequency::Monthly)
         }
     }
 }
 
+/// Get the first start of a period for a start day that falls after a date.
+fn boundary_after(date: NaiveDate, start_day: usize) -> NaiveDate {
+    let boundary = anchored_date(date, start_day);
+    if boundary > date { boundary } else { anchored_date(add_months(first_day_of_month(date), 1), start_day) }
+}
+
 fn calculate_start_date_from_end_date(end_date: NaiveDate, start_day: usize) -> NaiveDate {
     if start_day == 1 {
         NaiveDate::from_ymd_opt(end_date.year(), end_date.month(), 1).unwrap()
@@ -81,7 +135,7 @@ fn calculate_start_date_from_end_date(end_date: NaiveDate, start_day: usize) ->
 #[cfg(test)]
 mod tests {
     use super::MonthlyDateRange;
-    use chrono::{Datelike, NaiveDate};
+    use chrono::{Datelike, Duration, NaiveDate};
 
     fn d(y: i32, m: u32, day: u32) -> NaiveDate {
         NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
@@ -184,6 +238,43 @@ mod tests {
         assert_eq!(feb15.end_date(), d(2024, 2, 15));
     }
 
+    #[test]
+    fn next_ends_at_the_first_anchored_boundary_after_its_start() {
+        let feb = MonthlyDateRange::with_end_date_and_start_day(d(2023, 2, 28), 29);
+        let next = feb.next();
+        assert_eq!((next.start_date(), next.end_date()), (d(2023, 3, 1), d(2023, 3, 28)));
+        assert_eq!(next.next().start_date(), d(2023, 3, 29));
+    }
+
+    #[test]
+    fn next_then_prior_returns_to_the_same_period_through_clamped_months() {
+        for start_day in 29..=31 {
+            let end = d(2022, 12, start_day - 1);
+            let mut range = MonthlyDateRange::with_end_date_and_start_day(end, start_day as usize);
+            for _ in 0..27 {
+                assert_eq!(range.next().prior(), range, "start day {} at {:?}", start_day, range);
+                assert_eq!(range.next().start_date(), range.end_date() + Duration::days(1));
+                range = range.next();
+            }
+        }
+    }
+
+    #[test]
+    fn eom_mode_keeps_month_end_boundaries() {
+        let feb = MonthlyDateRange::with_end_date_eom(d(2023, 2, 28));
+        assert_eq!((feb.start_date(), feb.end_date()), (d(2023, 2, 1), d(2023, 2, 28)));
+
+        let jan_end = MonthlyDateRange::with_end_date_eom(d(2024, 1, 31));
+        let ends: Vec<NaiveDate> = jan_end.ranges_after_inclusive(3).iter().map(|r| r.end_date()).collect();
+        assert_eq!(ends, vec![d(2024, 1, 31), d(2024, 2, 29), d(2024, 3, 31), d(2024, 4, 30)]);
+        assert_eq!(jan_end.next().next().prior().prior(), jan_end);
+        assert_eq!(jan_end.next().start_date(), d(2024, 2, 1));
+
+        let drifting = MonthlyDateRange::with_end_date_eom(d(2024, 1, 30));
+        assert_eq!(drifting.next().end_date(), d(2024, 2, 29));
+        assert_eq!(drifting.next().next().end_date(), d(2024, 3, 31));
+    }
+
     #[test]
     fn range_containing_date_aligns_to_16_to_15_segments() {
         // Base: 2023-01-16 .. 2023-02-15 (constructed by passing end_date 2023-02-15)
@@ -0,0 +1,165 @@
+use crate::daterange::annual_date_range::AnnualDateRange;
+use crate::daterange::date_range::DateRange;
+use crate::daterange::monthly_date_range::MonthlyDateRange;
+use crate::daterange::period_id::parse_number;
+use crate::daterange::quarterly_date_range::QuarterlyDateRange;
+use crate::daterange::range_error::RangeError;
+use crate::dateutils::date_utils::{add_months, last_day_of_month};
+use chrono::{Datelike, Duration, NaiveDate};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// The naming convention used for partition keys.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PartitionStyle {
+    /// Hive-style `key=value` path segments: `year=2024`, `year=2024/quarter=2`,
+    /// `year=2024/month=03` and `year=2024/month=03/day=01` for calendar-aligned ranges, and
+    /// `start=2024-03-05/end=2024-03-09` for anything else.
+    Hive,
+    /// The bounds as `20240301_20240331`.
+    Compact,
+}
+
+impl DateRange {
+    /// Get a key naming the range in a data lake partitioning convention.
+    pub fn partition_key(&self, style: PartitionStyle) -> String {
+        let start = self.start_date();
+        let end = self.end_date();
+        if style == PartitionStyle::Compact {
+            return format!("{}_{}", start.format("%Y%m%d"), end.format("%Y%m%d"));
+        }
+
+        let months = if start.day() == 1 && end == last_day_of_month(end) {
+            (end.year() - start.year()) * 12 + end.month() as i32 - start.month() as i32 + 1
+        } else {
+            0
+        };
+        match months {
+            _ if start == end => format!("year={}/month={:02}/day={:02}", start.year(), start.month(), start.day()),
+            1 => format!("year={}/month={:02}", start.year(), start.month()),
+            3 if start.month() % 3 == 1 => format!("year={}/quarter={}", start.year(), start.month0() / 3 + 1),
+            12 if start.month() == 1 => format!("year={}", start.year()),
+            _ => format!("start={}/end={}", start.format("%Y-%m-%d"), end.format("%Y-%m-%d")),
+        }
+    }
+
+    /// Parse a key produced by `partition_key` in either style. Calendar years, quarters and
+    /// months are restored as annual, quarterly and monthly ranges.
+    pub fn from_partition_key(key: &str) -> Result<DateRange, RangeError> {
+        parse_partition_key(key).ok_or_else(|| RangeError::InvalidPartitionKey(key.to_string()))
+    }
+}
+
+fn parse_partition_key(key: &str) -> Option<DateRange> {
+    if let Some((start, end)) = key.split_once('_') {
+        if start.len() != 8 || end.len() != 8 {
+            return None;
+        }
+        let start = NaiveDate::parse_from_str(start, "%Y%m%d").ok()?;
+        let end = NaiveDate::parse_from_str(end, "%Y%m%d").ok()?;
+        return format!("{}/{}", start, end).parse().ok();
+    }
+
+    let segments: Vec<(&str, &str)> = key.split('/').map(|segment| segment.split_once('=')).collect::<Option<_>>()?;
+    match segments.as_slice() {
+        [("start", start), ("end", end)] => format!("{}/{}", start, end).parse().ok(),
+        [("year", year)] => Some(AnnualDateRange::with_start_date(NaiveDate::from_ymd_opt(parse_year(year)?, 1, 1)?)),
+        [("year", year), ("quarter", quarter)] => {
+            let quarter = parse_padded(quarter, 1).filter(|q| (1..=4).contains(q))?;
+            let start = NaiveDate::from_ymd_opt(parse_year(year)?, quarter * 3 - 2, 1)?;
+            Some(QuarterlyDateRange::with_start_date(start))
+        }
+        [("year", year), ("month", month)] => {
+            let start = NaiveDate::from_ymd_opt(parse_year(year)?, parse_padded(month, 2)?, 1)?;
+            Some(MonthlyDateRange::with_end_date_on_first(add_months(start, 1) - Duration::days(1)))
+        }
+        [("year", year), ("month", month), ("day", day)] => {
+            let date = NaiveDate::from_ymd_opt(parse_year(year)?, parse_padded(month, 2)?, parse_padded(day, 2)?)?;
+            Some(DateRange::new(date, date))
+        }
+        _ => None,
+    }
+}
+
+fn parse_year(value: &str) -> Option<i32> {
+    parse_padded(value, 4).map(|year| year as i32)
+}
+
+fn parse_padded(value: &str, width: usize) -> Option<u32> {
+    if value.len() != width {
+        return None;
+    }
+    parse_number(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::daterange::date_range::DateRange;
+    use crate::daterange::frequency::Frequency;
+    use crate::daterange::partition_key::PartitionStyle;
+    use crate::daterange::range_error::RangeError;
+    use chrono::NaiveDate;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    fn assert_hive_key(range: DateRange, key: &str, frequency: Option<Frequency>) {
+        assert_eq!(range.partition_key(PartitionStyle::Hive), key);
+        let parsed = DateRange::from_partition_key(key).unwrap();
+        assert!(parsed.same_bounds_as(&range), "{} parsed as {:?}", key, parsed);
+        assert_eq!(parsed.frequency(), frequency, "{}", key);
+    }
+
+    fn assert_rejected(key: &str) {
+        let expected = Err(RangeError::InvalidPartitionKey(key.to_string()));
+        assert_eq!(DateRange::from_partition_key(key), expected, "{}", key);
+    }
+
+    #[test]
+    fn hive_keys_follow_calendar_alignment() {
+        assert_hive_key(DateRange::new(d(2024, 1, 1), d(2024, 12, 31)), "year=2024", Some(Frequency::Annual));
+        let second_quarter = DateRange::new(d(2024, 4, 1), d(2024, 6, 30));
+        assert_hive_key(second_quarter, "year=2024/quarter=2", Some(Frequency::Quarterly));
+        assert_hive_key(DateRange::new(d(2024, 2, 1), d(2024, 2, 29)), "year=2024/month=02", Some(Frequency::Monthly));
+        assert_hive_key(DateRange::new(d(2024, 3, 1), d(2024, 3, 1)), "year=2024/month=03/day=01", None);
+        assert_hive_key(DateRange::new(d(2024, 3, 5), d(2024, 3, 9)), "start=2024-03-05/end=2024-03-09", None);
+        assert_hive_key(DateRange::new(d(2024, 2, 1), d(2024, 4, 30)), "start=2024-02-01/end=2024-04-30", None);
+
+        let month = DateRange::from_partition_key("year=2024/month=03").unwrap();
+        assert_eq!(month.next().start_date(), d(2024, 4, 1));
+    }
+
+    #[test]
+    fn compact_keys_use_bounds_for_every_range() {
+        let month = DateRange::new(d(2024, 3, 1), d(2024, 3, 31));
+        assert_eq!(month.partition_key(PartitionStyle::Compact), "20240301_20240331");
+        let parsed = DateRange::from_partition_key("20240301_20240331").unwrap();
+        assert!(parsed.same_bounds_as(&month));
+        assert_eq!(parsed.frequency(), None);
+
+        let day = DateRange::new(d(2024, 12, 31), d(2024, 12, 31));
+        assert_eq!(day.partition_key(PartitionStyle::Compact), "20241231_20241231");
+    }
+
+    #[test]
+    fn malformed_hive_keys_are_rejected() {
+        for key in ["", "year=24", "year=2024/month=3", "year=2024/quarter=5", "year=2024/quarter=02", "month=03"] {
+            assert_rejected(key);
+        }
+        for key in ["quarter=2/year=2024", "year=2024/month=03/day=1", "year=2024/month=02/day=30", "year2024", "x=1"] {
+            assert_rejected(key);
+        }
+        assert_rejected("start=2024-03-09/end=2024-03-05");
+        assert_rejected("start=2024-03-05");
+    }
+
+    #[test]
+    fn malformed_compact_keys_are_rejected() {
+        for key in ["20240331_20240301", "20240301_", "20240301_2024033", "2024-03-01_2024-03-31", "20240230_20240301"]
+        {
+            assert_rejected(key);
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use crate::daterange::date_range::{DateRange, MAX_CONTAINING_STEPS};
+use crate::daterange::range_error::RangeError;
+use chrono::NaiveDate;
+use alloc::collections::BTreeMap;
+
+/// The most periods to walk from a cached range before looking a date up from scratch instead.
+const MAX_CACHED_WALK: i64 = 16;
+
+#[derive(Clone, Debug)]
+struct Entry {
+    range: DateRange,
+    prior: Option<DateRange>,
+    next: Option<DateRange>,
+    used: u64,
+}
+
+/// Memoized prior and next ranges of a periodic range's cycle, keyed by start date.
+///
+/// Lookups that land near a cached period walk the cached chain instead of recomputing it. The
+/// least recently used periods are dropped once the cache holds `capacity` of them, so a
+/// long-lived cache stays bounded. Ranges passed in should come from the cache's cycle; one that
+/// merely shares a start date with a cached period is computed afresh.
+#[derive(Clone, Debug)]
+pub struct PeriodCache {
+    range: DateRange,
+    capacity: usize,
+    entries: BTreeMap<NaiveDate, Entry>,
+    recency: BTreeMap<u64, NaiveDate>,
+    clock: u64,
+    hits: u64,
+    misses: u64,
+}
+
+impl PeriodCache {
+    /// Create a cache for a range's cycle holding at most `capacity` periods, and at least one.
+    pub fn new(range: DateRange, capacity: usize) -> Self {
+        Self {
+            range,
+            capacity: capacity.max(1),
+            entries: BTreeMap::new(),
+            recency: BTreeMap::new(),
+            clock: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    pub fn range(&self) -> &DateRange {
+        &self.range
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get the number of periods cached.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Get the number of lookups answered from the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Get the number of lookups that had to compute a range.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Drop every cached period.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    /// Get the range after a range, computing it only if it isn't cached.
+    pub fn next(&mut self, range: &DateRange) -> DateRange {
+        if let Some(next) = self.entry(range).and_then(|entry| entry.next) {
+            self.hits += 1;
+            self.touch(next.start_date());
+            return next;
+        }
+        self.misses += 1;
+        let next = range.next();
+        self.store(range).next = Some(next);
+        self.store(&next).prior = Some(*range);
+        next
+    }
+
+    /// Get the range before a range, computing it only if it isn't cached.
+    pub fn prior(&mut self, range: &DateRange) -> DateRange {
+        if let Some(prior) = self.entry(range).and_then(|entry| entry.prior) {
+            self.hits += 1;
+            self.touch(prior.start_date());
+            return prior;
+        }
+        self.misses += 1;
+        let prior = range.prior();
+        self.store(range).prior = Some(prior);
+        self.store(&prior).next = Some(*range);
+        prior
+    }
+
+    /// Get the period of the cycle that contains a date. See `DateRange::try_range_containing_date`.
+    pub fn range_containing_date(&mut self, date: NaiveDate) -> Result<DateRange, RangeError> {
+        if !self.range.is_periodic() {
+            return Err(RangeError::NotPeriodic);
+        }
+        let Some(mut range) = self.nearest(date) else {
+            self.misses += 1;
+            let range = self.range.try_range_containing_date(date)?;
+            self.store(&range);
+            return Ok(range);
+        };
+        if range.contains_date(date) {
+            self.hits += 1;
+            self.touch(range.start_date());
+            return Ok(range);
+        }
+        for _ in 0..MAX_CONTAINING_STEPS {
+            range = if date > range.end_date() { self.next(&range) } else { self.prior(&range) };
+            if range.contains_date(date) {
+                return Ok(range);
+            }
+        }
+        Err(RangeError::IterationLimitExceeded { limit: MAX_CONTAINING_STEPS })
+    }
+
+    /// Get the cached period closest to a date, if it is near enough to walk from.
+    fn nearest(&self, date: NaiveDate) -> Option<DateRange> {
+        let before = self.entries.range(..=date).next_back().map(|(_, entry)| entry.range);
+        let after = self.entries.range(date..).next().map(|(_, entry)| entry.range);
+        let distance = |range: &DateRange| {
+            let days = (range.start_date() - date).num_days().abs().min((range.end_date() - date).num_days().abs());
+            days / (range.len() as i64).max(1)
+        };
+        before
+            .into_iter()
+            .chain(after)
+            .min_by_key(distance)
+            .filter(|range| range.contains_date(date) || distance(range) < MAX_CACHED_WALK)
+    }
+
+    fn entry(&self, range: &DateRange) -> Option<&Entry> {
+        self.entries
+            .get(&range.start_date())
+            .filter(|entry| entry.range.same_bounds_as(range))
+    }
+
+    fn touch(&mut self, start: NaiveDate) {
+        self.clock += 1;
+        if let Some(entry) = self.entries.get_mut(&start) {
+            self.recency.remove(&entry.used);
+            entry.used = self.clock;
+            self.recency.insert(self.clock, start);
+        }
+    }
+
+    /// Get the entry for a range, adding it and evicting the least recently used if needed.
+    fn store(&mut self, range: &DateRange) -> &mut Entry {
+        let start = range.start_date();
+        if self.entry(range).is_none() {
+            if let Some(stale) = self.entries.remove(&start) {
+                self.recency.remove(&stale.used);
+            }
+            while self.entries.len() >= self.capacity {
+                let Some((_, oldest)) = self.recency.pop_first() else { break };
+                self.entries.remove(&oldest);
+            }
+            self.entries.insert(start, Entry { range: *range, prior: None, next: None, used: 0 });
+        }
+        self.touch(start);
+        self.entries.get_mut(&start).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PeriodCache;
+    use crate::daterange::date_range::DateRange;
+    use crate::daterange::monthly_date_range::MonthlyDateRange;
+    use crate::daterange::range_error::RangeError;
+    use crate::daterange::weekly_date_range::WeeklyDateRange;
+    use chrono::NaiveDate;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn memoizes_neighbours() {
+        let january = MonthlyDateRange::with_end_date_on_first(d(2024, 1, 31));
+        let mut cache = PeriodCache::new(january, 100);
+        let february = cache.next(&january);
+        assert_eq!(february, january.next());
+        assert_eq!(cache.prior(&february), january);
+        assert_eq!(cache.next(&january), february);
+        assert_eq!((cache.hits(), cache.misses()), (2, 1));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn range_containing_date_walks_the_cached_chain() {
+        let week = WeeklyDateRange::with_end_date(d(2024, 1, 7));
+        let mut cache = PeriodCache::new(week, 100);
+        for date in week.next_n(3).iter().chain(week.prior_n(2).iter()) {
+            assert_eq!(cache.range_containing_date(date), week.try_range_containing_date(date));
+        }
+        let misses = cache.misses();
+        for date in DateRange::new(d(2023, 12, 18), d(2024, 1, 28)).iter() {
+            assert_eq!(cache.range_containing_date(date), week.try_range_containing_date(date));
+        }
+        assert_eq!(cache.misses(), misses);
+
+        let far = cache.range_containing_date(d(2030, 6, 1)).unwrap();
+        assert!(far.contains_date(d(2030, 6, 1)));
+        assert!(cache.len() <= 8);
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let january = MonthlyDateRange::with_end_date_on_first(d(2024, 1, 31));
+        let mut cache = PeriodCache::new(january, 3);
+        let mut range = january;
+        for _ in 0..5 {
+            range = cache.next(&range);
+        }
+        assert_eq!(cache.len(), 3);
+        assert_eq!(range.start_date(), d(2024, 6, 1));
+
+        let misses = cache.misses();
+        cache.next(&january);
+        assert_eq!(cache.misses(), misses + 1);
+        assert_eq!(cache.len(), 3);
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(PeriodCache::new(january, 0).capacity(), 1);
+    }
+
+    #[test]
+    fn rejects_plain_ranges() {
+        let mut cache = PeriodCache::new(DateRange::new(d(2024, 1, 1), d(2024, 1, 31)), 10);
+        assert_eq!(cache.range_containing_date(d(2024, 2, 1)), Err(RangeError::NotPeriodic));
+    }
+}
@@ -0,0 +1,198 @@
+use crate::daterange::date_range::DateRange;
+use crate::daterange::frequency::Frequency;
+use chrono::{Datelike, Duration, NaiveDate};
+use alloc::vec::Vec;
+
+/// Where the periods used for a window are anchored.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PeriodAlignment {
+    /// Calendar periods: weeks end on Sunday, months start on the 1st and years in January.
+    Calendar,
+    /// Periods laid end to end from the start of the window.
+    Rolling,
+}
+
+/// Get the end dates of the periods of a frequency that end within a window, in order, such
+/// as every month end within a fiscal year.
+pub fn period_end_dates(frequency: Frequency, within: &DateRange, alignment: PeriodAlignment) -> Vec<NaiveDate> {
+    let mut range = match alignment {
+        PeriodAlignment::Calendar => calendar_period_containing(frequency, within.start_date()),
+        PeriodAlignment::Rolling => {
+            DateRange::builder().frequency(frequency).starting_on(within.start_date()).build().unwrap()
+        }
+    };
+    let mut ends = Vec::new();
+    while range.end_date() <= within.end_date() {
+        ends.push(range.end_date());
+        range = range.next();
+    }
+    ends
+}
+
+/// Get the first calendar period of a frequency that ends in a year. Periods belong to the year
+/// they end in, so a week spanning New Year's Day is the first week of the new year.
+pub fn first_period_of_year(frequency: Frequency, year: i32) -> DateRange {
+    calendar_period_containing(frequency, NaiveDate::from_ymd_opt(year, 1, 1).unwrap())
+}
+
+/// Get the last calendar period of a frequency that ends in a year.
+pub fn last_period_of_year(frequency: Frequency, year: i32) -> DateRange {
+    let last = calendar_period_containing(frequency, NaiveDate::from_ymd_opt(year, 12, 31).unwrap());
+    if last.end_date().year() > year { last.prior() } else { last }
+}
+
+/// Get all the calendar periods of a frequency that end in a year, in order.
+pub fn periods_in_year(frequency: Frequency, year: i32) -> Vec<DateRange> {
+    let mut range = first_period_of_year(frequency, year);
+    let mut periods = Vec::new();
+    while range.end_date().year() == year {
+        periods.push(range);
+        range = range.next();
+    }
+    periods
+}
+
+/// The periods of a pay calendar that end in a calendar year.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CalendarYearPeriods {
+    pub year: i32,
+    pub frequency: Frequency,
+    pub periods: Vec<DateRange>,
+}
+
+impl CalendarYearPeriods {
+    /// Check if the year has more periods than usual, such as a 53rd weekly or 27th bi-weekly
+    /// pay period.
+    pub fn has_extra_period(&self) -> bool {
+        self.periods.len() > self.frequency.nominal_periods_per_year()
+    }
+
+    /// Get the extra period of the year, which is the last one, if the year has one.
+    pub fn extra_period(&self) -> Option<DateRange> {
+        if self.has_extra_period() { self.periods.last().copied() } else { None }
+    }
+
+    /// Get the 1-based number of the period containing a date, or None if the date is not in
+    /// one of the year's periods.
+    pub fn period_number(&self, date: NaiveDate) -> Option<usize> {
+        self.periods.iter().position(|period| period.contains_date(date)).map(|index| index + 1)
+    }
+}
+
+/// Get the periods of a pay calendar that end in a calendar year. The calendar is given by the
+/// end date of any one of its periods, so bi-weekly calendars keep their cycle.
+pub fn periods_in_calendar_year(anchor: NaiveDate, frequency: Frequency, year: i32) -> CalendarYearPeriods {
+    let next_start = anchor + Duration::days(1);
+    let seed = DateRange::builder().frequency(frequency).starting_on(next_start).build().unwrap();
+    let mut range = seed.range_containing_date(NaiveDate::from_ymd_opt(year, 1, 1).unwrap());
+    let mut periods = Vec::new();
+    while range.end_date().year() == year {
+        periods.push(range);
+        range = range.next();
+    }
+    CalendarYearPeriods { year, frequency, periods }
+}
+
+fn calendar_period_containing(frequency: Frequency, date: NaiveDate) -> DateRange {
+    DateRange::builder().frequency(frequency).containing(date).build().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        first_period_of_year, last_period_of_year, period_end_dates, periods_in_calendar_year, periods_in_year,
+        PeriodAlignment,
+    };
+    use crate::daterange::date_range::DateRange;
+    use crate::daterange::frequency::Frequency;
+    use chrono::NaiveDate;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn calendar_month_ends_within_fiscal_year() {
+        let fy24 = DateRange::new(d(2023, 7, 1), d(2024, 6, 30));
+        let ends = period_end_dates(Frequency::Monthly, &fy24, PeriodAlignment::Calendar);
+        assert_eq!(ends.len(), 12);
+        assert_eq!(ends[0], d(2023, 7, 31));
+        assert_eq!(ends[7], d(2024, 2, 29));
+        assert_eq!(ends[11], d(2024, 6, 30));
+
+        let quarter_ends = period_end_dates(Frequency::Quarterly, &fy24, PeriodAlignment::Calendar);
+        assert_eq!(quarter_ends, vec![d(2023, 9, 30), d(2023, 12, 31), d(2024, 3, 31), d(2024, 6, 30)]);
+    }
+
+    #[test]
+    fn calendar_periods_that_start_inside_but_end_outside_are_left_out() {
+        let window = DateRange::new(d(2024, 3, 6), d(2024, 3, 20));
+        let ends = period_end_dates(Frequency::Weekly, &window, PeriodAlignment::Calendar);
+        assert_eq!(ends, vec![d(2024, 3, 10), d(2024, 3, 17)]);
+        assert!(period_end_dates(Frequency::Monthly, &window, PeriodAlignment::Calendar).is_empty());
+    }
+
+    #[test]
+    fn rolling_periods_start_with_the_window() {
+        let window = DateRange::new(d(2024, 3, 6), d(2024, 3, 20));
+        let ends = period_end_dates(Frequency::Weekly, &window, PeriodAlignment::Rolling);
+        assert_eq!(ends, vec![d(2024, 3, 12), d(2024, 3, 19)]);
+
+        let year = DateRange::new(d(2024, 1, 15), d(2024, 6, 14));
+        let month_ends = period_end_dates(Frequency::Monthly, &year, PeriodAlignment::Rolling);
+        assert_eq!(month_ends[0], d(2024, 2, 14));
+        assert_eq!(month_ends.last(), Some(&d(2024, 6, 14)));
+    }
+
+    #[test]
+    fn periods_belong_to_the_year_they_end_in() {
+        // 2024 starts on a Monday, so the first week ends on Sunday 2024-01-07.
+        let first = first_period_of_year(Frequency::Weekly, 2024);
+        assert_eq!((first.start_date(), first.end_date()), (d(2024, 1, 1), d(2024, 1, 7)));
+        let last = last_period_of_year(Frequency::Weekly, 2024);
+        assert_eq!((last.start_date(), last.end_date()), (d(2024, 12, 23), d(2024, 12, 29)));
+        assert_eq!(periods_in_year(Frequency::Weekly, 2024).len(), 52);
+
+        // 2023 starts on a Sunday, so its first week is the one ending on New Year's Day.
+        assert_eq!(first_period_of_year(Frequency::Weekly, 2023).end_date(), d(2023, 1, 1));
+        assert_eq!(periods_in_year(Frequency::Weekly, 2023).len(), 53);
+    }
+
+    #[test]
+    fn periods_in_year_for_calendar_frequencies() {
+        assert_eq!(periods_in_year(Frequency::Monthly, 2024).len(), 12);
+        assert_eq!(periods_in_year(Frequency::SemiMonthly, 2024).len(), 24);
+        assert_eq!(periods_in_year(Frequency::Quarterly, 2024).len(), 4);
+        assert_eq!(periods_in_year(Frequency::SemiAnnual, 2024).len(), 2);
+        let years = periods_in_year(Frequency::Annual, 2024);
+        assert_eq!(years.len(), 1);
+        assert_eq!((years[0].start_date(), years[0].end_date()), (d(2024, 1, 1), d(2024, 12, 31)));
+
+        let last = last_period_of_year(Frequency::SemiMonthly, 2024);
+        assert_eq!((last.start_date(), last.end_date()), (d(2024, 12, 16), d(2024, 12, 31)));
+    }
+
+    #[test]
+    fn periods_in_calendar_year_flags_extra_pay_periods() {
+        // Fridays 2021-01-01 and 2021-12-31 are both pay dates, so 2021 has 27 bi-weekly periods.
+        let anchor = d(2021, 1, 1);
+        let y2021 = periods_in_calendar_year(anchor, Frequency::BiWeekly, 2021);
+        assert_eq!(y2021.periods.len(), 27);
+        assert!(y2021.has_extra_period());
+        assert_eq!(y2021.extra_period().map(|p| p.end_date()), Some(d(2021, 12, 31)));
+        assert_eq!(y2021.period_number(d(2021, 1, 1)), Some(1));
+        assert_eq!(y2021.period_number(d(2021, 12, 30)), Some(27));
+
+        let y2022 = periods_in_calendar_year(anchor, Frequency::BiWeekly, 2022);
+        assert_eq!(y2022.periods.len(), 26);
+        assert_eq!(y2022.extra_period(), None);
+        assert_eq!(y2022.periods[0].end_date(), d(2022, 1, 14));
+
+        let weekly = periods_in_calendar_year(anchor, Frequency::Weekly, 2021);
+        assert_eq!(weekly.periods.len(), 53);
+        assert_eq!(weekly.extra_period().map(|p| p.end_date()), Some(d(2021, 12, 31)));
+        let months = periods_in_calendar_year(d(2021, 1, 31), Frequency::Monthly, 2021);
+        assert_eq!(months.periods.len(), 12);
+        assert!(!months.has_extra_period());
+    }
+}
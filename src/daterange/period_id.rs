@@ -0,0 +1,275 @@
+use crate::daterange::annual_date_range::AnnualDateRange;
+use crate::daterange::bi_weekly_date_range::BiWeeklyDateRange;
+use crate::daterange::billing_cycle::anchored_date;
+use crate::daterange::date_range::DateRange;
+use crate::daterange::frequency::Frequency;
+use crate::daterange::monthly_date_range::MonthlyDateRange;
+use crate::daterange::quarterly_date_range::QuarterlyDateRange;
+use crate::daterange::range_error::RangeError;
+use crate::daterange::semi_annual_date_range::SemiAnnualDateRange;
+use crate::daterange::semi_monthly_date_range::SemiMonthlyDateRange;
+use crate::daterange::weekly_date_range::WeeklyDateRange;
+use crate::dateutils::date_utils::{add_months, last_day_of_month};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+impl DateRange {
+    /// Get a stable ID for the period, derived from the range's frequency:
+    ///
+    /// - weekly: `2024W15`, the ISO week of the end date, with `-SAT` style suffixes for weeks
+    ///   that don't end on Sunday; bi-weekly uses `BW` in place of `W`
+    /// - monthly: `2024M03`, or `2024M03-16` for months starting on the 16th; month-end ranges
+    ///   from `with_end_date_eom` that aren't calendar months use their bounds
+    /// - semi-monthly: `2024SM06A` for the 1st-15th and `2024SM06B` for the rest of the month
+    /// - quarterly and semi-annual: `2024Q2` and `2024H1` for calendar-aligned periods
+    /// - annual: `2024` for calendar years
+    ///
+    /// Years are written with four digits, so only periods in the years 0000 to 9999 get these
+    /// IDs. Any other range, including fiscal periods that aren't calendar-aligned, is identified
+    /// by its bounds, as in `2024-03-01/2024-03-31`.
+    pub fn period_id(&self) -> String {
+        let start = self.start_date();
+        let end = self.end_date();
+        let aligned = start.day() == 1;
+        if [start.year(), end.year(), end.iso_week().year()].iter().any(|year| !(0..=9999).contains(year)) {
+            return self.to_string();
+        }
+
+        match self.frequency() {
+            Some(Frequency::Weekly) => week_id(end, "W"),
+            Some(Frequency::BiWeekly) => week_id(end, "BW"),
+            Some(Frequency::Monthly) if self.start_day().is_some() || (aligned && end == last_day_of_month(start)) => {
+                match self.start_day().unwrap_or(1) {
+                    1 => format!("{:04}M{:02}", start.year(), start.month()),
+                    day => format!("{:04}M{:02}-{:02}", start.year(), start.month(), day),
+                }
+            }
+            Some(Frequency::SemiMonthly) => {
+                let half = if start.day() == 1 { 'A' } else { 'B' };
+                format!("{:04}SM{:02}{}", start.year(), start.month(), half)
+            }
+            Some(Frequency::Quarterly) if aligned && start.month() % 3 == 1 => {
+                format!("{:04}Q{}", start.year(), start.month0() / 3 + 1)
+            }
+            Some(Frequency::SemiAnnual) if aligned && start.month() % 6 == 1 => {
+                format!("{:04}H{}", start.year(), start.month0() / 6 + 1)
+            }
+            Some(Frequency::Annual) if aligned && start.month() == 1 => format!("{:04}", start.year()),
+            _ => self.to_string(),
+        }
+    }
+
+    /// Parse an ID produced by `period_id` back into the range, with the same frequency.
+    pub fn from_period_id(id: &str) -> Result<DateRange, RangeError> {
+        parse_period_id(id).ok_or_else(|| RangeError::InvalidPeriodId(id.to_string()))
+    }
+}
+
+fn week_id(end: NaiveDate, prefix: &str) -> String {
+    let week = end.iso_week();
+    match end.weekday() {
+        Weekday::Sun => format!("{:04}{}{:02}", week.year(), prefix, week.week()),
+        day => format!("{:04}{}{:02}-{}", week.year(), prefix, week.week(), day.to_string().to_uppercase()),
+    }
+}
+
+fn parse_period_id(id: &str) -> Option<DateRange> {
+    if id.contains('/') {
+        return id.parse().ok();
+    }
+
+    let digits = id.find(|c: char| !c.is_ascii_digit()).unwrap_or(id.len());
+    if digits != 4 {
+        return None;
+    }
+    let year: i32 = id[..digits].parse().ok()?;
+    let rest = &id[digits..];
+
+    if rest.is_empty() {
+        return Some(AnnualDateRange::with_start_date(NaiveDate::from_ymd_opt(year, 1, 1)?));
+    }
+    if let Some(rest) = rest.strip_prefix("BW") {
+        return parse_week(year, rest).map(BiWeeklyDateRange::with_end_date);
+    }
+    if let Some(rest) = rest.strip_prefix('W') {
+        return parse_week(year, rest).map(WeeklyDateRange::with_end_date);
+    }
+    if let Some(rest) = rest.strip_prefix("SM") {
+        let (month, half) = rest.split_at_checked(2)?;
+        let month = parse_number(month)?;
+        let end = match half {
+            "A" => NaiveDate::from_ymd_opt(year, month, 15)?,
+            "B" => last_day_of_month(NaiveDate::from_ymd_opt(year, month, 1)?),
+            _ => return None,
+        };
+        return Some(SemiMonthlyDateRange::with_end_date(end));
+    }
+    if let Some(rest) = rest.strip_prefix('M') {
+        let (month, start_day) = match rest.split_once('-') {
+            Some((month, day)) => (parse_number(month)?, parse_number(day)?),
+            None => (parse_number(rest)?, 1),
+        };
+        if !(1..=31).contains(&start_day) || (rest.contains('-') && start_day == 1) {
+            return None;
+        }
+        let month_start = NaiveDate::from_ymd_opt(year, month, 1)?;
+        let start_day = start_day as usize;
+        let start = anchored_date(month_start, start_day);
+        let end = anchored_date(add_months(month_start, 1), start_day) - Duration::days(1);
+        return Some(MonthlyDateRange::with_bounds_and_start_day(start, end, start_day));
+    }
+    if let Some(rest) = rest.strip_prefix('Q') {
+        let quarter = parse_number(rest).filter(|q| (1..=4).contains(q))?;
+        let start = NaiveDate::from_ymd_opt(year, quarter * 3 - 2, 1)?;
+        return Some(QuarterlyDateRange::with_start_date(start));
+    }
+    if let Some(rest) = rest.strip_prefix('H') {
+        let half = parse_number(rest).filter(|h| (1..=2).contains(h))?;
+        let start = NaiveDate::from_ymd_opt(year, half * 6 - 5, 1)?;
+        return Some(SemiAnnualDateRange::with_start_date(start));
+    }
+    None
+}
+
+/// Parse `15` or `15-SAT` into the end date of that ISO week's period.
+fn parse_week(year: i32, rest: &str) -> Option<NaiveDate> {
+    let (week, end_day) = match rest.split_once('-') {
+        Some((week, day)) => (week, day.parse::<Weekday>().ok().filter(|day| *day != Weekday::Sun)?),
+        None => (rest, Weekday::Sun),
+    };
+    if week.len() != 2 {
+        return None;
+    }
+    NaiveDate::from_isoywd_opt(year, parse_number(week)?, end_day)
+}
+
+pub(crate) fn parse_number(value: &str) -> Option<u32> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::daterange::date_range::DateRange;
+    use crate::daterange::frequency::Frequency;
+    use crate::daterange::monthly_date_range::MonthlyDateRange;
+    use crate::daterange::range_error::RangeError;
+    use chrono::{NaiveDate, Weekday};
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    fn build(frequency: Frequency, date: NaiveDate) -> DateRange {
+        DateRange::builder().frequency(frequency).containing(date).build().unwrap()
+    }
+
+    fn assert_round_trip(range: DateRange, id: &str) {
+        assert_eq!(range.period_id(), id);
+        let parsed = DateRange::from_period_id(id).unwrap();
+        assert!(parsed.same_bounds_as(&range), "{} parsed as {:?}", id, parsed);
+        assert_eq!(parsed.frequency(), range.frequency());
+        assert_eq!(parsed.start_day(), range.start_day());
+    }
+
+    #[test]
+    fn ids_for_each_frequency_round_trip() {
+        assert_round_trip(build(Frequency::Weekly, d(2024, 4, 10)), "2024W15");
+        let saturday_week = DateRange::builder().weekly().ending(Weekday::Sat).containing(d(2024, 4, 10)).build();
+        assert_round_trip(saturday_week.unwrap(), "2024W15-SAT");
+        assert_round_trip(build(Frequency::BiWeekly, d(2024, 4, 10)), "2024BW15");
+        assert_round_trip(build(Frequency::Monthly, d(2024, 3, 10)), "2024M03");
+        let sixteenth = DateRange::builder().monthly().start_day(16).containing(d(2024, 3, 20)).build();
+        assert_round_trip(sixteenth.unwrap(), "2024M03-16");
+        assert_round_trip(build(Frequency::SemiMonthly, d(2024, 6, 3)), "2024SM06A");
+        assert_round_trip(build(Frequency::SemiMonthly, d(2024, 6, 30)), "2024SM06B");
+        assert_round_trip(build(Frequency::Quarterly, d(2024, 5, 1)), "2024Q2");
+        assert_round_trip(build(Frequency::SemiAnnual, d(2024, 5, 1)), "2024H1");
+        assert_round_trip(build(Frequency::Annual, d(2024, 5, 1)), "2024");
+    }
+
+    #[test]
+    fn late_start_day_ids_round_trip_through_short_months() {
+        let parsed = |id: &str| DateRange::from_period_id(id).unwrap();
+        assert!(parsed("2023M01-30").same_bounds_as(&DateRange::new(d(2023, 1, 30), d(2023, 2, 27))));
+        assert!(parsed("2023M03-31").same_bounds_as(&DateRange::new(d(2023, 3, 31), d(2023, 4, 29))));
+        assert!(parsed("2024M02-31").same_bounds_as(&DateRange::new(d(2024, 2, 29), d(2024, 3, 30))));
+
+        for start_day in 29..=31 {
+            let end = d(2023, 12, start_day - 1);
+            let mut range = MonthlyDateRange::with_end_date_and_start_day(end, start_day as usize);
+            for _ in 0..15 {
+                let id = range.period_id();
+                assert_round_trip(range, &id);
+                range = range.next();
+            }
+        }
+    }
+
+    #[test]
+    fn month_end_ranges_use_their_bounds_unless_they_are_calendar_months() {
+        let eom = MonthlyDateRange::with_end_date_eom(d(2024, 1, 30));
+        assert_eq!((eom.start_date(), eom.end_date()), (d(2023, 12, 31), d(2024, 1, 30)));
+        assert_eq!(eom.period_id(), "2023-12-31/2024-01-30");
+        assert!(DateRange::from_period_id(&eom.period_id()).unwrap().same_bounds_as(&eom));
+
+        let february = MonthlyDateRange::with_end_date_eom(d(2024, 2, 29));
+        assert_eq!(february.period_id(), "2024M02");
+        assert!(DateRange::from_period_id("2024M02").unwrap().same_bounds_as(&february));
+
+        let mut range = eom;
+        for _ in 0..14 {
+            let parsed = DateRange::from_period_id(&range.period_id()).unwrap();
+            assert!(parsed.same_bounds_as(&range), "{} parsed as {:?}", range.period_id(), parsed);
+            range = range.next();
+        }
+    }
+
+    #[test]
+    fn years_are_padded_and_years_outside_four_digits_use_bounds() {
+        let sixteenth = DateRange::builder().monthly().start_day(16).containing(d(999, 2, 20)).build().unwrap();
+        assert_round_trip(sixteenth, "0999M02-16");
+        assert_round_trip(build(Frequency::Quarterly, d(42, 5, 1)), "0042Q2");
+        assert_round_trip(build(Frequency::Annual, d(0, 5, 1)), "0000");
+        assert_round_trip(build(Frequency::Annual, d(9999, 5, 1)), "9999");
+
+        for range in [build(Frequency::Annual, d(-1, 5, 1)), build(Frequency::Monthly, d(10000, 3, 10))] {
+            let id = range.period_id();
+            assert_eq!(id, range.to_string());
+            assert!(DateRange::from_period_id(&id).unwrap().same_bounds_as(&range), "{}", id);
+        }
+        assert_eq!(build(Frequency::Weekly, d(0, 1, 1)).period_id(), "-0001-12-27/0000-01-02");
+        assert_eq!(DateRange::from_period_id("999M02"), Err(RangeError::InvalidPeriodId("999M02".to_string())));
+    }
+
+    #[test]
+    fn iso_week_year_is_used_at_year_boundaries() {
+        let week = build(Frequency::Weekly, d(2024, 12, 31));
+        assert_eq!(week.end_date(), d(2025, 1, 5));
+        assert_round_trip(week, "2025W01");
+    }
+
+    #[test]
+    fn unaligned_and_plain_ranges_use_their_bounds() {
+        let fiscal_year = DateRange::builder().annual().fiscal_start(7).containing(d(2024, 5, 1)).build().unwrap();
+        assert_eq!(fiscal_year.period_id(), "2023-07-01/2024-06-30");
+
+        let plain = DateRange::new(d(2024, 3, 5), d(2024, 3, 9));
+        assert_eq!(plain.period_id(), "2024-03-05/2024-03-09");
+        assert_eq!(DateRange::from_period_id("2024-03-05/2024-03-09"), Ok(plain));
+    }
+
+    #[test]
+    fn malformed_ids_are_rejected() {
+        for id in ["", "24Q1", "2024Q5", "2024M13", "2024M03-01", "2024W54", "2024W15-SUN", "2024SM06C", "2024X"] {
+            assert_eq!(DateRange::from_period_id(id), Err(RangeError::InvalidPeriodId(id.to_string())), "{}", id);
+        }
+        assert_eq!(
+            DateRange::from_period_id("2024Q5").unwrap_err().to_string(),
+            "invalid period id: 2024Q5"
+        );
+    }
+}
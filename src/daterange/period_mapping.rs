@@ -0,0 +1,83 @@
+use crate::daterange::date_range::DateRange;
+use alloc::vec::Vec;
+
+/// Associate each fine period, such as a week, with the indexes of the coarse periods, such as
+/// months, that it overlaps.
+///
+/// One entry is returned per fine period, in the order given, even when it overlaps nothing.
+pub fn map_periods(fine: &[DateRange], coarse: &[DateRange]) -> Vec<(usize, Vec<usize>)> {
+    map_periods_weighted(fine, coarse)
+        .into_iter()
+        .map(|(i, weights)| (i, weights.into_iter().map(|(j, _)| j).collect()))
+        .collect()
+}
+
+/// Like [`map_periods`], but pairs each coarse index with the fraction of the fine period's
+/// days that fall in it, for prorating amounts.
+pub fn map_periods_weighted(fine: &[DateRange], coarse: &[DateRange]) -> Vec<(usize, Vec<(usize, f64)>)> {
+    fine.iter()
+        .enumerate()
+        .map(|(i, fine_period)| {
+            let weights = coarse
+                .iter()
+                .enumerate()
+                .filter_map(|(j, coarse_period)| {
+                    let days = overlap_days(fine_period, coarse_period);
+                    (days > 0).then(|| (j, days as f64 / fine_period.len() as f64))
+                })
+                .collect();
+            (i, weights)
+        })
+        .collect()
+}
+
+fn overlap_days(a: &DateRange, b: &DateRange) -> i64 {
+    let start = a.start_date().max(b.start_date());
+    let end = a.end_date().min(b.end_date());
+    (end - start).num_days() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_periods, map_periods_weighted};
+    use crate::daterange::date_range::DateRange;
+    use chrono::NaiveDate;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn weeks_map_to_the_months_they_overlap() {
+        let weeks = DateRange::new(d(2024, 1, 22), d(2024, 1, 28)).ranges_after_inclusive(2);
+        let months = vec![
+            DateRange::new(d(2024, 1, 1), d(2024, 1, 31)),
+            DateRange::new(d(2024, 2, 1), d(2024, 2, 29)),
+        ];
+
+        assert_eq!(
+            map_periods(&weeks, &months),
+            vec![(0, vec![0]), (1, vec![0, 1]), (2, vec![1])]
+        );
+    }
+
+    #[test]
+    fn weights_prorate_by_days() {
+        let weeks = [DateRange::new(d(2024, 1, 29), d(2024, 2, 4))];
+        let months = [
+            DateRange::new(d(2024, 1, 1), d(2024, 1, 31)),
+            DateRange::new(d(2024, 2, 1), d(2024, 2, 29)),
+        ];
+
+        let mapped = map_periods_weighted(&weeks, &months);
+        assert_eq!(mapped.len(), 1);
+        assert_eq!(mapped[0].1, vec![(0, 3.0 / 7.0), (1, 4.0 / 7.0)]);
+    }
+
+    #[test]
+    fn uncovered_fine_periods_map_to_nothing() {
+        let weeks = [DateRange::new(d(2024, 5, 6), d(2024, 5, 12))];
+        let months = [DateRange::new(d(2024, 1, 1), d(2024, 1, 31))];
+        assert_eq!(map_periods(&weeks, &months), vec![(0, vec![])]);
+    }
+}
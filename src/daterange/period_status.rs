@@ -0,0 +1,10 @@
+/// Where a range falls relative to an as-of date.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PeriodStatus {
+    /// The range ended before the as-of date.
+    Past,
+    /// The range contains the as-of date.
+    Current,
+    /// The range starts after the as-of date.
+    Future,
+}
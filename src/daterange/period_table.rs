@@ -0,0 +1,135 @@
+use crate::daterange::date_range::DateRange;
+use crate::daterange::range_error::RangeError;
+use chrono::NaiveDate;
+use alloc::vec::Vec;
+use core::slice;
+
+/// The consecutive periods of a cycle covering a horizon, generated once so that lookups are a
+/// binary search rather than a walk along prior and next ranges.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PeriodTable {
+    periods: Vec<DateRange>,
+}
+
+impl PeriodTable {
+    /// Build the periods of a range's cycle from the one containing the start of the horizon to the
+    /// one containing its end. Fails if the range is not periodic.
+    pub fn new(range: &DateRange, horizon: &DateRange) -> Result<Self, RangeError> {
+        let mut period = range.try_range_containing_date(horizon.start_date())?;
+        let mut periods = Vec::new();
+        periods.push(period);
+        while period.end_date() < horizon.end_date() {
+            period = period.next();
+            periods.push(period);
+        }
+        Ok(Self { periods })
+    }
+
+    /// Get the periods in date order.
+    pub fn periods(&self) -> &[DateRange] {
+        &self.periods
+    }
+
+    /// Iterate over the periods in date order.
+    pub fn iter(&self) -> slice::Iter<'_, DateRange> {
+        self.periods.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.periods.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.periods.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&DateRange> {
+        self.periods.get(index)
+    }
+
+    /// Get the range from the start of the first period to the end of the last.
+    pub fn span(&self) -> Option<DateRange> {
+        let first = self.periods.first()?;
+        let last = self.periods.last()?;
+        Some(DateRange::new(first.start_date(), last.end_date()))
+    }
+
+    /// Get the period containing a date. None if the date is outside the table.
+    pub fn period_for(&self, date: NaiveDate) -> Option<&DateRange> {
+        self.index_for(date).map(|index| &self.periods[index])
+    }
+
+    /// Get the index of the period containing a date. None if the date is outside the table.
+    pub fn index_for(&self, date: NaiveDate) -> Option<usize> {
+        let index = self.periods.partition_point(|period| period.start_date() <= date);
+        index.checked_sub(1).filter(|index| self.periods[*index].contains_date(date))
+    }
+
+    /// Get the index of a period with the same bounds as a range.
+    pub fn index_of(&self, range: &DateRange) -> Option<usize> {
+        self.index_for(range.start_date())
+            .filter(|index| self.periods[*index].same_bounds_as(range))
+    }
+
+    /// Get the periods that overlap a span.
+    pub fn slice(&self, span: &DateRange) -> &[DateRange] {
+        let start = self.periods.partition_point(|period| period.end_date() < span.start_date());
+        let end = self.periods.partition_point(|period| period.start_date() <= span.end_date());
+        &self.periods[start..end.max(start)]
+    }
+}
+
+impl<'a> IntoIterator for &'a PeriodTable {
+    type Item = &'a DateRange;
+    type IntoIter = slice::Iter<'a, DateRange>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PeriodTable;
+    use crate::daterange::date_range::DateRange;
+    use crate::daterange::monthly_date_range::MonthlyDateRange;
+    use crate::daterange::range_error::RangeError;
+    use crate::daterange::weekly_date_range::WeeklyDateRange;
+    use chrono::NaiveDate;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn covers_horizon_and_locates_periods() {
+        let monthly = MonthlyDateRange::with_end_date_on_first(d(2024, 1, 31));
+        let table = PeriodTable::new(&monthly, &DateRange::new(d(2024, 3, 15), d(2025, 2, 1))).unwrap();
+        assert_eq!(table.len(), 12);
+        assert_eq!(table.span(), Some(DateRange::new(d(2024, 3, 1), d(2025, 2, 28))));
+        assert_eq!(table.period_for(d(2024, 2, 29)), None);
+        assert_eq!(table.period_for(d(2024, 7, 4)), Some(&DateRange::new(d(2024, 7, 1), d(2024, 7, 31))));
+        assert_eq!(table.index_for(d(2025, 2, 28)), Some(11));
+        assert_eq!(table.index_of(&DateRange::new(d(2024, 5, 1), d(2024, 5, 31))), Some(2));
+        assert_eq!(table.index_of(&DateRange::new(d(2024, 5, 1), d(2024, 5, 30))), None);
+    }
+
+    #[test]
+    fn slices_by_span() {
+        let weekly = WeeklyDateRange::with_end_date(d(2024, 1, 7));
+        let table = PeriodTable::new(&weekly, &DateRange::new(d(2024, 1, 1), d(2024, 2, 25))).unwrap();
+        let slice = table.slice(&DateRange::new(d(2024, 1, 10), d(2024, 1, 22)));
+        assert_eq!(slice.first().map(|p| p.start_date()), Some(d(2024, 1, 8)));
+        assert_eq!(slice.last().map(|p| p.end_date()), Some(d(2024, 1, 28)));
+        assert_eq!(slice.len(), 3);
+        assert!(table.slice(&DateRange::new(d(2023, 1, 1), d(2023, 12, 31))).is_empty());
+        assert_eq!(table.slice(&DateRange::new(d(2000, 1, 1), d(2100, 1, 1))).len(), table.len());
+    }
+
+    #[test]
+    fn rejects_plain_ranges() {
+        let plain = DateRange::new(d(2024, 1, 1), d(2024, 1, 31));
+        assert_eq!(PeriodTable::new(&plain, &plain), Err(RangeError::NotPeriodic));
+        assert_eq!(PeriodTable::new(&plain.repeating(), &plain).map(|t| t.len()), Ok(1));
+    }
+}
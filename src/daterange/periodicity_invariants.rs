@@ -0,0 +1,245 @@
+use crate::daterange::date_range::DateRange;
+use chrono::{Duration, NaiveDate};
+use core::error::Error;
+use core::fmt;
+
+/// A broken periodicity invariant found by [`check_periodicity_invariants`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PeriodicityViolation {
+    /// Moving to the next range and then back to the prior range did not return to `range`.
+    NextPriorMismatch { range: DateRange, round_trip: DateRange },
+    /// Moving to the prior range and then back to the next range did not return to `range`.
+    PriorNextMismatch { range: DateRange, round_trip: DateRange },
+    /// The range after `range` does not start the day after it ends.
+    NotTiled { range: DateRange, next: DateRange },
+    /// `range_containing_date` did not return the range found by iterating.
+    ContainingMismatch { date: NaiveDate, expected: DateRange, actual: Option<DateRange> },
+    /// The range has no periodicity, so there are no other ranges to check. See `DateRange::repeating`.
+    NotPeriodic { range: DateRange },
+}
+
+impl fmt::Display for PeriodicityViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PeriodicityViolation::NextPriorMismatch { range, round_trip } => write!(
+                f,
+                "next().prior() of {}..{} is {}..{}",
+                range.start_date(),
+                range.end_date(),
+                round_trip.start_date(),
+                round_trip.end_date()
+            ),
+            PeriodicityViolation::PriorNextMismatch { range, round_trip } => write!(
+                f,
+                "prior().next() of {}..{} is {}..{}",
+                range.start_date(),
+                range.end_date(),
+                round_trip.start_date(),
+                round_trip.end_date()
+            ),
+            PeriodicityViolation::NotTiled { range, next } => write!(
+                f,
+                "{}..{} is followed by {}..{}",
+                range.start_date(),
+                range.end_date(),
+                next.start_date(),
+                next.end_date()
+            ),
+            PeriodicityViolation::ContainingMismatch { date, expected, actual } => match actual {
+                Some(actual) => write!(
+                    f,
+                    "range containing {} is {}..{}, expected {}..{}",
+                    date,
+                    actual.start_date(),
+                    actual.end_date(),
+                    expected.start_date(),
+                    expected.end_date()
+                ),
+                None => write!(
+                    f,
+                    "no range containing {} was found, expected {}..{}",
+                    date,
+                    expected.start_date(),
+                    expected.end_date()
+                ),
+            },
+            PeriodicityViolation::NotPeriodic { range } => {
+                write!(f, "{}..{} is not periodic", range.start_date(), range.end_date())
+            }
+        }
+    }
+}
+
+impl Error for PeriodicityViolation {}
+
+/// Check that a range's prior/next strategy behaves like a periodic calendar over the `n` ranges
+/// before and after it: stepping forward and back returns to the same range, consecutive ranges
+/// tile without gaps or overlaps, and `range_containing_date` agrees with iteration for the
+/// first and last day of every range.
+///
+/// Intended for validating custom strategies in tests. The first violation found is returned,
+/// and a range without a periodicity fails with `NotPeriodic`.
+pub fn check_periodicity_invariants(range: &DateRange, n: usize) -> Result<(), PeriodicityViolation> {
+    if !range.is_periodic() {
+        return Err(PeriodicityViolation::NotPeriodic { range: *range });
+    }
+    let ranges = range.ranges_window(n, n);
+
+    for current in &ranges {
+        let round_trip = current.next().prior();
+        if !round_trip.same_bounds_as(current) {
+            return Err(PeriodicityViolation::NextPriorMismatch { range: *current, round_trip });
+        }
+        let round_trip = current.prior().next();
+        if !round_trip.same_bounds_as(current) {
+            return Err(PeriodicityViolation::PriorNextMismatch { range: *current, round_trip });
+        }
+    }
+
+    check_tiled(&ranges)?;
+
+    let limit = 2 * n + 2;
+    for expected in &ranges {
+        for date in [expected.start_date(), expected.end_date()] {
+            let actual = range.try_range_containing_date_within(date, limit).ok();
+            if !actual.is_some_and(|actual| actual.same_bounds_as(expected)) {
+                return Err(PeriodicityViolation::ContainingMismatch { date, expected: *expected, actual });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that the `n` ranges before and after a range partition the timeline exactly, each
+/// range starting the day after the previous one ends. Unlike [`check_periodicity_invariants`]
+/// this doesn't require `prior` to undo `next`.
+pub fn verify_tiling(range: &DateRange, n: usize) -> Result<(), PeriodicityViolation> {
+    check_tiled(&range.ranges_window(n, n))
+}
+
+fn check_tiled(ranges: &[DateRange]) -> Result<(), PeriodicityViolation> {
+    for pair in ranges.windows(2) {
+        if pair[1].start_date() != pair[0].end_date() + Duration::days(1) {
+            return Err(PeriodicityViolation::NotTiled { range: pair[0], next: pair[1] });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_periodicity_invariants, verify_tiling, PeriodicityViolation};
+    use crate::daterange::annual_date_range::AnnualDateRange;
+    use crate::daterange::date_range::DateRange;
+    use crate::daterange::monthly_date_range::MonthlyDateRange;
+    use crate::daterange::quarterly_date_range::QuarterlyDateRange;
+    use crate::daterange::semi_annual_date_range::SemiAnnualDateRange;
+    use crate::daterange::semi_monthly_date_range::SemiMonthlyDateRange;
+    use chrono::{Duration, NaiveDate};
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    fn next_overlapping(date_range: &DateRange) -> DateRange {
+        DateRange::new_with_prior_next(
+            date_range.start_date() + Duration::days(6),
+            date_range.end_date() + Duration::days(6),
+            prior_overlapping,
+            next_overlapping,
+        )
+    }
+
+    fn prior_overlapping(date_range: &DateRange) -> DateRange {
+        DateRange::new_with_prior_next(
+            date_range.start_date() - Duration::days(6),
+            date_range.end_date() - Duration::days(6),
+            prior_overlapping,
+            next_overlapping,
+        )
+    }
+
+    fn next_week(date_range: &DateRange) -> DateRange {
+        DateRange::new_with_prior_next(
+            date_range.start_date() + Duration::days(7),
+            date_range.end_date() + Duration::days(7),
+            prior_two_weeks,
+            next_week,
+        )
+    }
+
+    fn prior_two_weeks(date_range: &DateRange) -> DateRange {
+        DateRange::new_with_prior_next(
+            date_range.start_date() - Duration::days(14),
+            date_range.end_date() - Duration::days(14),
+            prior_two_weeks,
+            next_week,
+        )
+    }
+
+    #[test]
+    fn built_in_strategies_hold_invariants() {
+        let week = DateRange::new(d(2024, 1, 1), d(2024, 1, 7)).repeating();
+        assert_eq!(check_periodicity_invariants(&week, 10), Ok(()));
+        let half_month = SemiMonthlyDateRange::with_end_date(d(2024, 2, 15));
+        assert_eq!(check_periodicity_invariants(&half_month, 24), Ok(()));
+        let month = MonthlyDateRange::with_end_date_on_first(d(2024, 2, 29));
+        assert_eq!(check_periodicity_invariants(&month, 24), Ok(()));
+        let quarter = QuarterlyDateRange::with_start_date(d(2024, 1, 1));
+        assert_eq!(check_periodicity_invariants(&quarter, 8), Ok(()));
+    }
+
+    #[test]
+    fn periodic_factories_tile_over_long_horizons() {
+        let factories = [
+            MonthlyDateRange::with_end_date_and_start_day(d(2024, 1, 30), 31),
+            MonthlyDateRange::with_end_date_and_start_day(d(2024, 3, 28), 29),
+            QuarterlyDateRange::with_start_date(d(2024, 2, 1)),
+            SemiAnnualDateRange::with_start_date(d(2023, 8, 31)),
+            SemiAnnualDateRange::with_end_date(d(2024, 2, 29)),
+            AnnualDateRange::with_start_date(d(2024, 2, 29)),
+            AnnualDateRange::with_end_date(d(2024, 2, 29)),
+        ];
+        for range in factories {
+            assert_eq!(verify_tiling(&range, 400), Ok(()), "{:?}", range);
+        }
+
+        let month = MonthlyDateRange::with_end_date_and_start_day(d(2024, 1, 30), 31);
+        assert_eq!(check_periodicity_invariants(&month, 24), Ok(()));
+        assert_eq!(month.ranges_after(2)[1].end_date(), d(2024, 3, 30));
+        let half = SemiAnnualDateRange::with_start_date(d(2023, 8, 31));
+        assert_eq!(check_periodicity_invariants(&half, 24), Ok(()));
+    }
+
+    #[test]
+    fn verify_tiling_reports_gaps() {
+        let range =
+            DateRange::new_with_prior_next(d(2024, 1, 1), d(2024, 1, 7), prior_overlapping, next_overlapping);
+        assert!(matches!(verify_tiling(&range, 1), Err(PeriodicityViolation::NotTiled { .. })));
+    }
+
+    #[test]
+    fn detects_overlapping_ranges() {
+        let range =
+            DateRange::new_with_prior_next(d(2024, 1, 1), d(2024, 1, 7), prior_overlapping, next_overlapping);
+        let violation = check_periodicity_invariants(&range, 2).unwrap_err();
+        assert!(matches!(violation, PeriodicityViolation::NotTiled { .. }));
+        assert_eq!(violation.to_string(), "2023-12-20..2023-12-26 is followed by 2023-12-26..2024-01-01");
+    }
+
+    #[test]
+    fn plain_ranges_are_not_periodic() {
+        let plain = DateRange::new(d(2024, 1, 1), d(2024, 1, 7));
+        let violation = check_periodicity_invariants(&plain, 2).unwrap_err();
+        assert_eq!(violation, PeriodicityViolation::NotPeriodic { range: plain });
+        assert_eq!(violation.to_string(), "2024-01-01..2024-01-07 is not periodic");
+    }
+
+    #[test]
+    fn detects_asymmetric_prior_and_next() {
+        let range = DateRange::new_with_prior_next(d(2024, 1, 1), d(2024, 1, 7), prior_two_weeks, next_week);
+        let violation = check_periodicity_invariants(&range, 2).unwrap_err();
+        assert!(matches!(violation, PeriodicityViolation::NextPriorMismatch { .. }));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::daterange::date_range::DateRange;
+use crate::daterange::frequency::Frequency;
 use crate::dateutils::date_utils::{add_months, first_day_of_month, last_day_of_month, subtract_months};
 use chrono::NaiveDate;
 
@@ -13,7 +14,7 @@ impl QuarterlyDateRange {
         DateRange::new_with_prior_next(start,
                                        end,
                                        QuarterlyDateRange::prior,
-                                       QuarterlyDateRange::next)
+                                       QuarterlyDateRange::next).with_frequency(Frequency::Quarterly)
     }
 
     /// Creates a quarterly range ending at the given end_date.
@@ -24,7 +25,7 @@ impl QuarterlyDateRange {
         DateRange::new_with_prior_next(start,
                                        end,
                                        QuarterlyDateRange::prior,
-                                       QuarterlyDateRange::next)
+                                       QuarterlyDateRange::next).with_frequency(Frequency::Quarterly)
     }
 
     /// Returns the previous quarter.
@@ -35,7 +36,7 @@ impl QuarterlyDateRange {
         DateRange::new_with_prior_next(start,
                                        end,
                                        QuarterlyDateRange::prior,
-                                       QuarterlyDateRange::next)
+                                       QuarterlyDateRange::next).with_frequency(Frequency::Quarterly)
     }
 
     /// Returns the next quarter.
@@ -46,7 +47,7 @@ impl QuarterlyDateRange {
         DateRange::new_with_prior_next(start,
                                        end,
                                        QuarterlyDateRange::prior,
-                                       QuarterlyDateRange::next)
+                                       QuarterlyDateRange::next).with_frequency(Frequency::Quarterly)
     }
 }
 
@@ -0,0 +1,111 @@
+use crate::daterange::date_range::DateRange;
+use chrono::NaiveDate;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+type ChangeListener = Box<dyn FnMut(&DateRange, &DateRange)>;
+
+/// Keeps track of the current range while navigating through a chain of ranges, notifying
+/// listeners whenever the current range changes.
+pub struct RangeCursor {
+    current: DateRange,
+    listeners: Vec<ChangeListener>,
+}
+
+impl RangeCursor {
+    pub fn new(date_range: DateRange) -> Self {
+        Self {
+            current: date_range,
+            listeners: Vec::new(),
+        }
+    }
+
+    /// Register a callback that receives the previous and new range each time the current range changes.
+    pub fn on_change<F: FnMut(&DateRange, &DateRange) + 'static>(&mut self, listener: F) {
+        self.listeners.push(Box::new(listener));
+    }
+
+    /// Get the current range.
+    pub fn current(&self) -> DateRange {
+        self.current
+    }
+
+    /// Move to the prior range and return it.
+    pub fn go_prior(&mut self) -> DateRange {
+        self.set_current(self.current.prior())
+    }
+
+    /// Move to the next range and return it.
+    pub fn go_next(&mut self) -> DateRange {
+        self.set_current(self.current.next())
+    }
+
+    /// Move to the range containing a date and return it.
+    pub fn go_to_date(&mut self, date: NaiveDate) -> DateRange {
+        self.set_current(self.current.range_containing_date(date))
+    }
+
+    /// Get the current range along with `before` ranges before it and `after` ranges after it.
+    pub fn window(&self, before: usize, after: usize) -> Vec<DateRange> {
+        self.current.ranges_window(before, after)
+    }
+
+    fn set_current(&mut self, date_range: DateRange) -> DateRange {
+        if date_range != self.current {
+            let previous = self.current;
+            self.current = date_range;
+            for listener in self.listeners.iter_mut() {
+                listener(&previous, &date_range);
+            }
+        }
+        self.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RangeCursor;
+    use crate::daterange::date_range::DateRange;
+    use crate::daterange::monthly_date_range::MonthlyDateRange;
+    use chrono::NaiveDate;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn navigates_prior_next_and_to_date() {
+        let mut cursor = RangeCursor::new(MonthlyDateRange::with_end_date_on_first(d(2024, 1, 31)));
+
+        assert_eq!(cursor.go_next(), DateRange::new(d(2024, 2, 1), d(2024, 2, 29)));
+        assert_eq!(cursor.go_prior(), DateRange::new(d(2024, 1, 1), d(2024, 1, 31)));
+        assert_eq!(cursor.go_prior(), DateRange::new(d(2023, 12, 1), d(2023, 12, 31)));
+        assert_eq!(cursor.go_to_date(d(2024, 6, 15)), DateRange::new(d(2024, 6, 1), d(2024, 6, 30)));
+        assert_eq!(cursor.current(), DateRange::new(d(2024, 6, 1), d(2024, 6, 30)));
+
+        let window = cursor.window(1, 1);
+        assert_eq!(window, vec![
+            DateRange::new(d(2024, 5, 1), d(2024, 5, 31)),
+            DateRange::new(d(2024, 6, 1), d(2024, 6, 30)),
+            DateRange::new(d(2024, 7, 1), d(2024, 7, 31)),
+        ]);
+    }
+
+    #[test]
+    fn listeners_are_notified_only_when_current_changes() {
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let mut cursor = RangeCursor::new(MonthlyDateRange::with_end_date_on_first(d(2024, 1, 31)));
+        let recorded = Rc::clone(&changes);
+        cursor.on_change(move |previous, current| {
+            recorded.borrow_mut().push((previous.start_date(), current.start_date()));
+        });
+
+        cursor.go_next();
+        cursor.go_to_date(d(2024, 2, 10)); // already current
+        cursor.go_to_date(d(2024, 4, 10));
+
+        assert_eq!(*changes.borrow(), vec![(d(2024, 1, 1), d(2024, 2, 1)), (d(2024, 2, 1), d(2024, 4, 1))]);
+    }
+}
@@ -0,0 +1,34 @@
+use alloc::string::String;
+use core::error::Error;
+use core::fmt;
+
+/// Errors returned by the fallible DateRange operations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RangeError {
+    /// Stepping through prior or next ranges did not reach the target within the limit.
+    IterationLimitExceeded { limit: usize },
+    /// A period ID could not be parsed.
+    InvalidPeriodId(String),
+    /// A partition key could not be parsed.
+    InvalidPartitionKey(String),
+    /// An ISO 8601 interval could not be parsed.
+    InvalidInterval(String),
+    /// The range has no periodicity to find other ranges with.
+    NotPeriodic,
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeError::IterationLimitExceeded { limit } => {
+                write!(f, "no containing range found within {} steps", limit)
+            }
+            RangeError::InvalidPeriodId(id) => write!(f, "invalid period id: {}", id),
+            RangeError::InvalidPartitionKey(key) => write!(f, "invalid partition key: {}", key),
+            RangeError::InvalidInterval(interval) => write!(f, "invalid interval: {}", interval),
+            RangeError::NotPeriodic => write!(f, "range is not periodic"),
+        }
+    }
+}
+
+impl Error for RangeError {}
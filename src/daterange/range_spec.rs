@@ -0,0 +1,95 @@
+use crate::daterange::date_range::DateRange;
+use crate::daterange::date_range_builder::DateRangeBuilder;
+use crate::daterange::frequency::Frequency;
+use chrono::{NaiveDate, Weekday};
+
+/// A declarative period definition, such as one stored per tenant, that can be turned into
+/// the concrete range containing any date.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RangeSpec {
+    pub frequency: Frequency,
+    /// A date on which some period starts. When set, periods are phased from this date and
+    /// `end_day`, `start_day` and `fiscal_start_month` are ignored.
+    pub anchor: Option<NaiveDate>,
+    /// The day of the month monthly periods start on.
+    pub start_day: usize,
+    /// The weekday weekly and bi-weekly periods end on.
+    pub end_day: Weekday,
+    /// The month (1-12) quarterly, semi-annual and annual periods are aligned to.
+    pub fiscal_start_month: u32,
+}
+
+impl RangeSpec {
+    /// Creates a spec using the same defaults as [`DateRangeBuilder`].
+    pub fn new(frequency: Frequency) -> Self {
+        Self {
+            frequency,
+            anchor: None,
+            start_day: 1,
+            end_day: Weekday::Sun,
+            fiscal_start_month: 1,
+        }
+    }
+
+    /// Get the period of this spec that contains a date, or None if the spec is invalid.
+    pub fn range_containing_date(&self, date: NaiveDate) -> Option<DateRange> {
+        match self.anchor {
+            Some(anchor) => {
+                let first = DateRangeBuilder::new().frequency(self.frequency).starting_on(anchor).build()?;
+                Some(first.range_containing_date(date))
+            }
+            None => DateRangeBuilder::new()
+                .frequency(self.frequency)
+                .ending(self.end_day)
+                .start_day(self.start_day)
+                .fiscal_start(self.fiscal_start_month)
+                .containing(date)
+                .build(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RangeSpec;
+    use crate::daterange::frequency::Frequency;
+    use chrono::{NaiveDate, Weekday};
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn unanchored_spec_uses_alignment_settings() {
+        let spec = RangeSpec { end_day: Weekday::Fri, ..RangeSpec::new(Frequency::Weekly) };
+        let week = spec.range_containing_date(d(2024, 3, 13)).unwrap();
+        assert_eq!((week.start_date(), week.end_date()), (d(2024, 3, 9), d(2024, 3, 15)));
+
+        let spec = RangeSpec { start_day: 16, ..RangeSpec::new(Frequency::Monthly) };
+        let month = spec.range_containing_date(d(2024, 3, 20)).unwrap();
+        assert_eq!((month.start_date(), month.end_date()), (d(2024, 3, 16), d(2024, 4, 15)));
+
+        let spec = RangeSpec { fiscal_start_month: 7, ..RangeSpec::new(Frequency::Annual) };
+        let year = spec.range_containing_date(d(2024, 3, 20)).unwrap();
+        assert_eq!((year.start_date(), year.end_date()), (d(2023, 7, 1), d(2024, 6, 30)));
+    }
+
+    #[test]
+    fn anchored_spec_phases_from_anchor() {
+        let spec = RangeSpec { anchor: Some(d(2024, 1, 8)), ..RangeSpec::new(Frequency::BiWeekly) };
+        let first = spec.range_containing_date(d(2024, 1, 10)).unwrap();
+        assert_eq!((first.start_date(), first.end_date()), (d(2024, 1, 8), d(2024, 1, 21)));
+
+        let later = spec.range_containing_date(d(2024, 3, 4)).unwrap();
+        assert_eq!((later.start_date(), later.end_date()), (d(2024, 3, 4), d(2024, 3, 17)));
+
+        let before = spec.range_containing_date(d(2024, 1, 1)).unwrap();
+        assert_eq!((before.start_date(), before.end_date()), (d(2023, 12, 25), d(2024, 1, 7)));
+    }
+
+    #[test]
+    fn invalid_spec_yields_none() {
+        let spec = RangeSpec { fiscal_start_month: 0, ..RangeSpec::new(Frequency::Quarterly) };
+        assert_eq!(spec.range_containing_date(d(2024, 3, 20)), None);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::daterange::date_range::DateRange;
+use chrono::Duration;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Split a target range at the boundaries of a set of rate bands and pair each piece with the
+/// bands that apply to it.
+///
+/// Pieces are returned in date order. When bands overlap, a piece is returned once for each
+/// band covering it, in the order the bands were given. Parts of the target not covered by
+/// any band are omitted.
+pub fn apply_rate_bands<'a, B>(target: &DateRange, bands: &'a [(DateRange, B)]) -> Vec<(DateRange, &'a B)> {
+    let target_end = target.end_date() + Duration::days(1);
+    let mut boundaries = vec![target.start_date(), target_end];
+    for (range, _) in bands {
+        for boundary in [range.start_date(), range.end_date() + Duration::days(1)] {
+            if boundary > target.start_date() && boundary < target_end {
+                boundaries.push(boundary);
+            }
+        }
+    }
+    boundaries.sort();
+    boundaries.dedup();
+
+    let mut pieces = Vec::new();
+    for window in boundaries.windows(2) {
+        let piece = DateRange::new(window[0], window[1] - Duration::days(1));
+        for (range, band) in bands {
+            if range.contains_date(piece.start_date()) {
+                pieces.push((piece, band));
+            }
+        }
+    }
+    pieces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::apply_rate_bands;
+    use crate::daterange::date_range::DateRange;
+    use chrono::NaiveDate;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn splits_target_at_band_boundaries() {
+        let bands = vec![
+            (DateRange::new(d(2023, 1, 1), d(2024, 3, 14)), 0.05),
+            (DateRange::new(d(2024, 3, 15), d(2024, 12, 31)), 0.06),
+        ];
+        let target = DateRange::new(d(2024, 3, 1), d(2024, 3, 31));
+
+        let pieces = apply_rate_bands(&target, &bands);
+        assert_eq!(pieces, vec![
+            (DateRange::new(d(2024, 3, 1), d(2024, 3, 14)), &0.05),
+            (DateRange::new(d(2024, 3, 15), d(2024, 3, 31)), &0.06),
+        ]);
+    }
+
+    #[test]
+    fn overlapping_bands_each_apply_and_uncovered_days_are_omitted() {
+        let bands = vec![
+            (DateRange::new(d(2024, 1, 5), d(2024, 1, 20)), "state"),
+            (DateRange::new(d(2024, 1, 10), d(2024, 1, 25)), "city"),
+        ];
+        let target = DateRange::new(d(2024, 1, 1), d(2024, 1, 31));
+
+        let pieces = apply_rate_bands(&target, &bands);
+        assert_eq!(pieces, vec![
+            (DateRange::new(d(2024, 1, 5), d(2024, 1, 9)), &"state"),
+            (DateRange::new(d(2024, 1, 10), d(2024, 1, 20)), &"state"),
+            (DateRange::new(d(2024, 1, 10), d(2024, 1, 20)), &"city"),
+            (DateRange::new(d(2024, 1, 21), d(2024, 1, 25)), &"city"),
+        ]);
+    }
+
+    #[test]
+    fn no_bands_yields_no_pieces() {
+        let bands: Vec<(DateRange, u32)> = vec![];
+        let target = DateRange::new(d(2024, 1, 1), d(2024, 1, 31));
+        assert!(apply_rate_bands(&target, &bands).is_empty());
+    }
+}
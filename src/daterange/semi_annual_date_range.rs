@@ -1,63 +1,86 @@
+use crate::daterange::billing_cycle::anchored_date;
 use crate::daterange::date_range::DateRange;
-use crate::dateutils::date_utils::{add_months, subtract_months};
-use chrono::{Duration, NaiveDate};
+use crate::daterange::frequency::Frequency;
+use crate::dateutils::date_utils::{add_months, add_months_eom, first_day_of_month};
+use chrono::{Datelike, Duration, NaiveDate};
 
 pub struct SemiAnnualDateRange;
 
 impl SemiAnnualDateRange {
+    /// Create a semi-annual range starting on a date, deriving every later boundary from the
+    /// start's day of the month instead of from the neighbouring range. A start day that a
+    /// month doesn't have is clamped in that month only, so the ranges always tile.
     pub fn with_start_date(start_date: NaiveDate) -> DateRange {
-        let end_date = add_months(start_date, 6) - Duration::days(1);
+        Self::canonical(start_date, start_date.day() as usize)
+    }
+
+    /// Create a semi-annual range ending on a date, anchored on the day after it. See
+    /// `with_start_date`.
+    pub fn with_end_date(end_date: NaiveDate) -> DateRange {
+        let anchor_day = (end_date + Duration::days(1)).day() as usize;
+        let start = anchored_date(add_months(first_day_of_month(end_date + Duration::days(1)), -6), anchor_day);
+        Self::canonical(start, anchor_day)
+    }
+
+    /// Create a semi-annual range ending on a date, stepping the end date with `add_months_eom`
+    /// so that ends on the last day of a month stay on the last day of later months.
+    pub fn with_end_date_eom(end_date: NaiveDate) -> DateRange {
+        Self::eom(add_months_eom(end_date, -6) + Duration::days(1), end_date)
+    }
+
+    /// Create a semi-annual range starting on a date, in the end of month mode of
+    /// `with_end_date_eom`.
+    pub fn with_start_date_eom(start_date: NaiveDate) -> DateRange {
+        Self::eom(start_date, add_months(start_date, 6) - Duration::days(1))
+    }
 
+    fn eom(start_date: NaiveDate, end_date: NaiveDate) -> DateRange {
         DateRange::new_with_prior_next(
             start_date,
             end_date,
-            SemiAnnualDateRange::prior,
-            SemiAnnualDateRange::next,
-        )
+            SemiAnnualDateRange::prior_eom,
+            SemiAnnualDateRange::next_eom,
+        ).with_frequency(Frequency::SemiAnnual)
     }
 
-    pub fn with_end_date(end_date: NaiveDate) -> DateRange {
-        let start_date = subtract_months(end_date, 6) + Duration::days(1);
+    fn prior_eom(date_range: &DateRange) -> DateRange {
+        let end = date_range.start_date() - Duration::days(1);
+        Self::eom(add_months_eom(end, -6) + Duration::days(1), end)
+    }
 
-        DateRange::new_with_prior_next(
+    fn next_eom(date_range: &DateRange) -> DateRange {
+        Self::eom(date_range.end_date() + Duration::days(1), add_months_eom(date_range.end_date(), 6))
+    }
+
+    fn canonical(start_date: NaiveDate, anchor_day: usize) -> DateRange {
+        let next_start = anchored_date(add_months(first_day_of_month(start_date), 6), anchor_day);
+        DateRange::new_with_prior_next_start_day(
             start_date,
-            end_date,
+            next_start - Duration::days(1),
             SemiAnnualDateRange::prior,
             SemiAnnualDateRange::next,
-        )
+            Some(anchor_day),
+        ).with_frequency(Frequency::SemiAnnual)
     }
 
     /// Returns the prior range.
     pub fn prior(date_range: &DateRange) -> DateRange {
-        let start = subtract_months(date_range.start_date(), 6);
-        let end = subtract_months(date_range.end_date(), 6);
-
-        DateRange::new_with_prior_next(
-            start,
-            end,
-            SemiAnnualDateRange::prior,
-            SemiAnnualDateRange::next,
-        )
+        let anchor_day = date_range.start_day().unwrap_or(date_range.start_date().day() as usize);
+        let start = anchored_date(add_months(first_day_of_month(date_range.start_date()), -6), anchor_day);
+        Self::canonical(start, anchor_day)
     }
 
     /// Returns the next range.
     pub fn next(date_range: &DateRange) -> DateRange {
-        let start = add_months(date_range.start_date(), 6);
-        let end = add_months(date_range.end_date(), 6);
-
-        DateRange::new_with_prior_next(
-            start,
-            end,
-            SemiAnnualDateRange::prior,
-            SemiAnnualDateRange::next,
-        )
+        let anchor_day = date_range.start_day().unwrap_or(date_range.start_date().day() as usize);
+        Self::canonical(date_range.end_date() + Duration::days(1), anchor_day)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::SemiAnnualDateRange;
-    use chrono::NaiveDate;
+    use chrono::{Duration, NaiveDate};
 
     fn d(y: i32, m: u32, day: u32) -> NaiveDate {
         NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
@@ -86,9 +109,9 @@ mod tests {
 
     #[test]
     fn with_end_date_spans_six_months_inclusive() {
-        // End on Jun 30, 2023 -> start Dec 31, 2022 (preserves day-of-month symmetry)
+        // End on Jun 30, 2023 -> start Jan 1, 2023 (anchored on the day after the end)
         let r1 = SemiAnnualDateRange::with_end_date(d(2023, 6, 30));
-        assert_eq!(r1.start_date(), d(2022, 12, 31));
+        assert_eq!(r1.start_date(), d(2023, 1, 1));
         assert_eq!(r1.end_date(), d(2023, 6, 30));
 
         // End on Dec 31, 2023 -> start Jul 1, 2023
@@ -118,10 +141,10 @@ mod tests {
         assert_eq!(h1_2024.start_date(), d(2024, 1, 1));
         assert_eq!(h1_2024.end_date(), d(2024, 6, 30));
 
-        // Prior from Jan 1, 2024 -> Jul 1, 2023 .. Dec 30, 2023 (end shifted by add_months semantics)
+        // Prior from Jan 1, 2024 -> Jul 1, 2023 .. Dec 31, 2023
         let back = h1_2024.prior();
         assert_eq!(back.start_date(), d(2023, 7, 1));
-        assert_eq!(back.end_date(), d(2023, 12, 30));
+        assert_eq!(back.end_date(), d(2023, 12, 31));
     }
 
     #[test]
@@ -133,14 +156,14 @@ mod tests {
         let oct = d(2023, 10, 10);
         let found = base.range_containing_date(oct);
         assert_eq!(found.start_date(), d(2023, 7, 1));
-        assert_eq!(found.end_date(), d(2023, 12, 30));
+        assert_eq!(found.end_date(), d(2023, 12, 31));
         assert!(found.contains_date(oct));
 
         // A date in previous year November should map to 2022-07-01 .. 2022-12-31
         let nov_prev = d(2022, 11, 5);
         let found2 = base.range_containing_date(nov_prev);
         assert_eq!(found2.start_date(), d(2022, 7, 1));
-        assert_eq!(found2.end_date(), d(2022, 12, 30));
+        assert_eq!(found2.end_date(), d(2022, 12, 31));
         assert!(found2.contains_date(nov_prev));
     }
 
@@ -151,9 +174,43 @@ mod tests {
         assert_eq!(r.start_date(), d(2019, 8, 31));
         assert_eq!(r.end_date(), d(2020, 2, 28));
 
-        // Ending on Feb 29, 2020 -> subtract 6 months clamps to Aug 29, 2019; +1 day -> Aug 30, 2019
+        // Ending on Feb 29, 2020 -> anchored on Mar 1, 2020, so starts Sep 1, 2019
         let r2 = SemiAnnualDateRange::with_end_date(d(2020, 2, 29));
-        assert_eq!(r2.start_date(), d(2019, 8, 30));
+        assert_eq!(r2.start_date(), d(2019, 9, 1));
         assert_eq!(r2.end_date(), d(2020, 2, 29));
     }
+
+    #[test]
+    fn eom_mode_tiles_half_years() {
+        let h1 = SemiAnnualDateRange::with_start_date_eom(d(2023, 1, 1));
+        assert_eq!(h1.end_date(), d(2023, 6, 30));
+        let h2 = h1.next();
+        assert_eq!((h2.start_date(), h2.end_date()), (d(2023, 7, 1), d(2023, 12, 31)));
+        let h3 = h2.next();
+        assert_eq!((h3.start_date(), h3.end_date()), (d(2024, 1, 1), d(2024, 6, 30)));
+        assert_eq!(h3.prior().prior(), h1);
+
+        let aug = SemiAnnualDateRange::with_end_date_eom(d(2024, 2, 29));
+        assert_eq!(aug.start_date(), d(2023, 9, 1));
+        assert_eq!(aug.next().end_date(), d(2024, 8, 31));
+    }
+
+    #[test]
+    fn boundaries_are_derived_from_the_anchor() {
+        let h2 = SemiAnnualDateRange::with_start_date(d(2023, 7, 1));
+        assert_eq!(h2.end_date(), d(2023, 12, 31));
+        assert_eq!(h2.prior().end_date(), d(2023, 6, 30));
+
+        let aug31 = SemiAnnualDateRange::with_start_date(d(2023, 8, 31));
+        let ranges = aug31.ranges_after_inclusive(20);
+        assert_eq!(ranges[1].start_date(), d(2024, 2, 29));
+        assert_eq!(ranges[2].start_date(), d(2024, 8, 31));
+        assert!(ranges.windows(2).all(|w| w[0].end_date() + Duration::days(1) == w[1].start_date()));
+        assert_eq!(ranges[20].prior(), ranges[19]);
+        assert_eq!(aug31.range_containing_date(d(2030, 3, 1)).start_date(), d(2030, 2, 28));
+
+        let from_end = SemiAnnualDateRange::with_end_date(d(2023, 12, 31));
+        assert_eq!(from_end, h2);
+        assert_eq!(from_end.next().end_date(), d(2024, 6, 30));
+    }
 }
@@ -1,4 +1,5 @@
 use crate::daterange::date_range::DateRange;
+use crate::daterange::frequency::Frequency;
 use crate::dateutils::date_utils::last_day_of_month;
 use chrono::{Datelike, Duration, NaiveDate};
 
@@ -12,7 +13,21 @@ impl SemiMonthlyDateRange {
         DateRange::new_with_prior_next(start,
                                        end_date,
                                        SemiMonthlyDateRange::prior,
-                                       SemiMonthlyDateRange::next)
+                                       SemiMonthlyDateRange::next).with_frequency(Frequency::SemiMonthly)
+    }
+
+    /// Get the half of the month a date falls in: 1 for the 1st-15th and 2 for the rest.
+    pub fn half_of_month(date: NaiveDate) -> u32 {
+        if date.day() <= FIFTEENTH_OF_MONTH { 1 } else { 2 }
+    }
+
+    /// Get the semi-monthly period containing a date.
+    pub fn period_containing(date: NaiveDate) -> DateRange {
+        let end_date = match Self::half_of_month(date) {
+            1 => NaiveDate::from_ymd_opt(date.year(), date.month(), FIFTEENTH_OF_MONTH).unwrap(),
+            _ => last_day_of_month(date),
+        };
+        Self::with_end_date(end_date)
     }
 
     fn prior(date_range: &DateRange) -> DateRange {
@@ -30,7 +45,7 @@ impl SemiMonthlyDateRange {
             end_date,
             SemiMonthlyDateRange::prior,
             SemiMonthlyDateRange::next,
-        )
+        ).with_frequency(Frequency::SemiMonthly)
     }
 
     fn next(date_range: &DateRange) -> DateRange {
@@ -63,7 +78,7 @@ impl SemiMonthlyDateRange {
             end_date,
             SemiMonthlyDateRange::prior,
             SemiMonthlyDateRange::next,
-        )
+        ).with_frequency(Frequency::SemiMonthly)
     }
 }
 
@@ -90,6 +105,18 @@ mod tests {
         NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
     }
 
+    #[test]
+    fn half_of_month_and_period_containing() {
+        assert_eq!(SemiMonthlyDateRange::half_of_month(d(2024, 2, 15)), 1);
+        assert_eq!(SemiMonthlyDateRange::half_of_month(d(2024, 2, 16)), 2);
+
+        let first = SemiMonthlyDateRange::period_containing(d(2024, 2, 1));
+        assert_eq!((first.start_date(), first.end_date()), (d(2024, 2, 1), d(2024, 2, 15)));
+        let second = SemiMonthlyDateRange::period_containing(d(2024, 2, 20));
+        assert_eq!((second.start_date(), second.end_date()), (d(2024, 2, 16), d(2024, 2, 29)));
+        assert_eq!(second.next().start_date(), d(2024, 3, 1));
+    }
+
     #[test]
     fn with_end_date_on_15th_spans_1_to_15_inclusive() {
         let dr = SemiMonthlyDateRange::with_end_date(d(2023, 3, 15));
@@ -0,0 +1,118 @@
+use crate::daterange::date_range::DateRange;
+use crate::datetimerange::date_time_range::DateTimeRange;
+use chrono::{Duration, NaiveDate};
+use alloc::vec::Vec;
+
+/// A range carrying a tag such as a pay period name or a blackout label. Tagged ranges sort by
+/// range first and then by tag.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tagged<T, R> {
+    pub range: R,
+    pub tag: T,
+}
+
+impl<T, R> Tagged<T, R> {
+    pub fn new(tag: T, range: R) -> Self {
+        Self { range, tag }
+    }
+
+    /// Replace the tag, keeping the range.
+    pub fn map_tag<U, F: FnOnce(T) -> U>(self, f: F) -> Tagged<U, R> {
+        Tagged { range: self.range, tag: f(self.tag) }
+    }
+}
+
+impl<T: Clone> Tagged<T, DateRange> {
+    /// Get the part of the range within some bounds, keeping the tag.
+    pub fn intersection(&self, bounds: &DateRange) -> Option<Self> {
+        self.range.clip_to(bounds).map(|clipped| Self::new(self.tag.clone(), clipped.range))
+    }
+
+    /// Split the range on dates as `DateRange::split_on` does, tagging every piece.
+    pub fn split_on(&self, dates: &[NaiveDate]) -> Vec<Self> {
+        self.range.split_on(dates).into_iter().map(|range| Self::new(self.tag.clone(), range)).collect()
+    }
+}
+
+impl<T: Clone> Tagged<T, DateTimeRange> {
+    /// Get the part of the range that overlaps another, keeping the tag.
+    pub fn intersection(&self, other: &DateTimeRange) -> Option<Self> {
+        self.range.overlap_range(other).map(|range| Self::new(self.tag.clone(), range))
+    }
+}
+
+/// Merge tagged date ranges that overlap or touch and have the same tag. The result is sorted
+/// by range.
+pub fn union_by_tag<T: Clone + Ord>(items: &[Tagged<T, DateRange>]) -> Vec<Tagged<T, DateRange>> {
+    let mut sorted = items.to_vec();
+    sorted.sort_by(|a, b| a.tag.cmp(&b.tag).then_with(|| a.range.cmp(&b.range)));
+
+    let mut merged: Vec<Tagged<T, DateRange>> = Vec::with_capacity(sorted.len());
+    for item in sorted {
+        if let Some(last) = merged.last_mut()
+            && last.tag == item.tag
+            && item.range.start_date() <= last.range.end_date() + Duration::days(1)
+        {
+            let end = last.range.end_date().max(item.range.end_date());
+            last.range = DateRange::new(last.range.start_date(), end);
+            continue;
+        }
+        merged.push(item);
+    }
+    merged.sort();
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{union_by_tag, Tagged};
+    use crate::daterange::date_range::DateRange;
+    use crate::datetimerange::date_time_range::DateTimeRange;
+    use chrono::NaiveDate;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn tags_survive_sorting_and_set_operations() {
+        let pp07 = Tagged::new("PP-07", DateRange::new(d(2024, 3, 31), d(2024, 4, 13)));
+        let pp06 = Tagged::new("PP-06", DateRange::new(d(2024, 3, 17), d(2024, 3, 30)));
+        let mut periods = vec![pp07, pp06];
+        periods.sort();
+        assert_eq!(periods, vec![pp06, pp07]);
+
+        let march = DateRange::new(d(2024, 3, 1), d(2024, 3, 31));
+        let last_day = DateRange::new(d(2024, 3, 31), d(2024, 3, 31));
+        assert_eq!(pp07.intersection(&march), Some(Tagged::new("PP-07", last_day)));
+        assert_eq!(pp07.intersection(&DateRange::new(d(2024, 5, 1), d(2024, 5, 2))), None);
+
+        let pieces = pp07.split_on(&[d(2024, 4, 1)]);
+        assert_eq!(pieces.iter().map(|p| p.tag).collect::<Vec<_>>(), vec!["PP-07", "PP-07"]);
+        assert_eq!(pieces[1].range.start_date(), d(2024, 4, 1));
+        assert_eq!(pp07.map_tag(|tag| tag.len()).tag, 5);
+
+        let shift =
+            DateTimeRange::of(d(2024, 3, 1).and_hms_opt(22, 0, 0).unwrap(), d(2024, 3, 2).and_hms_opt(6, 0, 0).unwrap());
+        let night = Tagged::new("Night", shift);
+        let day_two = night.intersection(&DateTimeRange::all_day(d(2024, 3, 2))).unwrap();
+        assert_eq!((day_two.tag, day_two.range.duration().num_hours()), ("Night", 6));
+    }
+
+    #[test]
+    fn union_by_tag_merges_only_matching_tags() {
+        let items = [
+            Tagged::new("Blackout", DateRange::new(d(2024, 12, 20), d(2024, 12, 24))),
+            Tagged::new("Holiday", DateRange::new(d(2024, 12, 25), d(2024, 12, 26))),
+            Tagged::new("Blackout", DateRange::new(d(2024, 12, 25), d(2024, 12, 31))),
+            Tagged::new("Blackout", DateRange::new(d(2024, 12, 22), d(2024, 12, 23))),
+        ];
+        assert_eq!(
+            union_by_tag(&items),
+            vec![
+                Tagged::new("Blackout", DateRange::new(d(2024, 12, 20), d(2024, 12, 31))),
+                Tagged::new("Holiday", DateRange::new(d(2024, 12, 25), d(2024, 12, 26))),
+            ]
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use crate::dateutils::date_utils::{add_days, add_months, add_years};
+use chrono::{Duration, NaiveDate};
+
+/// A length of time expressed in calendar units.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Tenor {
+    Days(i64),
+    Weeks(i64),
+    Months(i32),
+    Years(i32),
+}
+
+impl Tenor {
+    /// Check if the tenor covers at least one day.
+    pub fn is_positive(&self) -> bool {
+        match *self {
+            Tenor::Days(n) | Tenor::Weeks(n) => n > 0,
+            Tenor::Months(n) | Tenor::Years(n) => n > 0,
+        }
+    }
+
+    /// Add the tenor to a date, clamping to the end of the month for month and year tenors.
+    pub fn add_to(&self, date: NaiveDate) -> NaiveDate {
+        match *self {
+            Tenor::Days(n) => add_days(date, n),
+            Tenor::Weeks(n) => add_days(date, n * 7),
+            Tenor::Months(n) => add_months(date, n),
+            Tenor::Years(n) => add_years(date, n),
+        }
+    }
+
+    /// Subtract the tenor from a date, clamping to the end of the month for month and year tenors.
+    pub fn subtract_from(&self, date: NaiveDate) -> NaiveDate {
+        match *self {
+            Tenor::Days(n) => add_days(date, -n),
+            Tenor::Weeks(n) => add_days(date, -n * 7),
+            Tenor::Months(n) => add_months(date, -n),
+            Tenor::Years(n) => add_years(date, -n),
+        }
+    }
+
+    /// Get the inclusive end date of a span of this tenor beginning on `start_date`.
+    pub fn end_date_from(&self, start_date: NaiveDate) -> NaiveDate {
+        self.add_to(start_date) - Duration::days(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Tenor;
+    use chrono::NaiveDate;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn end_date_from_is_inclusive() {
+        assert_eq!(Tenor::Days(14).end_date_from(d(2024, 1, 1)), d(2024, 1, 14));
+        assert_eq!(Tenor::Weeks(2).end_date_from(d(2024, 1, 1)), d(2024, 1, 14));
+        assert_eq!(Tenor::Months(1).end_date_from(d(2024, 1, 15)), d(2024, 2, 14));
+        assert_eq!(Tenor::Years(1).end_date_from(d(2024, 3, 1)), d(2025, 2, 28));
+    }
+
+    #[test]
+    fn subtract_from_reverses_add_to() {
+        assert_eq!(Tenor::Days(10).subtract_from(d(2024, 1, 5)), d(2023, 12, 26));
+        assert_eq!(Tenor::Months(3).subtract_from(d(2024, 5, 31)), d(2024, 2, 29));
+        assert_eq!(Tenor::Years(1).subtract_from(d(2024, 2, 29)), d(2023, 2, 28));
+    }
+
+    #[test]
+    fn is_positive_rejects_zero_and_negative() {
+        assert!(Tenor::Days(1).is_positive());
+        assert!(!Tenor::Weeks(0).is_positive());
+        assert!(!Tenor::Months(-1).is_positive());
+    }
+}
@@ -0,0 +1,117 @@
+use crate::daterange::date_range::DateRange;
+use crate::daterange::tenor::Tenor;
+use chrono::{Duration, NaiveDate};
+use alloc::vec::Vec;
+
+/// A lease or contract term: an initial term followed by optional renewal terms.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Term {
+    start_date: NaiveDate,
+    initial: Tenor,
+    renewals: usize,
+    renewal: Tenor,
+    notice: Option<Tenor>,
+}
+
+/// One generated term along with the window in which notice must be given before it ends.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TermPeriod {
+    /// 0 for the initial term, 1 for the first renewal, and so on.
+    pub renewal_number: usize,
+    pub term: DateRange,
+    pub notice_window: Option<DateRange>,
+}
+
+impl Term {
+    /// Create a term with no renewals beginning on `start_date`.
+    pub fn new(start_date: NaiveDate, initial: Tenor) -> Self {
+        Self {
+            start_date,
+            initial,
+            renewals: 0,
+            renewal: initial,
+            notice: None,
+        }
+    }
+
+    /// Add `count` renewal terms of the given tenor after the initial term.
+    pub fn with_renewals(mut self, count: usize, tenor: Tenor) -> Self {
+        self.renewals = count;
+        self.renewal = tenor;
+        self
+    }
+
+    /// Require notice of the given tenor before the end of each term.
+    pub fn with_notice(mut self, notice: Tenor) -> Self {
+        self.notice = Some(notice);
+        self
+    }
+
+    /// Get the initial term followed by each renewal term. Each renewal starts the day after
+    /// the previous term ends. Notice windows end on the term end date and are clipped to the term.
+    pub fn periods(&self) -> Vec<TermPeriod> {
+        let mut periods = Vec::with_capacity(self.renewals + 1);
+        let mut start = self.start_date;
+        for renewal_number in 0..=self.renewals {
+            let tenor = if renewal_number == 0 { self.initial } else { self.renewal };
+            let term = DateRange::new(start, tenor.end_date_from(start));
+            let notice_window = self.notice.map(|notice| {
+                let notice_start = notice.subtract_from(term.end_date() + Duration::days(1));
+                DateRange::new(notice_start.max(term.start_date()), term.end_date())
+            });
+            periods.push(TermPeriod {
+                renewal_number,
+                term,
+                notice_window,
+            });
+            start = term.end_date() + Duration::days(1);
+        }
+        periods
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Term;
+    use crate::daterange::date_range::DateRange;
+    use crate::daterange::tenor::Tenor;
+    use chrono::NaiveDate;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn initial_and_renewal_terms_are_back_to_back() {
+        let term = Term::new(d(2024, 3, 1), Tenor::Years(3)).with_renewals(2, Tenor::Years(1));
+        let periods = term.periods();
+
+        let terms: Vec<_> = periods.iter().map(|p| p.term).collect();
+        assert_eq!(terms, vec![
+            DateRange::new(d(2024, 3, 1), d(2027, 2, 28)),
+            DateRange::new(d(2027, 3, 1), d(2028, 2, 29)),
+            DateRange::new(d(2028, 3, 1), d(2029, 2, 28)),
+        ]);
+        assert_eq!(periods[2].renewal_number, 2);
+        assert!(periods.iter().all(|p| p.notice_window.is_none()));
+    }
+
+    #[test]
+    fn notice_windows_are_computed_backwards_from_term_end() {
+        let term = Term::new(d(2024, 1, 1), Tenor::Years(1))
+            .with_renewals(1, Tenor::Years(1))
+            .with_notice(Tenor::Months(3));
+        let periods = term.periods();
+
+        assert_eq!(periods[0].notice_window, Some(DateRange::new(d(2024, 10, 1), d(2024, 12, 31))));
+        assert_eq!(periods[1].notice_window, Some(DateRange::new(d(2025, 10, 1), d(2025, 12, 31))));
+    }
+
+    #[test]
+    fn notice_longer_than_term_is_clipped_to_term() {
+        let term = Term::new(d(2024, 1, 1), Tenor::Months(1)).with_notice(Tenor::Days(60));
+        let periods = term.periods();
+        assert_eq!(periods.len(), 1);
+        assert_eq!(periods[0].notice_window, Some(DateRange::new(d(2024, 1, 1), d(2024, 1, 31))));
+    }
+}
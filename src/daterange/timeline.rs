@@ -0,0 +1,270 @@
+use crate::daterange::date_range::DateRange;
+use chrono::{Duration, NaiveDate};
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+use core::slice;
+
+/// A segment that could not be added to a [`Timeline`] because it overlaps an existing one.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OverlapError {
+    pub existing: DateRange,
+    pub rejected: DateRange,
+}
+
+impl fmt::Display for OverlapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "segment {}..{} overlaps {}..{}",
+            self.rejected.start_date(),
+            self.rejected.end_date(),
+            self.existing.start_date(),
+            self.existing.end_date()
+        )
+    }
+}
+
+impl Error for OverlapError {}
+
+/// Labelled, non-overlapping date range segments kept in date order, such as a status history.
+///
+/// Segments may be added in any order; adding one that overlaps an existing segment fails and
+/// leaves the timeline unchanged. Gaps between segments are allowed until filled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Timeline<T> {
+    segments: Vec<(DateRange, T)>,
+}
+
+impl<T> Timeline<T> {
+    pub fn new() -> Self {
+        Self { segments: Vec::new() }
+    }
+
+    /// Add a segment to the timeline.
+    pub fn push(&mut self, range: DateRange, value: T) -> Result<(), OverlapError> {
+        let index = self.segments.partition_point(|(existing, _)| existing.start_date() < range.start_date());
+        let neighbours = [index.checked_sub(1), Some(index)];
+        for existing in neighbours.into_iter().flatten().filter_map(|i| self.segments.get(i)) {
+            if existing.0.overlaps(&range) {
+                return Err(OverlapError {
+                    existing: existing.0,
+                    rejected: range,
+                });
+            }
+        }
+        self.segments.insert(index, (range, value));
+        Ok(())
+    }
+
+    /// Add a segment, returning the timeline for chaining.
+    pub fn with(mut self, range: DateRange, value: T) -> Result<Self, OverlapError> {
+        self.push(range, value)?;
+        Ok(self)
+    }
+
+    /// Get the segments in date order.
+    pub fn segments(&self) -> &[(DateRange, T)] {
+        &self.segments
+    }
+
+    /// Iterate over the segments in date order.
+    pub fn iter(&self) -> slice::Iter<'_, (DateRange, T)> {
+        self.segments.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Get the range from the start of the first segment to the end of the last.
+    pub fn span(&self) -> Option<DateRange> {
+        let first = self.segments.first()?;
+        let last = self.segments.last()?;
+        Some(DateRange::new(first.0.start_date(), last.0.end_date()))
+    }
+
+    /// Get the segment containing a date.
+    pub fn segment_at(&self, date: NaiveDate) -> Option<&(DateRange, T)> {
+        let index = self.segments.partition_point(|(range, _)| range.start_date() <= date);
+        self.segments[..index].last().filter(|(range, _)| range.contains_date(date))
+    }
+
+    /// Get the value of the segment containing a date.
+    pub fn value_at(&self, date: NaiveDate) -> Option<&T> {
+        self.segment_at(date).map(|(_, value)| value)
+    }
+
+    /// Get the gaps between consecutive segments.
+    pub fn gaps(&self) -> Vec<DateRange> {
+        self.segments
+            .windows(2)
+            .filter(|pair| pair[1].0.start_date() > pair[0].0.end_date() + Duration::days(1))
+            .map(|pair| {
+                DateRange::new(pair[0].0.end_date() + Duration::days(1), pair[1].0.start_date() - Duration::days(1))
+            })
+            .collect()
+    }
+}
+
+impl<T: Clone> Timeline<T> {
+    /// Fill every gap in `span`, including any before the first segment and after the last,
+    /// with segments holding a default value. Segments outside the span are kept as they are.
+    pub fn fill_gaps(&mut self, span: &DateRange, default: T) {
+        let mut filled = Vec::with_capacity(self.segments.len() * 2 + 1);
+        let mut next_free = span.start_date();
+        for (range, value) in self.segments.drain(..) {
+            if range.start_date() > next_free && next_free <= span.end_date() {
+                let end = (range.start_date() - Duration::days(1)).min(span.end_date());
+                filled.push((DateRange::new(next_free, end), default.clone()));
+            }
+            next_free = next_free.max(range.end_date() + Duration::days(1));
+            filled.push((range, value));
+        }
+        if next_free <= span.end_date() {
+            filled.push((DateRange::new(next_free, span.end_date()), default));
+        }
+        self.segments = filled;
+    }
+}
+
+/// Join two timelines into the refinement of both segmentations. Each segment of the result is
+/// the intersection of one segment from each timeline, paired with both values, so dates
+/// covered by only one of the timelines are left out.
+pub fn join<A: Clone, B: Clone>(a: &Timeline<A>, b: &Timeline<B>) -> Timeline<(A, B)> {
+    let mut segments = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while let (Some((range_a, value_a)), Some((range_b, value_b))) = (a.segments.get(i), b.segments.get(j)) {
+        let start = range_a.start_date().max(range_b.start_date());
+        let end = range_a.end_date().min(range_b.end_date());
+        if start <= end {
+            segments.push((DateRange::new(start, end), (value_a.clone(), value_b.clone())));
+        }
+        if range_a.end_date() <= range_b.end_date() {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    Timeline { segments }
+}
+
+impl<T> Default for Timeline<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Timeline<T> {
+    type Item = &'a (DateRange, T);
+    type IntoIter = slice::Iter<'a, (DateRange, T)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{join, OverlapError, Timeline};
+    use crate::daterange::date_range::DateRange;
+    use chrono::NaiveDate;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    fn history() -> Timeline<&'static str> {
+        Timeline::new()
+            .with(DateRange::new(d(2024, 3, 1), d(2024, 3, 10)), "active")
+            .and_then(|t| t.with(DateRange::new(d(2024, 1, 1), d(2024, 1, 31)), "probation"))
+            .and_then(|t| t.with(DateRange::new(d(2024, 4, 1), d(2024, 4, 30)), "leave"))
+            .unwrap()
+    }
+
+    #[test]
+    fn segments_are_kept_in_order_and_looked_up_by_date() {
+        let timeline = history();
+        let values: Vec<_> = timeline.iter().map(|(_, value)| *value).collect();
+        assert_eq!(values, vec!["probation", "active", "leave"]);
+        assert_eq!(timeline.value_at(d(2024, 3, 10)), Some(&"active"));
+        assert_eq!(timeline.value_at(d(2024, 3, 11)), None);
+        assert_eq!(timeline.value_at(d(2023, 12, 31)), None);
+        assert_eq!(timeline.span(), Some(DateRange::new(d(2024, 1, 1), d(2024, 4, 30))));
+        assert_eq!(
+            timeline.gaps(),
+            vec![DateRange::new(d(2024, 2, 1), d(2024, 2, 29)), DateRange::new(d(2024, 3, 11), d(2024, 3, 31))]
+        );
+        assert_eq!((&timeline).into_iter().count(), 3);
+    }
+
+    #[test]
+    fn overlapping_segments_are_rejected() {
+        let mut timeline = history();
+        let error = timeline.push(DateRange::new(d(2024, 1, 31), d(2024, 2, 5)), "late").unwrap_err();
+        assert_eq!(
+            error,
+            OverlapError {
+                existing: DateRange::new(d(2024, 1, 1), d(2024, 1, 31)),
+                rejected: DateRange::new(d(2024, 1, 31), d(2024, 2, 5)),
+            }
+        );
+        assert_eq!(error.to_string(), "segment 2024-01-31..2024-02-05 overlaps 2024-01-01..2024-01-31");
+        assert!(timeline.push(DateRange::new(d(2024, 2, 20), d(2024, 3, 1)), "early").is_err());
+        assert_eq!(timeline.len(), 3);
+    }
+
+    #[test]
+    fn fill_gaps_covers_the_span_with_a_default() {
+        let mut timeline = history();
+        timeline.fill_gaps(&DateRange::new(d(2023, 12, 15), d(2024, 4, 15)), "unknown");
+
+        let segments: Vec<_> = timeline
+            .iter()
+            .map(|(range, value)| (range.start_date(), range.end_date(), *value))
+            .collect();
+        assert_eq!(
+            segments,
+            vec![
+                (d(2023, 12, 15), d(2023, 12, 31), "unknown"),
+                (d(2024, 1, 1), d(2024, 1, 31), "probation"),
+                (d(2024, 2, 1), d(2024, 2, 29), "unknown"),
+                (d(2024, 3, 1), d(2024, 3, 10), "active"),
+                (d(2024, 3, 11), d(2024, 3, 31), "unknown"),
+                (d(2024, 4, 1), d(2024, 4, 30), "leave"),
+            ]
+        );
+        assert!(timeline.gaps().is_empty());
+    }
+
+    #[test]
+    fn join_intersects_both_segmentations() {
+        let rates = Timeline::new()
+            .with(DateRange::new(d(2024, 1, 1), d(2024, 3, 14)), 20)
+            .and_then(|t| t.with(DateRange::new(d(2024, 3, 15), d(2024, 12, 31)), 22))
+            .unwrap();
+        let cost_centers = Timeline::new()
+            .with(DateRange::new(d(2024, 2, 1), d(2024, 5, 31)), "ops")
+            .and_then(|t| t.with(DateRange::new(d(2024, 7, 1), d(2025, 1, 31)), "sales"))
+            .unwrap();
+
+        let joined = join(&rates, &cost_centers);
+        let segments: Vec<_> = joined
+            .iter()
+            .map(|(range, value)| (range.start_date(), range.end_date(), *value))
+            .collect();
+        assert_eq!(
+            segments,
+            vec![
+                (d(2024, 2, 1), d(2024, 3, 14), (20, "ops")),
+                (d(2024, 3, 15), d(2024, 5, 31), (22, "ops")),
+                (d(2024, 7, 1), d(2024, 12, 31), (22, "sales")),
+            ]
+        );
+        assert!(join(&rates, &Timeline::<u8>::new()).is_empty());
+    }
+}
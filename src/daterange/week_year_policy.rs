@@ -0,0 +1,11 @@
+/// How weekly and bi-weekly periods are matched to the same period in another year.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum WeekYearPolicy {
+    /// Move back 52 weeks per year, keeping the weekday and the period cycle. In years after
+    /// a 53-week year this lands one week earlier in the calendar than the ISO week number.
+    #[default]
+    FiftyTwoWeeks,
+    /// Use the period containing the same ISO week and weekday. Week 53 maps to week 52 in
+    /// years that don't have a 53rd week.
+    SameIsoWeek,
+}
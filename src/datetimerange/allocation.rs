@@ -0,0 +1,95 @@
+use crate::daterange::date_range::DateRange;
+use crate::datetimerange::date_time_range::DateTimeRange;
+use crate::dateutils::date_utils::duration_in_fractional_hours;
+#[cfg(feature = "bigdecimal")]
+use crate::dateutils::date_utils::duration_in_fractional_hours_bd;
+#[cfg(feature = "bigdecimal")]
+use bigdecimal::BigDecimal;
+use chrono::Duration;
+use alloc::vec::Vec;
+
+/// The portion of a range's duration that falls within a reporting period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeriodAllocation {
+    pub period: DateRange,
+    pub duration: Duration,
+    pub hours: f64,
+    #[cfg(feature = "bigdecimal")]
+    pub hours_bd: BigDecimal,
+}
+
+/// Allocate the duration of a range across reporting periods.
+///
+/// Each period covers its dates in full, from midnight on the start date up to (but not
+/// including) midnight after the end date. One allocation is returned per period, in the
+/// order given, including periods that receive no time.
+pub fn allocate_duration(range: &DateTimeRange, periods: &[DateRange]) -> Vec<PeriodAllocation> {
+    periods
+        .iter()
+        .map(|period| {
+            let (period_start, period_end) = period.as_query_bounds();
+            let start = range.start().max(period_start);
+            let end = range.end().min(period_end).max(start);
+
+            PeriodAllocation {
+                period: *period,
+                duration: end - start,
+                hours: duration_in_fractional_hours(start, end),
+                #[cfg(feature = "bigdecimal")]
+                hours_bd: duration_in_fractional_hours_bd(start, end),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::allocate_duration;
+    use crate::daterange::date_range::DateRange;
+    use crate::daterange::semi_monthly_date_range::SemiMonthlyDateRange;
+    use crate::datetimerange::date_time_range::DateTimeRange;
+    #[cfg(feature = "bigdecimal")]
+    use bigdecimal::BigDecimal;
+    use chrono::{Duration, NaiveDate, NaiveDateTime};
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+    fn dt(y: i32, m: u32, day: u32, h: u32, mi: u32) -> NaiveDateTime {
+        d(y, m, day).and_hms_opt(h, mi, 0).unwrap()
+    }
+
+    #[test]
+    fn shift_straddling_two_pay_periods_is_split_at_midnight() {
+        let first = SemiMonthlyDateRange::with_end_date(d(2023, 3, 15));
+        let second = first.next();
+        let shift = DateTimeRange::of(dt(2023, 3, 15, 20, 0), dt(2023, 3, 16, 4, 30));
+
+        let allocations = allocate_duration(&shift, &[first, second]);
+        assert_eq!(allocations.len(), 2);
+        assert_eq!(allocations[0].period, first);
+        assert_eq!(allocations[0].duration, Duration::hours(4));
+        assert!((allocations[0].hours - 4.0).abs() < 1e-12);
+        #[cfg(feature = "bigdecimal")]
+        assert_eq!(allocations[0].hours_bd, BigDecimal::from(4));
+        assert_eq!(allocations[1].duration, Duration::hours(4) + Duration::minutes(30));
+        assert!((allocations[1].hours - 4.5).abs() < 1e-12);
+        #[cfg(feature = "bigdecimal")]
+        assert_eq!(allocations[1].hours_bd, BigDecimal::from(9) / BigDecimal::from(2));
+    }
+
+    #[test]
+    fn periods_outside_the_range_receive_nothing() {
+        let shift = DateTimeRange::of(dt(2023, 3, 10, 9, 0), dt(2023, 3, 10, 17, 0));
+        let before = DateRange::new(d(2023, 3, 1), d(2023, 3, 9));
+        let during = DateRange::new(d(2023, 3, 10), d(2023, 3, 10));
+        let after = DateRange::new(d(2023, 3, 11), d(2023, 3, 31));
+
+        let allocations = allocate_duration(&shift, &[before, during, after]);
+        let durations: Vec<_> = allocations.iter().map(|a| a.duration).collect();
+        assert_eq!(durations, vec![Duration::zero(), Duration::hours(8), Duration::zero()]);
+        assert_eq!(allocations[0].hours, 0.0);
+        #[cfg(feature = "bigdecimal")]
+        assert_eq!(allocations[2].hours_bd, BigDecimal::from(0));
+    }
+}
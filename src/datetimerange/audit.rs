@@ -0,0 +1,156 @@
+use crate::datetimerange::date_time_range::DateTimeRange;
+use chrono::{Duration, NaiveTime};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A suspicious punch found by [`audit`]. Indexes are positions in the audited list.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AuditIssue {
+    /// The punch ends before it starts.
+    NegativeDuration { index: usize, duration: Duration },
+    /// The punch is longer than the allowed maximum.
+    ExceedsMaxDuration { index: usize, duration: Duration },
+    /// The punch overlaps an earlier punch with the same key.
+    Overlap { index: usize, other: usize },
+    /// The punch crosses more than one midnight.
+    CrossesMultipleMidnights { index: usize, midnights: i64 },
+}
+
+impl AuditIssue {
+    /// Get the index of the punch the issue is about.
+    pub fn index(&self) -> usize {
+        match self {
+            AuditIssue::NegativeDuration { index, .. }
+            | AuditIssue::ExceedsMaxDuration { index, .. }
+            | AuditIssue::Overlap { index, .. }
+            | AuditIssue::CrossesMultipleMidnights { index, .. } => *index,
+        }
+    }
+}
+
+impl fmt::Display for AuditIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuditIssue::NegativeDuration { index, duration } => {
+                write!(f, "punch {} has a negative duration of {} minutes", index, duration.num_minutes())
+            }
+            AuditIssue::ExceedsMaxDuration { index, duration } => {
+                write!(f, "punch {} lasts {} minutes", index, duration.num_minutes())
+            }
+            AuditIssue::Overlap { index, other } => write!(f, "punch {} overlaps punch {}", index, other),
+            AuditIssue::CrossesMultipleMidnights { index, midnights } => {
+                write!(f, "punch {} crosses {} midnights", index, midnights)
+            }
+        }
+    }
+}
+
+/// The issues found by [`audit`], ordered by the index of the punch.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AuditReport {
+    pub issues: Vec<AuditIssue>,
+}
+
+impl AuditReport {
+    /// Check if no issues were found.
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Get the issues about one punch.
+    pub fn issues_for(&self, index: usize) -> impl Iterator<Item = &AuditIssue> {
+        self.issues.iter().filter(move |issue| issue.index() == index)
+    }
+}
+
+/// Audit a list of punches, such as imported timecard entries keyed by employee. Punches are
+/// flagged when they end before they start, last longer than `max_duration`, overlap an earlier
+/// punch with the same key or cross more than one midnight. Punches that only touch don't
+/// overlap, and a punch ending at midnight doesn't cross it.
+pub fn audit<K: Ord>(punches: &[(K, DateTimeRange)], max_duration: Duration) -> AuditReport {
+    let mut issues = Vec::new();
+    for (index, (_, range)) in punches.iter().enumerate() {
+        let duration = range.duration();
+        if duration < Duration::zero() {
+            issues.push(AuditIssue::NegativeDuration { index, duration });
+            continue;
+        }
+        if duration > max_duration {
+            issues.push(AuditIssue::ExceedsMaxDuration { index, duration });
+        }
+        let mut midnights = (range.end().date() - range.start().date()).num_days();
+        if range.end().time() == NaiveTime::MIN && range.end() > range.start() {
+            midnights -= 1;
+        }
+        if midnights > 1 {
+            issues.push(AuditIssue::CrossesMultipleMidnights { index, midnights });
+        }
+    }
+
+    let mut order: Vec<usize> = (0..punches.len()).filter(|i| punches[*i].1.duration() >= Duration::zero()).collect();
+    order.sort_by(|a, b| punches[*a].0.cmp(&punches[*b].0).then_with(|| punches[*a].1.cmp(&punches[*b].1)));
+    let mut latest: Option<usize> = None;
+    for index in order {
+        if let Some(other) = latest
+            && punches[other].0 == punches[index].0
+        {
+            if punches[index].1.start() < punches[other].1.end() {
+                issues.push(AuditIssue::Overlap { index, other });
+            }
+            if punches[index].1.end() <= punches[other].1.end() {
+                continue;
+            }
+        }
+        latest = Some(index);
+    }
+
+    issues.sort_by_key(|issue| issue.index());
+    AuditReport { issues }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{audit, AuditIssue};
+    use crate::datetimerange::date_time_range::DateTimeRange;
+    use chrono::{Duration, NaiveDate, NaiveDateTime};
+
+    fn dt(day: u32, h: u32, mi: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 3, day).unwrap().and_hms_opt(h, mi, 0).unwrap()
+    }
+
+    #[test]
+    fn clean_timecards_pass() {
+        let punches = [
+            ("alice", DateTimeRange::of(dt(1, 9, 0), dt(1, 12, 0))),
+            ("alice", DateTimeRange::of(dt(1, 12, 0), dt(1, 17, 0))),
+            ("bob", DateTimeRange::of(dt(1, 10, 0), dt(1, 18, 0))),
+            ("bob", DateTimeRange::of(dt(1, 22, 0), dt(3, 0, 0))),
+        ];
+        let report = audit(&punches, Duration::hours(26));
+        assert!(report.is_clean(), "{:?}", report);
+    }
+
+    #[test]
+    fn suspicious_punches_are_reported() {
+        let punches = [
+            ("alice", DateTimeRange::of(dt(1, 9, 0), dt(1, 17, 0))),
+            ("alice", DateTimeRange::of(dt(1, 16, 0), dt(1, 18, 0))),
+            ("bob", DateTimeRange::of(dt(1, 16, 0), dt(1, 8, 0))),
+            ("bob", DateTimeRange::of(dt(1, 20, 0), dt(4, 6, 0))),
+            ("alice", DateTimeRange::of(dt(1, 10, 0), dt(1, 11, 0))),
+        ];
+        let report = audit(&punches, Duration::hours(16));
+        assert_eq!(
+            report.issues,
+            vec![
+                AuditIssue::Overlap { index: 1, other: 0 },
+                AuditIssue::NegativeDuration { index: 2, duration: Duration::hours(-8) },
+                AuditIssue::ExceedsMaxDuration { index: 3, duration: Duration::hours(58) },
+                AuditIssue::CrossesMultipleMidnights { index: 3, midnights: 3 },
+                AuditIssue::Overlap { index: 4, other: 0 },
+            ]
+        );
+        assert_eq!(report.issues_for(3).count(), 2);
+        assert_eq!(report.issues[0].to_string(), "punch 1 overlaps punch 0");
+    }
+}
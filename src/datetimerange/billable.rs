@@ -0,0 +1,85 @@
+use crate::daterange::date_range::DateRange;
+use crate::datetimerange::date_time_range::DateTimeRange;
+use crate::dateutils::date_utils::merge_overlapping;
+use chrono::NaiveDate;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Get the billable minutes for each date of a range. Overlapping usage is only billed once,
+/// usage is split at midnight into the date it falls on, and each day's seconds are rounded
+/// down to whole minutes before the optional daily cap is applied.
+pub fn billable_minutes_by_day(
+    usage: &[DateTimeRange],
+    within: &DateRange,
+    daily_cap_minutes: Option<i64>,
+) -> Vec<(NaiveDate, i64)> {
+    let mut seconds = vec![0i64; within.len()];
+    for range in merge_overlapping(usage) {
+        for (index, date) in within.iter().enumerate() {
+            if date < range.start().date() {
+                continue;
+            }
+            if date > range.end().date() {
+                break;
+            }
+            seconds[index] += range.overlap_duration(&DateTimeRange::all_day(date)).num_seconds();
+        }
+    }
+
+    within
+        .iter()
+        .zip(seconds)
+        .map(|(date, seconds)| {
+            let minutes = seconds / 60;
+            (date, daily_cap_minutes.map_or(minutes, |cap| minutes.min(cap)))
+        })
+        .collect()
+}
+
+/// Get the total billable minutes of usage within a range. See `billable_minutes_by_day`.
+pub fn billable_minutes(usage: &[DateTimeRange], within: &DateRange, daily_cap_minutes: Option<i64>) -> i64 {
+    billable_minutes_by_day(usage, within, daily_cap_minutes)
+        .iter()
+        .map(|(_, minutes)| minutes)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{billable_minutes, billable_minutes_by_day};
+    use crate::daterange::date_range::DateRange;
+    use crate::datetimerange::date_time_range::DateTimeRange;
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+    fn dt(day: u32, h: u32, mi: u32, s: u32) -> NaiveDateTime {
+        d(2024, 3, day).and_hms_opt(h, mi, s).unwrap()
+    }
+
+    #[test]
+    fn overlaps_are_billed_once_and_split_at_midnight() {
+        let usage = [
+            DateTimeRange::of(dt(1, 9, 0, 0), dt(1, 10, 0, 0)),
+            DateTimeRange::of(dt(1, 9, 30, 0), dt(1, 10, 30, 0)),
+            DateTimeRange::of(dt(1, 23, 0, 0), dt(2, 1, 0, 0)),
+            DateTimeRange::of(dt(3, 8, 0, 0), dt(3, 8, 0, 59)),
+        ];
+        let within = DateRange::new(d(2024, 3, 1), d(2024, 3, 3));
+        assert_eq!(
+            billable_minutes_by_day(&usage, &within, None),
+            vec![(d(2024, 3, 1), 150), (d(2024, 3, 2), 60), (d(2024, 3, 3), 0)]
+        );
+        assert_eq!(billable_minutes(&usage, &within, None), 210);
+        assert_eq!(billable_minutes(&usage, &within, Some(100)), 160);
+    }
+
+    #[test]
+    fn usage_outside_the_range_is_ignored() {
+        let usage = [DateTimeRange::of(dt(1, 22, 0, 0), dt(3, 2, 0, 0))];
+        let within = DateRange::new(d(2024, 3, 2), d(2024, 3, 2));
+        assert_eq!(billable_minutes(&usage, &within, None), 24 * 60);
+        assert_eq!(billable_minutes(&[], &within, Some(10)), 0);
+    }
+}
@@ -1,8 +1,14 @@
-use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
-use std::cmp::Ordering;
-use std::hash::{Hash, Hasher};
+use crate::daterange::range_error::RangeError;
+use crate::dateutils::date_utils::{add_months, stable_hash};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, SecondsFormat, Timelike};
+use alloc::format;
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "diesel", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Tsrange))]
 pub struct DateTimeRange {
     start: NaiveDateTime,
     end: NaiveDateTime,
@@ -34,6 +40,14 @@ impl DateTimeRange {
         }
     }
 
+    /// Create a range from unix timestamps in seconds, treating them as UTC. Returns None if
+    /// either timestamp is out of range.
+    pub fn from_unix_seconds(start: i64, end: i64) -> Option<Self> {
+        let start = DateTime::from_timestamp(start, 0)?.naive_utc();
+        let end = DateTime::from_timestamp(end, 0)?.naive_utc();
+        Some(Self::of(start, end))
+    }
+
     pub fn start(&self) -> NaiveDateTime {
         self.start
     }
@@ -75,6 +89,14 @@ impl DateTimeRange {
         Some(Self::of(start, end))
     }
 
+    /// Get the part of the range that has elapsed as of a date time. None if the range has not started.
+    pub fn elapsed_portion(&self, as_of: NaiveDateTime) -> Option<DateTimeRange> {
+        if as_of < self.start {
+            return None;
+        }
+        Some(Self::of(self.start, as_of.min(self.end)))
+    }
+
     pub fn contains(&self, dt: NaiveDateTime) -> bool {
         dt >= self.start && dt <= self.end
     }
@@ -82,6 +104,105 @@ impl DateTimeRange {
     pub fn contains_exclusive(&self, dt: NaiveDateTime) -> bool {
         dt > self.start && dt < self.end
     }
+
+    /// Check if both bounds are within `tolerance` of the other range's bounds.
+    pub fn eq_within(&self, other: &DateTimeRange, tolerance: Duration) -> bool {
+        (self.start - other.start).abs() <= tolerance && (self.end - other.end).abs() <= tolerance
+    }
+
+    /// Check if the ranges overlap, treating ranges separated by a gap of at most `tolerance` as overlapping.
+    pub fn overlaps_within(&self, other: &DateTimeRange, tolerance: Duration) -> bool {
+        self.start <= other.end + tolerance && self.end + tolerance >= other.start
+    }
+
+    /// Get the bounds for a half-open `>= start AND < end` query. The end is excluded so that
+    /// adjacent ranges never match the same row.
+    pub fn as_query_bounds(&self) -> (NaiveDateTime, NaiveDateTime) {
+        (self.start, self.end)
+    }
+
+    /// Get the start and end as unix timestamps in milliseconds, treating them as UTC.
+    pub fn to_unix_millis(&self) -> (i64, i64) {
+        (self.start.and_utc().timestamp_millis(), self.end.and_utc().timestamp_millis())
+    }
+
+    /// Check if a unix timestamp in seconds falls within the range (inclusive), treating the range as UTC.
+    pub fn contains_unix(&self, timestamp: i64) -> bool {
+        DateTime::from_timestamp(timestamp, 0).is_some_and(|dt| self.contains(dt.naive_utc()))
+    }
+
+    /// Create a range from RFC 3339 timestamps, converting any offset to UTC. Fails with
+    /// `InvalidInterval` if either timestamp is malformed or the end is before the start.
+    pub fn parse_rfc3339(start: &str, end: &str) -> Result<Self, RangeError> {
+        let invalid = || RangeError::InvalidInterval(format!("{}/{}", start, end));
+        let start_time = DateTime::parse_from_rfc3339(start).map_err(|_| invalid())?.naive_utc();
+        let end_time = DateTime::parse_from_rfc3339(end).map_err(|_| invalid())?.naive_utc();
+        if end_time < start_time {
+            return Err(invalid());
+        }
+        Ok(Self::of(start_time, end_time))
+    }
+
+    /// Format the start and end as RFC 3339 timestamps, treating them as UTC.
+    pub fn to_rfc3339_pair(&self) -> (String, String) {
+        let format = |dt: NaiveDateTime| dt.and_utc().to_rfc3339_opts(SecondsFormat::AutoSi, true);
+        (format(self.start), format(self.end))
+    }
+
+    /// Get a copy of the range with the seconds and fractional seconds of both bounds set to zero.
+    pub fn truncate_to_minutes(&self) -> DateTimeRange {
+        let truncate = |dt: NaiveDateTime| dt.with_second(0).unwrap().with_nanosecond(0).unwrap();
+        Self::of(truncate(self.start), truncate(self.end))
+    }
+
+    /// Get a copy of the range with the fractional seconds of both bounds set to zero.
+    pub fn truncate_to_seconds(&self) -> DateTimeRange {
+        let truncate = |dt: NaiveDateTime| dt.with_nanosecond(0).unwrap();
+        Self::of(truncate(self.start), truncate(self.end))
+    }
+
+    /// Get a copy of the range with the end moved by a number of calendar months, keeping its time
+    /// of day. Days past the end of a shorter month are clamped to its last day.
+    pub fn extend_by_months(&self, months: i32) -> DateTimeRange {
+        Self::of(self.start, add_months_to_datetime(self.end, months))
+    }
+
+    /// Get a copy of the range with both bounds moved by a number of calendar months, keeping
+    /// their times of day. Days past the end of a shorter month are clamped to its last day.
+    pub fn shift_by_months(&self, months: i32) -> DateTimeRange {
+        Self::of(add_months_to_datetime(self.start, months), add_months_to_datetime(self.end, months))
+    }
+
+    /// Get a deterministic 64-bit id for the start and end, suitable for idempotency and cache
+    /// keys shared between processes. Ranges with the same bounds have the same id.
+    pub fn stable_id(&self) -> u64 {
+        let mut bytes = [0u8; 24];
+        for (i, dt) in [self.start, self.end].iter().enumerate() {
+            let utc = dt.and_utc();
+            bytes[i * 12..i * 12 + 8].copy_from_slice(&utc.timestamp().to_le_bytes());
+            bytes[i * 12 + 8..i * 12 + 12].copy_from_slice(&utc.timestamp_subsec_nanos().to_le_bytes());
+        }
+        stable_hash(&bytes)
+    }
+
+    /// Check if both ranges start and end on the same dates, ignoring the time of day.
+    pub fn same_dates_as(&self, other: &DateTimeRange) -> bool {
+        self.start.date() == other.start.date() && self.end.date() == other.end.date()
+    }
+
+    /// Compare ranges by end, then by start. Usable with `sort_by`.
+    pub fn cmp_by_end(a: &DateTimeRange, b: &DateTimeRange) -> Ordering {
+        a.end.cmp(&b.end).then_with(|| a.start.cmp(&b.start))
+    }
+
+    /// Compare ranges by duration, then by start. Usable with `sort_by`.
+    pub fn cmp_by_duration(a: &DateTimeRange, b: &DateTimeRange) -> Ordering {
+        a.duration().cmp(&b.duration()).then_with(|| a.start.cmp(&b.start))
+    }
+}
+
+fn add_months_to_datetime(dt: NaiveDateTime, months: i32) -> NaiveDateTime {
+    add_months(dt.date(), months).and_time(dt.time())
 }
 
 impl PartialEq for DateTimeRange {
@@ -117,6 +238,7 @@ impl Ord for DateTimeRange {
 #[cfg(test)]
 mod tests {
     use super::DateTimeRange;
+    use crate::daterange::range_error::RangeError;
     use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
     use std::collections::hash_map::DefaultHasher;
     use std::hash::{Hash, Hasher};
@@ -240,4 +362,149 @@ mod tests {
         c.hash(&mut hc);
         assert_eq!(ha.finish(), hc.finish());
     }
+
+    #[test]
+    fn as_query_bounds_of_adjacent_ranges_do_not_share_rows() {
+        let a = DateTimeRange::of(dt(2023, 1, 1, 0, 0, 0), dt(2023, 1, 2, 0, 0, 0));
+        let b = DateTimeRange::of(dt(2023, 1, 2, 0, 0, 0), dt(2023, 1, 3, 0, 0, 0));
+        let midnight = dt(2023, 1, 2, 0, 0, 0);
+        let (a_from, a_to) = a.as_query_bounds();
+        let (b_from, b_to) = b.as_query_bounds();
+        assert!(!(midnight >= a_from && midnight < a_to));
+        assert!(midnight >= b_from && midnight < b_to);
+    }
+
+    #[test]
+    fn unix_timestamp_conversions_round_trip() {
+        // 2023-11-14 22:13:20 UTC
+        let r = DateTimeRange::from_unix_seconds(1_700_000_000, 1_700_003_600).unwrap();
+        assert_eq!(r.start(), dt(2023, 11, 14, 22, 13, 20));
+        assert_eq!(r.duration(), Duration::hours(1));
+        assert_eq!(r.to_unix_millis(), (1_700_000_000_000, 1_700_003_600_000));
+
+        assert!(r.contains_unix(1_700_000_000));
+        assert!(r.contains_unix(1_700_003_600));
+        assert!(!r.contains_unix(1_700_003_601));
+        assert!(DateTimeRange::from_unix_seconds(i64::MAX, 0).is_none());
+    }
+
+    #[test]
+    fn rfc3339_round_trips_and_normalizes_offsets() {
+        let range = DateTimeRange::parse_rfc3339("2024-03-10T09:00:00+02:00", "2024-03-10T17:30:00.250Z").unwrap();
+        assert_eq!(range.start(), dt(2024, 3, 10, 7, 0, 0));
+        assert_eq!(range.end(), dt(2024, 3, 10, 17, 30, 0) + Duration::milliseconds(250));
+
+        let (start, end) = range.to_rfc3339_pair();
+        assert_eq!((start.as_str(), end.as_str()), ("2024-03-10T07:00:00Z", "2024-03-10T17:30:00.250Z"));
+        assert_eq!(DateTimeRange::parse_rfc3339(&start, &end).unwrap(), range);
+
+        assert!(DateTimeRange::parse_rfc3339("2024-03-10 09:00", "2024-03-10T17:00:00Z").is_err());
+    }
+
+    #[test]
+    fn rfc3339_rejects_an_end_before_the_start() {
+        let (start, end) = ("2024-03-10T17:00:00Z", "2024-03-10T18:00:00+02:00");
+        assert_eq!(
+            DateTimeRange::parse_rfc3339(start, end),
+            Err(RangeError::InvalidInterval(format!("{}/{}", start, end)))
+        );
+        assert!(DateTimeRange::parse_rfc3339(start, start).is_ok());
+    }
+
+    #[test]
+    fn month_arithmetic_clamps_and_keeps_time_of_day() {
+        let range = DateTimeRange::of(dt(2024, 1, 31, 9, 0, 0), dt(2024, 3, 31, 17, 30, 0));
+
+        let extended = range.extend_by_months(1);
+        assert_eq!((extended.start(), extended.end()), (dt(2024, 1, 31, 9, 0, 0), dt(2024, 4, 30, 17, 30, 0)));
+
+        let shifted = range.shift_by_months(1);
+        assert_eq!((shifted.start(), shifted.end()), (dt(2024, 2, 29, 9, 0, 0), dt(2024, 4, 30, 17, 30, 0)));
+
+        let back = range.shift_by_months(-13);
+        assert_eq!((back.start(), back.end()), (dt(2022, 12, 31, 9, 0, 0), dt(2023, 2, 28, 17, 30, 0)));
+    }
+
+    #[test]
+    fn eq_within_allows_small_skew_on_either_bound() {
+        let a = DateTimeRange::of(dt(2023, 4, 1, 9, 0, 0), dt(2023, 4, 1, 17, 0, 0));
+        let b = DateTimeRange::of(dt(2023, 4, 1, 8, 59, 58), dt(2023, 4, 1, 17, 0, 2));
+        assert!(a.eq_within(&b, Duration::seconds(2)));
+        assert!(b.eq_within(&a, Duration::seconds(2)));
+        assert!(!a.eq_within(&b, Duration::seconds(1)));
+        assert!(a.eq_within(&a, Duration::zero()));
+    }
+
+    #[test]
+    fn overlaps_within_bridges_small_gaps() {
+        let a = DateTimeRange::of(dt(2023, 4, 1, 9, 0, 0), dt(2023, 4, 1, 10, 0, 0));
+        let b = DateTimeRange::of(dt(2023, 4, 1, 10, 0, 30), dt(2023, 4, 1, 11, 0, 0));
+        assert!(!a.overlaps(&b));
+        assert!(a.overlaps_within(&b, Duration::seconds(30)));
+        assert!(b.overlaps_within(&a, Duration::seconds(30)));
+        assert!(!a.overlaps_within(&b, Duration::seconds(29)));
+    }
+
+    #[test]
+    fn stable_id_is_deterministic_and_sensitive_to_bounds() {
+        let r = DateTimeRange::of(dt(2024, 1, 1, 9, 0, 0), dt(2024, 1, 1, 17, 0, 0));
+        assert_eq!(r.stable_id(), 0x352c_ec80_c63c_d08d);
+        assert_eq!(r.stable_id(), DateTimeRange::of(r.start(), r.end()).stable_id());
+
+        let shifted = DateTimeRange::of(dt(2024, 1, 1, 9, 0, 1), dt(2024, 1, 1, 17, 0, 0));
+        assert_ne!(r.stable_id(), shifted.stable_id());
+        let fraction = DateTimeRange::of(r.start() + Duration::nanoseconds(1), r.end());
+        assert_ne!(r.stable_id(), fraction.stable_id());
+    }
+
+    #[test]
+    fn truncation_zeroes_sub_unit_components() {
+        let start = d(2023, 4, 1).and_hms_milli_opt(9, 15, 42, 250).unwrap();
+        let end = d(2023, 4, 1).and_hms_milli_opt(17, 0, 5, 999).unwrap();
+        let r = DateTimeRange::of(start, end);
+
+        let minutes = r.truncate_to_minutes();
+        assert_eq!(minutes.start(), dt(2023, 4, 1, 9, 15, 0));
+        assert_eq!(minutes.end(), dt(2023, 4, 1, 17, 0, 0));
+
+        let seconds = r.truncate_to_seconds();
+        assert_eq!(seconds.start(), dt(2023, 4, 1, 9, 15, 42));
+        assert_eq!(seconds.end(), dt(2023, 4, 1, 17, 0, 5));
+        assert_eq!(seconds.truncate_to_seconds(), seconds);
+    }
+
+    #[test]
+    fn same_dates_as_ignores_time_of_day() {
+        let a = DateTimeRange::of(dt(2023, 1, 1, 9, 0, 0), dt(2023, 1, 2, 17, 0, 0));
+        let b = DateTimeRange::of(dt(2023, 1, 1, 0, 0, 0), dt(2023, 1, 2, 23, 59, 59));
+        let c = DateTimeRange::of(dt(2023, 1, 1, 9, 0, 0), dt(2023, 1, 3, 0, 0, 0));
+        assert!(a.same_dates_as(&b));
+        assert!(!a.same_dates_as(&c));
+    }
+
+    #[test]
+    fn elapsed_portion_stops_at_as_of() {
+        let shift = DateTimeRange::of(dt(2023, 1, 1, 9, 0, 0), dt(2023, 1, 1, 17, 0, 0));
+        assert_eq!(
+            shift.elapsed_portion(dt(2023, 1, 1, 12, 30, 0)),
+            Some(DateTimeRange::of(dt(2023, 1, 1, 9, 0, 0), dt(2023, 1, 1, 12, 30, 0)))
+        );
+        assert_eq!(shift.elapsed_portion(dt(2023, 1, 2, 0, 0, 0)), Some(shift.clone()));
+        assert_eq!(shift.elapsed_portion(dt(2023, 1, 1, 8, 59, 59)), None);
+    }
+
+    #[test]
+    fn comparators_sort_by_end_and_by_duration() {
+        let long = DateTimeRange::of(dt(2023, 1, 1, 6, 0, 0), dt(2023, 1, 1, 18, 0, 0));
+        let short_late = DateTimeRange::of(dt(2023, 1, 1, 19, 0, 0), dt(2023, 1, 1, 20, 0, 0));
+        let short_early = DateTimeRange::of(dt(2023, 1, 1, 7, 0, 0), dt(2023, 1, 1, 8, 0, 0));
+
+        let mut by_end = vec![short_late.clone(), long.clone(), short_early.clone()];
+        by_end.sort_by(DateTimeRange::cmp_by_end);
+        assert_eq!(by_end, vec![short_early.clone(), long.clone(), short_late.clone()]);
+
+        let mut by_duration = vec![long.clone(), short_late.clone(), short_early.clone()];
+        by_duration.sort_by(DateTimeRange::cmp_by_duration);
+        assert_eq!(by_duration, vec![short_early, short_late, long]);
+    }
 }
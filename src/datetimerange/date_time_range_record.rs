@@ -0,0 +1,84 @@
+use crate::datetimerange::date_time_range::DateTimeRange;
+use chrono::NaiveDateTime;
+use alloc::string::{String, ToString};
+
+const ISO_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+/// A flat, plain-value view of a DateTimeRange for CSV and columnar exports.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DateTimeRangeRecord {
+    /// The start as `YYYY-MM-DDTHH:MM:SS`, with fractional seconds when present.
+    pub start: String,
+    /// The end as `YYYY-MM-DDTHH:MM:SS`, with fractional seconds when present.
+    pub end: String,
+    /// The start as milliseconds since the unix epoch, treating it as UTC.
+    pub start_epoch_millis: i64,
+    /// The end as milliseconds since the unix epoch, treating it as UTC.
+    pub end_epoch_millis: i64,
+    /// The length of the range in milliseconds.
+    pub duration_millis: i64,
+}
+
+impl DateTimeRange {
+    /// Get a flat record of the range for exporting.
+    pub fn to_record(&self) -> DateTimeRangeRecord {
+        let (start_epoch_millis, end_epoch_millis) = self.to_unix_millis();
+        DateTimeRangeRecord {
+            start: self.start().format(ISO_FORMAT).to_string(),
+            end: self.end().format(ISO_FORMAT).to_string(),
+            start_epoch_millis,
+            end_epoch_millis,
+            duration_millis: self.duration().num_milliseconds(),
+        }
+    }
+
+    /// Rebuild a range from the ISO datetimes of a record, or None if they can't be parsed or
+    /// the end is before the start. The epoch and duration columns are derived values and are
+    /// ignored.
+    pub fn from_record(record: &DateTimeRangeRecord) -> Option<DateTimeRange> {
+        let start = NaiveDateTime::parse_from_str(&record.start, ISO_FORMAT).ok()?;
+        let end = NaiveDateTime::parse_from_str(&record.end, ISO_FORMAT).ok()?;
+        (start <= end).then(|| DateTimeRange::of(start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DateTimeRangeRecord;
+    use crate::datetimerange::date_time_range::DateTimeRange;
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    fn dt(y: i32, m: u32, day: u32, h: u32, mi: u32, s: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap().and_hms_opt(h, mi, s).unwrap()
+    }
+
+    #[test]
+    fn to_record_flattens_bounds() {
+        let record = DateTimeRange::of(dt(2024, 3, 1, 9, 0, 0), dt(2024, 3, 1, 17, 30, 0)).to_record();
+        assert_eq!(
+            record,
+            DateTimeRangeRecord {
+                start: "2024-03-01T09:00:00".to_string(),
+                end: "2024-03-01T17:30:00".to_string(),
+                start_epoch_millis: 1_709_283_600_000,
+                end_epoch_millis: 1_709_314_200_000,
+                duration_millis: 30_600_000,
+            }
+        );
+    }
+
+    #[test]
+    fn from_record_round_trips_fractional_seconds() {
+        let start = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap().and_hms_milli_opt(9, 0, 0, 250).unwrap();
+        let range = DateTimeRange::of(start, dt(2024, 3, 2, 0, 0, 0));
+        let record = range.to_record();
+        assert_eq!(record.start, "2024-03-01T09:00:00.250");
+        assert_eq!(DateTimeRange::from_record(&record), Some(range));
+
+        let reversed = DateTimeRangeRecord { start: record.end.clone(), end: record.start.clone(), ..record.clone() };
+        assert_eq!(DateTimeRange::from_record(&reversed), None);
+
+        let garbled = DateTimeRangeRecord { end: "not a date".to_string(), ..record };
+        assert_eq!(DateTimeRange::from_record(&garbled), None);
+    }
+}
@@ -0,0 +1,227 @@
+use crate::datetimerange::date_time_range::DateTimeRange;
+use crate::dateutils::date_utils::merge_overlapping;
+use chrono::{Duration, NaiveDateTime};
+use alloc::vec::Vec;
+use core::cmp::{max, min};
+
+/// A change to a [`DateTimeRangeSet`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RangeChange {
+    Insert(DateTimeRange),
+    Remove(DateTimeRange),
+}
+
+/// The parts of the timeline whose coverage flipped when a change was applied.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Delta {
+    /// Ranges that were not in the set before the change and are now.
+    pub covered: Vec<DateTimeRange>,
+    /// Ranges that were in the set before the change and no longer are.
+    pub uncovered: Vec<DateTimeRange>,
+}
+
+impl Delta {
+    /// Check if the change left the set as it was.
+    pub fn is_empty(&self) -> bool {
+        self.covered.is_empty() && self.uncovered.is_empty()
+    }
+}
+
+/// A set of instants stored as sorted, disjoint ranges. Each range covers its start up to but not
+/// including its end, so ranges that touch are merged and empty ranges are dropped.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DateTimeRangeSet {
+    ranges: Vec<DateTimeRange>,
+}
+
+impl DateTimeRangeSet {
+    pub fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    /// Create a set covering every instant of some ranges.
+    pub fn from_ranges(ranges: &[DateTimeRange]) -> Self {
+        Self { ranges: merge_overlapping(ranges) }
+    }
+
+    /// Get the disjoint ranges of the set, sorted by start.
+    pub fn ranges(&self) -> &[DateTimeRange] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Check if an instant is in the set.
+    pub fn contains(&self, dt: NaiveDateTime) -> bool {
+        let index = self.ranges.partition_point(|range| range.start() <= dt);
+        index > 0 && dt < self.ranges[index - 1].end()
+    }
+
+    /// Get the total time covered by the set.
+    pub fn total_duration(&self) -> Duration {
+        self.ranges.iter().fold(Duration::zero(), |total, range| total + range.duration())
+    }
+
+    /// Add a range to the set.
+    pub fn insert(&mut self, range: DateTimeRange) {
+        *self = self.union(&Self::from_ranges(&[range]));
+    }
+
+    /// Remove a range from the set.
+    pub fn remove(&mut self, range: DateTimeRange) {
+        *self = self.difference(&Self::from_ranges(&[range]));
+    }
+
+    /// Apply a change, returning exactly which parts of the timeline became covered or
+    /// uncovered by it.
+    pub fn apply(&mut self, change: RangeChange) -> Delta {
+        match change {
+            RangeChange::Insert(range) => {
+                let covered = Self::from_ranges(&[range]).difference(self);
+                *self = self.union(&covered);
+                Delta { covered: covered.ranges, uncovered: Vec::new() }
+            }
+            RangeChange::Remove(range) => {
+                let uncovered = self.intersection(&Self::from_ranges(&[range]));
+                *self = self.difference(&uncovered);
+                Delta { covered: Vec::new(), uncovered: uncovered.ranges }
+            }
+        }
+    }
+
+    /// Get the instants in either set.
+    pub fn union(&self, other: &DateTimeRangeSet) -> DateTimeRangeSet {
+        self.combine(other, |a, b| a || b)
+    }
+
+    /// Get the instants in both sets.
+    pub fn intersection(&self, other: &DateTimeRangeSet) -> DateTimeRangeSet {
+        self.combine(other, |a, b| a && b)
+    }
+
+    /// Get the instants in this set but not the other.
+    pub fn difference(&self, other: &DateTimeRangeSet) -> DateTimeRangeSet {
+        self.combine(other, |a, b| a && !b)
+    }
+
+    /// Get the instants of a window that are not in the set.
+    pub fn complement_within(&self, window: &DateTimeRange) -> DateTimeRangeSet {
+        Self::from_ranges(core::slice::from_ref(window)).difference(self)
+    }
+
+    /// Get the part of the set within a window.
+    pub fn clip_to(&self, window: &DateTimeRange) -> DateTimeRangeSet {
+        let ranges = self
+            .ranges
+            .iter()
+            .filter(|range| range.start() < window.end() && range.end() > window.start())
+            .map(|range| DateTimeRange::of(max(range.start(), window.start()), min(range.end(), window.end())))
+            .collect();
+        DateTimeRangeSet { ranges }
+    }
+
+    /// Sweep the boundaries of both sets in order, keeping the spans where `keep` holds for
+    /// whether each set covers the span.
+    fn combine(&self, other: &DateTimeRangeSet, keep: impl Fn(bool, bool) -> bool) -> DateTimeRangeSet {
+        let mut boundaries: Vec<NaiveDateTime> = Vec::with_capacity(2 * (self.ranges.len() + other.ranges.len()));
+        for range in self.ranges.iter().chain(&other.ranges) {
+            boundaries.push(range.start());
+            boundaries.push(range.end());
+        }
+        boundaries.sort();
+        boundaries.dedup();
+
+        let (mut i, mut j) = (0, 0);
+        let mut ranges: Vec<DateTimeRange> = Vec::new();
+        for span in boundaries.windows(2) {
+            let (start, end) = (span[0], span[1]);
+            while i < self.ranges.len() && self.ranges[i].end() <= start {
+                i += 1;
+            }
+            while j < other.ranges.len() && other.ranges[j].end() <= start {
+                j += 1;
+            }
+            let in_self = i < self.ranges.len() && self.ranges[i].start() <= start;
+            let in_other = j < other.ranges.len() && other.ranges[j].start() <= start;
+            if !keep(in_self, in_other) {
+                continue;
+            }
+            match ranges.last_mut() {
+                Some(last) if last.end() == start => *last = DateTimeRange::of(last.start(), end),
+                _ => ranges.push(DateTimeRange::of(start, end)),
+            }
+        }
+        DateTimeRangeSet { ranges }
+    }
+}
+
+impl FromIterator<DateTimeRange> for DateTimeRangeSet {
+    fn from_iter<I: IntoIterator<Item = DateTimeRange>>(iter: I) -> Self {
+        let ranges: Vec<DateTimeRange> = iter.into_iter().collect();
+        Self::from_ranges(&ranges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DateTimeRangeSet, Delta, RangeChange};
+    use crate::datetimerange::date_time_range::DateTimeRange;
+    use chrono::{Duration, NaiveDate, NaiveDateTime};
+
+    fn dt(h: u32, mi: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 3, 1).unwrap().and_hms_opt(h, mi, 0).unwrap()
+    }
+    fn r(start: u32, end: u32) -> DateTimeRange {
+        DateTimeRange::of(dt(start, 0), dt(end, 0))
+    }
+    fn set(ranges: &[DateTimeRange]) -> DateTimeRangeSet {
+        DateTimeRangeSet::from_ranges(ranges)
+    }
+
+    #[test]
+    fn ranges_are_normalized() {
+        let s: DateTimeRangeSet = [r(9, 11), r(13, 14), r(10, 12), r(12, 13), r(15, 15)].into_iter().collect();
+        assert_eq!(s.ranges(), &[r(9, 14)]);
+        assert_eq!(s.total_duration(), Duration::hours(5));
+        assert!(s.contains(dt(9, 0)));
+        assert!(!s.contains(dt(14, 0)));
+        assert!(DateTimeRangeSet::new().is_empty());
+    }
+
+    #[test]
+    fn boolean_operations() {
+        let a = set(&[r(8, 12), r(14, 18)]);
+        let b = set(&[r(10, 15), r(17, 20)]);
+        assert_eq!(a.union(&b).ranges(), &[r(8, 20)]);
+        assert_eq!(a.intersection(&b).ranges(), &[r(10, 12), r(14, 15), r(17, 18)]);
+        assert_eq!(a.difference(&b).ranges(), &[r(8, 10), r(15, 17)]);
+        assert_eq!(b.difference(&a).ranges(), &[r(12, 14), r(18, 20)]);
+        assert_eq!(a.intersection(&set(&[r(12, 14)])).ranges(), &[]);
+        assert_eq!(a.complement_within(&r(6, 22)).ranges(), &[r(6, 8), r(12, 14), r(18, 22)]);
+        assert_eq!(a.clip_to(&r(11, 15)).ranges(), &[r(11, 12), r(14, 15)]);
+
+        let mut c = a.clone();
+        c.insert(r(12, 14));
+        assert_eq!(c.ranges(), &[r(8, 18)]);
+    }
+
+    #[test]
+    fn apply_reports_flipped_coverage() {
+        let mut s = set(&[r(8, 10), r(12, 14)]);
+        let delta = s.apply(RangeChange::Insert(r(9, 13)));
+        assert_eq!(delta, Delta { covered: vec![r(10, 12)], uncovered: vec![] });
+        assert_eq!(s.ranges(), &[r(8, 14)]);
+
+        let delta = s.apply(RangeChange::Remove(r(6, 9)));
+        assert_eq!(delta.uncovered, vec![r(8, 9)]);
+        assert_eq!(s.ranges(), &[r(9, 14)]);
+
+        assert!(s.apply(RangeChange::Insert(r(10, 11))).is_empty());
+        assert!(s.apply(RangeChange::Remove(r(15, 16))).is_empty());
+
+        s.remove(r(10, 11));
+        assert_eq!(s.ranges(), &[r(9, 10), r(11, 14)]);
+    }
+}
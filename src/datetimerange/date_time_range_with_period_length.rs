@@ -1,9 +1,9 @@
 use crate::datetimerange::date_time_range::DateTimeRange;
-use chrono::{Duration, NaiveDateTime, Timelike};
-use std::hash::{Hash, Hasher};
-use std::iter::Iterator;
+use crate::timeutils::time_utils::{minute_of_day, time_to_period_index};
+use chrono::{Duration, NaiveDateTime};
+use core::hash::{Hash, Hasher};
+use core::iter::Iterator;
 
-const MINUTES_PER_HOUR: i32 = 60;
 const MINUTES_PER_DAY: i32 = 1440;
 
 #[derive(Debug, Clone)]
@@ -25,15 +25,14 @@ impl DateTimeRangeWithPeriodLength {
     }
 
     pub fn start_index(&self) -> i32 {
-        let start = self.date_time_range.start();
-        (start.hour() as i32 * MINUTES_PER_HOUR + start.minute() as i32) / self.period_length_minutes
+        time_to_period_index(self.date_time_range.start().time(), self.period_length_minutes)
     }
 
     pub fn end_index(&self) -> i32 {
         let start = self.date_time_range.start();
         let end = self.date_time_range.end();
 
-        let mut end_index = end.hour() as i32 * MINUTES_PER_HOUR + end.minute() as i32;
+        let mut end_index = minute_of_day(end.time());
 
         if end.date() > start.date() {
             end_index += MINUTES_PER_DAY;
@@ -57,8 +56,26 @@ impl DateTimeRangeWithPeriodLength {
     pub fn number_of_periods_in_shift(&self) -> i32 {
         (self.date_time_range.duration().num_minutes() as i32) / self.period_length_minutes
     }
+
+    /// Get a key that compares and hashes both the range and the period length, for maps that
+    /// hold ranges on different grids.
+    pub fn grid_key(&self) -> GridKey {
+        GridKey {
+            date_time_range: self.date_time_range.clone(),
+            period_length_minutes: self.period_length_minutes,
+        }
+    }
 }
 
+/// A range together with its period length, compared and hashed on both.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GridKey {
+    pub date_time_range: DateTimeRange,
+    pub period_length_minutes: i32,
+}
+
+/// Equality only considers the range, so the same range on a 15-minute and a 30-minute grid are
+/// equal. Use `grid_key` to tell them apart.
 impl PartialEq for DateTimeRangeWithPeriodLength {
     fn eq(&self, other: &Self) -> bool {
         self.date_time_range == other.date_time_range
@@ -66,6 +83,7 @@ impl PartialEq for DateTimeRangeWithPeriodLength {
 }
 impl Eq for DateTimeRangeWithPeriodLength {}
 
+/// Hashing only considers the range, matching equality.
 impl Hash for DateTimeRangeWithPeriodLength {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.date_time_range.hash(state);
@@ -224,4 +242,20 @@ mod tests {
         b.hash(&mut hb);
         assert_eq!(ha.finish(), hb.finish());
     }
+
+    #[test]
+    fn grid_key_distinguishes_period_length() {
+        let start = dt(2023, 7, 7, 7, 0, 0);
+        let end = dt(2023, 7, 7, 9, 0, 0);
+        let a = R::of_datetimes(start, end, 15);
+        let b = R::of_datetimes(start, end, 30);
+        assert_ne!(a.grid_key(), b.grid_key());
+        assert_eq!(a.grid_key(), R::of_datetimes(start, end, 15).grid_key());
+
+        let mut grids = std::collections::HashMap::new();
+        grids.insert(a.grid_key(), "quarter hours");
+        grids.insert(b.grid_key(), "half hours");
+        assert_eq!(grids.len(), 2);
+        assert_eq!(grids[&b.grid_key()], "half hours");
+    }
 }
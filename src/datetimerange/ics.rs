@@ -0,0 +1,450 @@
+use crate::daterange::date_range::DateRange;
+use crate::datetimerange::date_time_range::DateTimeRange;
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+/// Errors returned when reading an iCalendar stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IcsError {
+    /// A content line had no `:` separating the property from its value.
+    InvalidLine(String),
+    /// An event had no `DTSTART`.
+    MissingStart,
+    /// A date or date time value could not be parsed.
+    InvalidDateTime(String),
+    /// A `DURATION` value could not be parsed.
+    InvalidDuration(String),
+    /// An `RRULE` used a part this parser does not expand.
+    UnsupportedRule(String),
+    /// The stream ended inside an event.
+    UnterminatedEvent,
+}
+
+impl fmt::Display for IcsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IcsError::InvalidLine(line) => write!(f, "invalid content line: {}", line),
+            IcsError::MissingStart => write!(f, "event has no DTSTART"),
+            IcsError::InvalidDateTime(value) => write!(f, "invalid date time: {}", value),
+            IcsError::InvalidDuration(value) => write!(f, "invalid duration: {}", value),
+            IcsError::UnsupportedRule(rule) => write!(f, "unsupported recurrence rule: {}", rule),
+            IcsError::UnterminatedEvent => write!(f, "event is missing END:VEVENT"),
+        }
+    }
+}
+
+impl Error for IcsError {}
+
+/// Read the events of an iCalendar stream as ranges that overlap `within`, in order.
+///
+/// Recurring events are expanded over `within` for `DAILY`, `WEEKLY` (optionally with `BYDAY`),
+/// `MONTHLY` and `YEARLY` rules with `INTERVAL`, `COUNT` and `UNTIL`, skipping `EXDATE`s.
+/// Monthly and yearly occurrences on a day the month lacks are skipped. Cancelled events are left
+/// out. Times are read as written: `TZID` is ignored and UTC values stay in UTC. All-day events
+/// cover whole days, ending at midnight after their last day.
+pub fn parse_ics(input: &str, within: &DateRange) -> Result<Vec<DateTimeRange>, IcsError> {
+    let (window_start, window_end) = within.as_query_bounds();
+    let mut ranges = Vec::new();
+    let mut event: Option<Event> = None;
+    let mut nested = 0;
+
+    for line in unfold(input) {
+        let (name, params, value) = split_line(&line)?;
+        match (name.as_str(), event.as_mut()) {
+            ("BEGIN", None) if value.eq_ignore_ascii_case("VEVENT") => event = Some(Event::default()),
+            ("BEGIN", Some(_)) => nested += 1,
+            ("END", Some(_)) if nested > 0 => nested -= 1,
+            ("END", Some(_)) if value.eq_ignore_ascii_case("VEVENT") => {
+                if let Some(event) = event.take() {
+                    event.expand(window_start, window_end, &mut ranges)?;
+                }
+            }
+            (_, Some(_)) if nested > 0 => {}
+            (_, Some(event)) => event.set(&name, params, value)?,
+            _ => {}
+        }
+    }
+    if event.is_some() {
+        return Err(IcsError::UnterminatedEvent);
+    }
+
+    ranges.sort();
+    Ok(ranges)
+}
+
+#[derive(Default)]
+struct Event {
+    start: Option<(NaiveDateTime, bool)>,
+    end: Option<NaiveDateTime>,
+    duration: Option<Duration>,
+    rule: Option<String>,
+    exdates: Vec<NaiveDateTime>,
+    cancelled: bool,
+}
+
+impl Event {
+    fn set(&mut self, name: &str, params: &str, value: &str) -> Result<(), IcsError> {
+        let params = params.to_ascii_uppercase();
+        let date_only = params.contains("VALUE=DATE") && !params.contains("VALUE=DATE-TIME");
+        match name {
+            "DTSTART" => self.start = Some(parse_date_time(value, date_only)?),
+            "DTEND" => self.end = Some(parse_date_time(value, date_only)?.0),
+            "DURATION" => self.duration = Some(parse_duration(value)?),
+            "RRULE" => self.rule = Some(value.to_string()),
+            "EXDATE" => {
+                for exdate in value.split(',') {
+                    self.exdates.push(parse_date_time(exdate, date_only)?.0);
+                }
+            }
+            "STATUS" => self.cancelled = value.eq_ignore_ascii_case("CANCELLED"),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn expand(
+        &self,
+        window_start: NaiveDateTime,
+        window_end: NaiveDateTime,
+        ranges: &mut Vec<DateTimeRange>,
+    ) -> Result<(), IcsError> {
+        let (start, all_day) = self.start.ok_or(IcsError::MissingStart)?;
+        if self.cancelled {
+            return Ok(());
+        }
+        let length = match (self.end, self.duration) {
+            (Some(end), _) => end - start,
+            (None, Some(duration)) => duration,
+            (None, None) if all_day => Duration::days(1),
+            (None, None) => Duration::zero(),
+        };
+        let mut push = |occurrence: NaiveDateTime| {
+            let end = occurrence
+                .checked_add_signed(length)
+                .ok_or_else(|| IcsError::InvalidDuration(length.to_string()))?;
+            let instant_within = end == occurrence && occurrence >= window_start;
+            let overlaps = occurrence < window_end && (end > window_start || instant_within);
+            if overlaps && !self.exdates.contains(&occurrence) {
+                ranges.push(DateTimeRange::of(occurrence, end));
+            }
+            Ok(())
+        };
+
+        let Some(rule) = &self.rule else {
+            return push(start);
+        };
+        let rule = Rule::parse(rule)?;
+        let mut generated = 0;
+        for period in 0.. {
+            let Some((floor, dates)) = rule.period(start.date(), period) else {
+                break;
+            };
+            if floor.and_time(NaiveTime::MIN) >= window_end || rule.until.is_some_and(|until| floor > until.date()) {
+                break;
+            }
+            for date in dates {
+                let occurrence = date.and_time(start.time());
+                if occurrence < start {
+                    continue;
+                }
+                let past_until = rule.until.is_some_and(|until| occurrence > until);
+                if past_until || rule.count.is_some_and(|count| generated >= count) {
+                    return Ok(());
+                }
+                generated += 1;
+                push(occurrence)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Freq {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+struct Rule {
+    freq: Freq,
+    interval: i64,
+    count: Option<usize>,
+    until: Option<NaiveDateTime>,
+    by_day: Vec<Weekday>,
+}
+
+impl Rule {
+    fn parse(rule: &str) -> Result<Self, IcsError> {
+        let unsupported = || IcsError::UnsupportedRule(rule.to_string());
+        let mut freq = None;
+        let mut parsed = Rule { freq: Freq::Daily, interval: 1, count: None, until: None, by_day: Vec::new() };
+        for part in rule.split(';').filter(|part| !part.is_empty()) {
+            let (key, value) = part.split_once('=').ok_or_else(unsupported)?;
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    freq = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Freq::Daily,
+                        "WEEKLY" => Freq::Weekly,
+                        "MONTHLY" => Freq::Monthly,
+                        "YEARLY" => Freq::Yearly,
+                        _ => return Err(unsupported()),
+                    })
+                }
+                "INTERVAL" => parsed.interval = value.parse().ok().filter(|n| *n > 0).ok_or_else(unsupported)?,
+                "COUNT" => parsed.count = Some(value.parse().map_err(|_| unsupported())?),
+                "UNTIL" => parsed.until = Some(parse_date_time(value, false)?.0),
+                "BYDAY" => {
+                    for day in value.split(',') {
+                        parsed.by_day.push(parse_weekday(day).ok_or_else(unsupported)?);
+                    }
+                }
+                "WKST" if value.eq_ignore_ascii_case("MO") => {}
+                _ => return Err(unsupported()),
+            }
+        }
+        parsed.freq = freq.ok_or_else(unsupported)?;
+        if !parsed.by_day.is_empty() && parsed.freq != Freq::Weekly {
+            return Err(unsupported());
+        }
+        parsed.by_day.sort_by_key(|day| day.num_days_from_monday());
+        parsed.by_day.dedup();
+        Ok(parsed)
+    }
+
+    /// Get the first day of a recurrence period and the candidate dates in it, in order.
+    fn period(&self, first: NaiveDate, period: i64) -> Option<(NaiveDate, Vec<NaiveDate>)> {
+        let step = period.checked_mul(self.interval)?;
+        match self.freq {
+            Freq::Daily => {
+                let date = first.checked_add_signed(Duration::try_days(step)?)?;
+                Some((date, vec![date]))
+            }
+            Freq::Weekly => {
+                let monday = first.week(Weekday::Mon).first_day().checked_add_signed(Duration::try_weeks(step)?)?;
+                let on = |day: Weekday| monday.checked_add_signed(Duration::days(day.num_days_from_monday() as i64));
+                let dates = if self.by_day.is_empty() {
+                    vec![on(first.weekday())?]
+                } else {
+                    self.by_day.iter().map(|day| on(*day)).collect::<Option<_>>()?
+                };
+                Some((monday, dates))
+            }
+            Freq::Monthly => {
+                let months = (first.year() as i64 * 12 + first.month0() as i64).checked_add(step)?;
+                let (year, month) = (i32::try_from(months.div_euclid(12)).ok()?, months.rem_euclid(12) as u32 + 1);
+                let floor = NaiveDate::from_ymd_opt(year, month, 1)?;
+                Some((floor, NaiveDate::from_ymd_opt(year, month, first.day()).into_iter().collect()))
+            }
+            Freq::Yearly => {
+                let year = i32::try_from(first.year() as i64 + step).ok()?;
+                let floor = NaiveDate::from_ymd_opt(year, 1, 1)?;
+                Some((floor, NaiveDate::from_ymd_opt(year, first.month(), first.day()).into_iter().collect()))
+            }
+        }
+    }
+}
+
+/// Join folded content lines back together, dropping blank lines.
+fn unfold(input: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in input.split('\n').map(|line| line.strip_suffix('\r').unwrap_or(line)) {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(last)) => last.push_str(continuation),
+            _ if line.is_empty() => {}
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+/// Split a content line into its upper-cased property name, its parameters and its value.
+fn split_line(line: &str) -> Result<(String, &str, &str), IcsError> {
+    let (head, value) = line.split_once(':').ok_or_else(|| IcsError::InvalidLine(line.to_string()))?;
+    let (name, params) = head.split_once(';').unwrap_or((head, ""));
+    Ok((name.to_ascii_uppercase(), params, value.trim()))
+}
+
+/// Parse a `DATE` or `DATE-TIME` value, returning whether it was a date.
+fn parse_date_time(value: &str, date_only: bool) -> Result<(NaiveDateTime, bool), IcsError> {
+    let invalid = || IcsError::InvalidDateTime(value.to_string());
+    let trimmed = value.trim().trim_end_matches(['Z', 'z']);
+    if date_only || trimmed.len() == 8 {
+        let date = NaiveDate::parse_from_str(trimmed, "%Y%m%d").map_err(|_| invalid())?;
+        return Ok((date.and_time(NaiveTime::MIN), true));
+    }
+    let date_time = NaiveDateTime::parse_from_str(trimmed, "%Y%m%dT%H%M%S").map_err(|_| invalid())?;
+    Ok((date_time, false))
+}
+
+/// Parse a duration such as `PT1H30M`, `P1D` or `-P2W`.
+fn parse_duration(value: &str) -> Result<Duration, IcsError> {
+    let invalid = || IcsError::InvalidDuration(value.to_string());
+    let (negative, rest) = match value.trim().strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.trim().trim_start_matches('+')),
+    };
+    let rest = rest.strip_prefix(['P', 'p']).ok_or_else(invalid)?;
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    let mut in_time = false;
+    let mut any = false;
+    for c in rest.chars() {
+        match c.to_ascii_uppercase() {
+            '0'..='9' => number.push(c),
+            'T' if !in_time && number.is_empty() => in_time = true,
+            unit => {
+                let n: i64 = number.parse().map_err(|_| invalid())?;
+                number.clear();
+                let part = match (unit, in_time) {
+                    ('W', false) => Duration::try_weeks(n),
+                    ('D', false) => Duration::try_days(n),
+                    ('H', true) => Duration::try_hours(n),
+                    ('M', true) => Duration::try_minutes(n),
+                    ('S', true) => Duration::try_seconds(n),
+                    _ => None,
+                }
+                .ok_or_else(invalid)?;
+                total = total.checked_add(&part).ok_or_else(invalid)?;
+                any = true;
+            }
+        }
+    }
+    if !any || !number.is_empty() {
+        return Err(invalid());
+    }
+    Ok(if negative { -total } else { total })
+}
+
+fn parse_weekday(day: &str) -> Option<Weekday> {
+    match day.trim().to_ascii_uppercase().as_str() {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IcsError, parse_ics};
+    use crate::daterange::date_range::DateRange;
+    use crate::datetimerange::date_time_range::DateTimeRange;
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    fn dt(y: i32, m: u32, day: u32, h: u32, min: u32) -> NaiveDateTime {
+        d(y, m, day).and_hms_opt(h, min, 0).unwrap()
+    }
+
+    fn calendar(events: &[&str]) -> String {
+        let mut ics = String::from("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n");
+        for event in events {
+            ics.push_str("BEGIN:VEVENT\r\n");
+            ics.push_str(event);
+            ics.push_str("END:VEVENT\r\n");
+        }
+        ics.push_str("END:VCALENDAR\r\n");
+        ics
+    }
+
+    #[test]
+    fn reads_single_and_all_day_events() {
+        let ics = calendar(&[
+            "UID:1\r\nDTSTART;TZID=America/New_York:20240305T090000\r\nDTEND;TZID=America/New_York:20240305T103000\r\n",
+            "UID:2\r\nDTSTART;VALUE=DATE:20240304\r\nDTEND;VALUE=DATE:20240306\r\n",
+            "UID:3\r\nDTSTART:20240310T220000Z\r\nDURATION:PT3H\r\nBEGIN:VALARM\r\nDURATION:PT15M\r\n\
+             END:VALARM\r\n",
+            "UID:4\r\nDTSTART:20240501T090000\r\nDTEND:20240501T100000\r\n",
+            "UID:5\r\nDTSTART:20240306T090000\r\nDTEND:20240306T100000\r\nSTATUS:CANCELLED\r\n",
+        ]);
+        let ranges = parse_ics(&ics, &DateRange::new(d(2024, 3, 1), d(2024, 3, 31))).unwrap();
+        assert_eq!(
+            ranges,
+            vec![
+                DateTimeRange::of(dt(2024, 3, 4, 0, 0), dt(2024, 3, 6, 0, 0)),
+                DateTimeRange::of(dt(2024, 3, 5, 9, 0), dt(2024, 3, 5, 10, 30)),
+                DateTimeRange::of(dt(2024, 3, 10, 22, 0), dt(2024, 3, 11, 1, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn unfolds_continuation_lines() {
+        let ics = "BEGIN:VEVENT\nDTSTART:20240305T09\n 0000\nDTEND:20240305T1000\n\t00\nEND:VEVENT\n";
+        let ranges = parse_ics(ics, &DateRange::new(d(2024, 3, 5), d(2024, 3, 5))).unwrap();
+        assert_eq!(ranges, vec![DateTimeRange::of(dt(2024, 3, 5, 9, 0), dt(2024, 3, 5, 10, 0))]);
+    }
+
+    #[test]
+    fn expands_recurring_events_over_the_window() {
+        let ics = calendar(&[
+            "DTSTART:20240101T090000\r\nDTEND:20240101T093000\r\nRRULE:FREQ=WEEKLY;BYDAY=MO,WE\r\n\
+             EXDATE:20240110T090000\r\n",
+            "DTSTART:20240131T120000\r\nDURATION:PT1H\r\nRRULE:FREQ=MONTHLY;COUNT=3\r\n",
+            "DTSTART;VALUE=DATE:20231225\r\nRRULE:FREQ=DAILY;INTERVAL=7;UNTIL=20240115\r\n",
+        ]);
+        let ranges = parse_ics(&ics, &DateRange::new(d(2024, 1, 8), d(2024, 3, 31))).unwrap();
+        let starts: Vec<_> = ranges.iter().map(|r| r.start()).collect();
+
+        let weekly = starts.iter().filter(|s| s.time() == dt(2024, 1, 1, 9, 0).time()).count();
+        assert_eq!(weekly, 24 - 1);
+        assert!(!starts.contains(&dt(2024, 1, 10, 9, 0)));
+        assert!(starts.contains(&dt(2024, 1, 8, 9, 0)));
+        assert!(starts.contains(&dt(2024, 3, 27, 9, 0)));
+
+        // February and April have no 31st, so the three occurrences are Jan, Mar and May 31.
+        let monthly: Vec<_> = starts.iter().filter(|s| s.time() == dt(2024, 1, 1, 12, 0).time()).collect();
+        assert_eq!(monthly, vec![&dt(2024, 1, 31, 12, 0), &dt(2024, 3, 31, 12, 0)]);
+
+        assert!(ranges.contains(&DateTimeRange::of(dt(2024, 1, 8, 0, 0), dt(2024, 1, 9, 0, 0))));
+        assert!(ranges.contains(&DateTimeRange::of(dt(2024, 1, 15, 0, 0), dt(2024, 1, 16, 0, 0))));
+        assert!(!starts.contains(&dt(2024, 1, 22, 0, 0)));
+        assert!(ranges.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn reports_malformed_input() {
+        let window = DateRange::new(d(2024, 1, 1), d(2024, 12, 31));
+        assert_eq!(parse_ics("BEGIN:VEVENT\r\nSUMMARY", &window), Err(IcsError::InvalidLine("SUMMARY".into())));
+        assert_eq!(parse_ics(&calendar(&["SUMMARY:x\r\n"]), &window), Err(IcsError::MissingStart));
+        assert_eq!(
+            parse_ics(&calendar(&["DTSTART:2024-01-01\r\n"]), &window),
+            Err(IcsError::InvalidDateTime("2024-01-01".into()))
+        );
+        assert_eq!(
+            parse_ics(&calendar(&["DTSTART:20240101T090000\r\nDURATION:1H\r\n"]), &window),
+            Err(IcsError::InvalidDuration("1H".into()))
+        );
+        assert_eq!(
+            parse_ics(&calendar(&["DTSTART:20240101T090000\r\nRRULE:FREQ=MONTHLY;BYSETPOS=-1\r\n"]), &window),
+            Err(IcsError::UnsupportedRule("FREQ=MONTHLY;BYSETPOS=-1".into()))
+        );
+        assert_eq!(parse_ics("BEGIN:VEVENT\r\nDTSTART:20240101\r\n", &window), Err(IcsError::UnterminatedEvent));
+    }
+
+    #[test]
+    fn reports_durations_that_overflow() {
+        let window = DateRange::new(d(2024, 1, 1), d(2024, 12, 31));
+        let huge = parse_ics(&calendar(&["DTSTART:20240101T090000\r\nDURATION:P99999999W\r\n"]), &window);
+        assert!(matches!(huge, Err(IcsError::InvalidDuration(_))), "{:?}", huge);
+        assert_eq!(
+            parse_ics(&calendar(&["DTSTART:20240101T090000\r\nDURATION:P15000000000WT2000000000000H\r\n"]), &window),
+            Err(IcsError::InvalidDuration("P15000000000WT2000000000000H".into()))
+        );
+        let recurring = calendar(&["DTSTART:20240101T090000\r\nDURATION:P99999999W\r\nRRULE:FREQ=WEEKLY;BYDAY=MO\r\n"]);
+        assert!(matches!(parse_ics(&recurring, &window), Err(IcsError::InvalidDuration(_))));
+    }
+}
@@ -1,2 +1,14 @@
 pub mod date_time_range;
+pub mod date_time_range_set;
 pub mod date_time_range_with_period_length;
+pub mod range_stats;
+pub mod slo;
+pub mod zoned;
+pub mod allocation;
+pub mod audit;
+pub mod billable;
+pub mod occupancy;
+pub mod quota_window;
+pub mod date_time_range_record;
+pub mod ics;
+pub mod overlap_join;
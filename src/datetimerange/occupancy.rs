@@ -0,0 +1,240 @@
+use crate::datetimerange::date_time_range::DateTimeRange;
+use crate::datetimerange::date_time_range_with_period_length::DateTimeRangeWithPeriodLength;
+use crate::dateutils::date_utils::merge_overlapping;
+use chrono::NaiveDateTime;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::max;
+use core::ops::Range;
+
+/// How much of a period must be busy for it to count as occupied.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Coverage {
+    /// Any overlap of positive length.
+    Partial,
+    /// The whole period, possibly across several adjacent busy ranges.
+    Full,
+}
+
+/// A fixed-length set of period flags, one bit per period of a shift.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PeriodBitmap {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl PeriodBitmap {
+    /// Create a bitmap of `len` periods with none set.
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    /// Get the number of periods in the bitmap.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the bitmap has no periods.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Check if a period is set. Periods past the end are never set.
+    pub fn get(&self, index: usize) -> bool {
+        index < self.len && self.words[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Set or clear a period.
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert!(index < self.len, "index {} out of range for {} periods", index, self.len);
+        if value {
+            self.words[index / 64] |= 1 << (index % 64);
+        } else {
+            self.words[index / 64] &= !(1 << (index % 64));
+        }
+    }
+
+    /// Get the number of periods that are set.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Iterate over the indexes of the periods that are set.
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len).filter(|index| self.get(*index))
+    }
+
+    /// Get the periods set in both bitmaps.
+    pub fn and(&self, other: &PeriodBitmap) -> PeriodBitmap {
+        self.combine(other, |a, b| a & b)
+    }
+
+    /// Get the periods set in either bitmap.
+    pub fn or(&self, other: &PeriodBitmap) -> PeriodBitmap {
+        self.combine(other, |a, b| a | b)
+    }
+
+    /// Get the periods set in this bitmap but not the other.
+    pub fn and_not(&self, other: &PeriodBitmap) -> PeriodBitmap {
+        self.combine(other, |a, b| a & !b)
+    }
+
+    /// Get the periods not set in this bitmap.
+    pub fn complement(&self) -> PeriodBitmap {
+        let mut result = self.combine(self, |a, _| !a);
+        if !self.len.is_multiple_of(64) {
+            *result.words.last_mut().unwrap() &= (1 << (self.len % 64)) - 1;
+        }
+        result
+    }
+
+    fn combine(&self, other: &PeriodBitmap, op: impl Fn(u64, u64) -> u64) -> PeriodBitmap {
+        assert_eq!(self.len, other.len, "bitmaps must have the same number of periods");
+        PeriodBitmap {
+            words: self.words.iter().zip(&other.words).map(|(a, b)| op(*a, *b)).collect(),
+            len: self.len,
+        }
+    }
+}
+
+/// Mark which periods of a shift are occupied by busy ranges. Busy ranges may overlap and
+/// needn't be sorted; with full coverage, adjacent ranges together can cover a period.
+pub fn occupancy_bitmap(
+    shift: &DateTimeRangeWithPeriodLength,
+    busy: &[DateTimeRange],
+    coverage: Coverage,
+) -> PeriodBitmap {
+    let mut bitmap = PeriodBitmap::new(period_count(shift));
+    for range in merge_overlapping(busy) {
+        for index in period_span(shift, range.start(), range.end(), coverage) {
+            bitmap.set(index, true);
+        }
+    }
+    bitmap
+}
+
+/// Count the shifts working in each period of a grid, such as for a staffing-vs-demand chart.
+/// The counts are accumulated with a difference array, so the cost is linear in the number of
+/// shifts plus the number of periods.
+pub fn demand_curve(shifts: &[DateTimeRange], grid: &DateTimeRangeWithPeriodLength, coverage: Coverage) -> Vec<u32> {
+    let periods = period_count(grid);
+    let mut deltas = vec![0i64; periods + 1];
+    for shift in shifts.iter().filter(|shift| shift.end() > shift.start()) {
+        let span = period_span(grid, shift.start(), shift.end(), coverage);
+        if !span.is_empty() {
+            deltas[span.start] += 1;
+            deltas[span.end] -= 1;
+        }
+    }
+
+    let mut headcount = 0;
+    deltas[..periods]
+        .iter()
+        .map(|delta| {
+            headcount += delta;
+            headcount as u32
+        })
+        .collect()
+}
+
+fn period_count(grid: &DateTimeRangeWithPeriodLength) -> usize {
+    max(grid.number_of_periods_in_shift(), 0) as usize
+}
+
+/// Get the indexes of the grid periods that `start..end` covers.
+fn period_span(
+    grid: &DateTimeRangeWithPeriodLength,
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    coverage: Coverage,
+) -> Range<usize> {
+    let period_seconds = grid.period_length_in_minutes() as i64 * 60;
+    let from = (start - grid.date_time_range().start()).num_seconds();
+    let to = (end - grid.date_time_range().start()).num_seconds();
+    let floor = |seconds: i64| seconds.div_euclid(period_seconds);
+    let ceil = |seconds: i64| (seconds + period_seconds - 1).div_euclid(period_seconds);
+    let (first, last) = match coverage {
+        Coverage::Partial => (floor(from), ceil(to)),
+        Coverage::Full => (ceil(from), floor(to)),
+    };
+
+    let periods = period_count(grid) as i64;
+    let first = first.clamp(0, periods) as usize;
+    let last = last.clamp(0, periods) as usize;
+    first..max(first, last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn dt(h: u32, mi: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 3, 1).unwrap().and_hms_opt(h, mi, 0).unwrap()
+    }
+
+    fn ones(bitmap: &PeriodBitmap) -> Vec<usize> {
+        bitmap.iter_ones().collect()
+    }
+
+    #[test]
+    fn partial_and_full_coverage() {
+        // 09:00-11:00 in 15 minute periods.
+        let shift = DateTimeRangeWithPeriodLength::of_datetimes(dt(9, 0), dt(11, 0), 15);
+        let busy = [DateTimeRange::of(dt(9, 10), dt(9, 45)), DateTimeRange::of(dt(10, 30), dt(10, 40))];
+
+        let partial = occupancy_bitmap(&shift, &busy, Coverage::Partial);
+        assert_eq!(partial.len(), 8);
+        assert_eq!(ones(&partial), vec![0, 1, 2, 6]);
+
+        let full = occupancy_bitmap(&shift, &busy, Coverage::Full);
+        assert_eq!(ones(&full), vec![1, 2]);
+    }
+
+    #[test]
+    fn adjacent_ranges_combine_for_full_coverage() {
+        let shift = DateTimeRangeWithPeriodLength::of_datetimes(dt(9, 0), dt(10, 0), 30);
+        let busy = [DateTimeRange::of(dt(9, 20), dt(9, 40)), DateTimeRange::of(dt(8, 0), dt(9, 20))];
+        assert_eq!(ones(&occupancy_bitmap(&shift, &busy, Coverage::Full)), vec![0]);
+
+        let touching = [DateTimeRange::of(dt(8, 0), dt(9, 0)), DateTimeRange::of(dt(10, 0), dt(11, 0))];
+        assert_eq!(occupancy_bitmap(&shift, &touching, Coverage::Partial).count_ones(), 0);
+    }
+
+    #[test]
+    fn demand_curve_counts_overlapping_shifts() {
+        let grid = DateTimeRangeWithPeriodLength::of_datetimes(dt(8, 0), dt(12, 0), 60);
+        let shifts = [
+            DateTimeRange::of(dt(7, 0), dt(10, 0)),
+            DateTimeRange::of(dt(9, 0), dt(13, 0)),
+            DateTimeRange::of(dt(9, 30), dt(11, 0)),
+            DateTimeRange::of(dt(12, 0), dt(14, 0)),
+        ];
+        assert_eq!(demand_curve(&shifts, &grid, Coverage::Partial), vec![1, 3, 2, 1]);
+        assert_eq!(demand_curve(&shifts, &grid, Coverage::Full), vec![1, 2, 2, 1]);
+        assert_eq!(demand_curve(&[], &grid, Coverage::Partial), vec![0; 4]);
+    }
+
+    #[test]
+    fn set_operations() {
+        let mut a = PeriodBitmap::new(70);
+        let mut b = PeriodBitmap::new(70);
+        for index in [0, 3, 65] {
+            a.set(index, true);
+        }
+        for index in [3, 4, 69] {
+            b.set(index, true);
+        }
+        assert_eq!(ones(&a.and(&b)), vec![3]);
+        assert_eq!(ones(&a.or(&b)), vec![0, 3, 4, 65, 69]);
+        assert_eq!(ones(&a.and_not(&b)), vec![0, 65]);
+        assert_eq!(a.complement().count_ones(), 67);
+        assert!(!a.complement().get(70));
+
+        a.set(3, false);
+        assert!(!a.get(3));
+    }
+}
@@ -0,0 +1,126 @@
+use crate::datetimerange::date_time_range::DateTimeRange;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::iter::{Enumerate, Peekable};
+
+/// Join two streams of ranges sorted by start, yielding the index in each stream and the overlap
+/// of every pair that overlaps for a positive length. Pairs come out in the order the later of the
+/// two ranges starts. Only ranges that could still overlap an upcoming range are held, so the join
+/// runs in a single pass over both streams without materializing either.
+pub fn overlap_join<A, B>(a: A, b: B) -> impl Iterator<Item = (usize, usize, DateTimeRange)>
+where
+    A: IntoIterator<Item = DateTimeRange>,
+    B: IntoIterator<Item = DateTimeRange>,
+{
+    OverlapJoin {
+        a: a.into_iter().enumerate().peekable(),
+        b: b.into_iter().enumerate().peekable(),
+        active_a: Vec::new(),
+        active_b: Vec::new(),
+        pending: VecDeque::new(),
+    }
+}
+
+struct OverlapJoin<A: Iterator, B: Iterator> {
+    a: Peekable<Enumerate<A>>,
+    b: Peekable<Enumerate<B>>,
+    active_a: Vec<(usize, DateTimeRange)>,
+    active_b: Vec<(usize, DateTimeRange)>,
+    pending: VecDeque<(usize, usize, DateTimeRange)>,
+}
+
+impl<A, B> Iterator for OverlapJoin<A, B>
+where
+    A: Iterator<Item = DateTimeRange>,
+    B: Iterator<Item = DateTimeRange>,
+{
+    type Item = (usize, usize, DateTimeRange);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(pair) = self.pending.pop_front() {
+                return Some(pair);
+            }
+
+            // Once one stream is drained and none of its ranges are still open, nothing else can join.
+            let take_a = match (self.a.peek(), self.b.peek()) {
+                (Some((_, a)), Some((_, b))) => a.start() <= b.start(),
+                (Some(_), None) if !self.active_b.is_empty() => true,
+                (None, Some(_)) if !self.active_a.is_empty() => false,
+                _ => return None,
+            };
+            if take_a {
+                let (index, range) = self.a.next()?;
+                for (other, overlap) in advance(&mut self.active_a, &mut self.active_b, index, range) {
+                    self.pending.push_back((index, other, overlap));
+                }
+            } else {
+                let (index, range) = self.b.next()?;
+                for (other, overlap) in advance(&mut self.active_b, &mut self.active_a, index, range) {
+                    self.pending.push_back((other, index, overlap));
+                }
+            }
+        }
+    }
+}
+
+/// Take the next range of one stream, dropping ranges of the other stream that end by its start and
+/// returning its overlap with the rest.
+fn advance(
+    own: &mut Vec<(usize, DateTimeRange)>,
+    other: &mut Vec<(usize, DateTimeRange)>,
+    index: usize,
+    range: DateTimeRange,
+) -> Vec<(usize, DateTimeRange)> {
+    other.retain(|(_, r)| r.end() > range.start());
+    own.retain(|(_, r)| r.end() > range.start());
+    if range.start() >= range.end() {
+        return Vec::new();
+    }
+    let overlaps = other
+        .iter()
+        .map(|(other_index, r)| (*other_index, DateTimeRange::of(range.start(), r.end().min(range.end()))))
+        .collect();
+    own.push((index, range));
+    overlaps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::overlap_join;
+    use crate::datetimerange::date_time_range::DateTimeRange;
+    use chrono::{Duration, NaiveDate, NaiveDateTime};
+
+    fn dt(day: u32, h: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 1, day).unwrap().and_hms_opt(h, 0, 0).unwrap()
+    }
+
+    fn r(start: u32, end: u32) -> DateTimeRange {
+        DateTimeRange::of(dt(1, start), dt(1, end))
+    }
+
+    #[test]
+    fn joins_overlapping_pairs() {
+        let a = vec![r(0, 4), r(2, 6), r(10, 12)];
+        let b = vec![r(1, 3), r(4, 5), r(6, 11), r(12, 13)];
+        let joined: Vec<_> = overlap_join(a, b).collect();
+        assert_eq!(joined, vec![(0, 0, r(1, 3)), (1, 0, r(2, 3)), (1, 1, r(4, 5)), (2, 2, r(10, 11))]);
+    }
+
+    #[test]
+    fn touching_and_empty_ranges_do_not_join() {
+        let a = vec![r(0, 2), r(3, 3)];
+        let b = vec![r(2, 4), r(3, 5)];
+        assert_eq!(overlap_join(a, b).count(), 0);
+        assert_eq!(overlap_join(Vec::new(), vec![r(0, 1)]).count(), 0);
+    }
+
+    #[test]
+    fn streams_without_collecting() {
+        let a = (0..10_000).map(|i| DateTimeRange::of(dt(1, 0) + Duration::hours(i), dt(1, 1) + Duration::hours(i)));
+        let b = core::iter::once(DateTimeRange::of(dt(2, 0), dt(3, 0)));
+        let joined: Vec<_> = overlap_join(a, b).collect();
+        assert_eq!(joined.len(), 24);
+        assert_eq!(joined[0], (24, 0, DateTimeRange::of(dt(2, 0), dt(2, 1))));
+    }
+}
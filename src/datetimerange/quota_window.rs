@@ -0,0 +1,52 @@
+use crate::datetimerange::date_time_range::DateTimeRange;
+use chrono::{Duration, NaiveDateTime};
+
+/// Get the fixed window containing a datetime, where windows of equal length are laid end to
+/// end from an anchor in both directions. Windows are half-open: a datetime on a boundary
+/// belongs to the window that starts there. Returns None if the window length isn't positive.
+pub fn fixed_window_containing(dt: NaiveDateTime, window: Duration, anchor: NaiveDateTime) -> Option<DateTimeRange> {
+    let length = window.num_milliseconds();
+    if length <= 0 {
+        return None;
+    }
+    let index = (dt - anchor).num_milliseconds().div_euclid(length);
+    let start = anchor + Duration::milliseconds(index * length);
+    Some(DateTimeRange::of(start, start + window))
+}
+
+/// Get the rolling window of the given length that ends at a datetime, as used for limits
+/// such as "at most N in any 30 days".
+pub fn rolling_window_ending_at(dt: NaiveDateTime, window: Duration) -> DateTimeRange {
+    DateTimeRange::of(dt - window, dt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fixed_window_containing, rolling_window_ending_at};
+    use chrono::{Duration, NaiveDate, NaiveDateTime};
+
+    fn dt(y: i32, m: u32, day: u32, h: u32, mi: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap().and_hms_opt(h, mi, 0).unwrap()
+    }
+
+    #[test]
+    fn fixed_windows_are_aligned_to_the_anchor() {
+        let anchor = dt(2024, 3, 1, 0, 0);
+        let window = fixed_window_containing(dt(2024, 3, 1, 13, 20), Duration::hours(6), anchor).unwrap();
+        assert_eq!((window.start(), window.end()), (dt(2024, 3, 1, 12, 0), dt(2024, 3, 1, 18, 0)));
+
+        let boundary = fixed_window_containing(dt(2024, 3, 1, 18, 0), Duration::hours(6), anchor).unwrap();
+        assert_eq!(boundary.start(), dt(2024, 3, 1, 18, 0));
+
+        let before = fixed_window_containing(dt(2024, 2, 29, 23, 0), Duration::hours(6), anchor).unwrap();
+        assert_eq!((before.start(), before.end()), (dt(2024, 2, 29, 18, 0), anchor));
+
+        assert!(fixed_window_containing(anchor, Duration::zero(), anchor).is_none());
+    }
+
+    #[test]
+    fn rolling_window_ends_at_the_datetime() {
+        let window = rolling_window_ending_at(dt(2024, 3, 31, 9, 0), Duration::days(30));
+        assert_eq!((window.start(), window.end()), (dt(2024, 3, 1, 9, 0), dt(2024, 3, 31, 9, 0)));
+    }
+}
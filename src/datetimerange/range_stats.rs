@@ -0,0 +1,116 @@
+use crate::datetimerange::date_time_range::DateTimeRange;
+use crate::dateutils::date_utils::{earliest_start, latest_end, total_duration};
+use chrono::Duration;
+use alloc::vec::Vec;
+
+/// Summary statistics for a list of ranges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeStats {
+    pub count: usize,
+    pub total_duration: Duration,
+    pub mean_duration: Option<Duration>,
+    pub median_duration: Option<Duration>,
+    /// The range from the earliest start to the latest end.
+    pub span: Option<DateTimeRange>,
+    /// The number of uncovered gaps between the ranges within the span.
+    pub gap_count: usize,
+}
+
+/// Summarize a list of ranges. Ranges that touch or overlap are not separated by a gap.
+pub fn summarize(ranges: &[DateTimeRange]) -> RangeStats {
+    let count = ranges.len();
+    let total = total_duration(ranges);
+
+    let mut durations: Vec<Duration> = ranges.iter().map(|r| r.duration()).collect();
+    durations.sort();
+    let median_duration = match count {
+        0 => None,
+        _ if count % 2 == 1 => Some(durations[count / 2]),
+        _ => Some((durations[count / 2 - 1] + durations[count / 2]) / 2),
+    };
+
+    let span = match (earliest_start(ranges), latest_end(ranges)) {
+        (Some(start), Some(end)) => Some(DateTimeRange::of(start, end)),
+        _ => None,
+    };
+
+    RangeStats {
+        count,
+        total_duration: total,
+        mean_duration: if count == 0 { None } else { Some(total / count as i32) },
+        median_duration,
+        span,
+        gap_count: count_gaps(ranges),
+    }
+}
+
+fn count_gaps(ranges: &[DateTimeRange]) -> usize {
+    let mut sorted = ranges.to_vec();
+    sorted.sort();
+
+    let mut gaps = 0;
+    let mut covered_until = None;
+    for range in sorted {
+        match covered_until {
+            Some(end) if range.start() > end => {
+                gaps += 1;
+                covered_until = Some(range.end());
+            }
+            Some(end) => covered_until = Some(range.end().max(end)),
+            None => covered_until = Some(range.end()),
+        }
+    }
+    gaps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::summarize;
+    use crate::datetimerange::date_time_range::DateTimeRange;
+    use chrono::{Duration, NaiveDate, NaiveDateTime};
+
+    fn dt(day: u32, h: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 3, day).unwrap().and_hms_opt(h, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn summarize_shifts() {
+        let shifts = vec![
+            DateTimeRange::of(dt(1, 9), dt(1, 17)),
+            DateTimeRange::of(dt(1, 17), dt(1, 21)), // touches the first shift
+            DateTimeRange::of(dt(2, 9), dt(2, 11)),
+            DateTimeRange::of(dt(3, 8), dt(3, 14)),
+        ];
+        let stats = summarize(&shifts);
+
+        assert_eq!(stats.count, 4);
+        assert_eq!(stats.total_duration, Duration::hours(20));
+        assert_eq!(stats.mean_duration, Some(Duration::hours(5)));
+        assert_eq!(stats.median_duration, Some(Duration::hours(5))); // (4h + 6h) / 2
+        assert_eq!(stats.span, Some(DateTimeRange::of(dt(1, 9), dt(3, 14))));
+        assert_eq!(stats.gap_count, 2);
+    }
+
+    #[test]
+    fn summarize_counts_no_gap_inside_overlapping_ranges() {
+        let shifts = vec![
+            DateTimeRange::of(dt(1, 6), dt(1, 20)),
+            DateTimeRange::of(dt(1, 8), dt(1, 10)),
+            DateTimeRange::of(dt(1, 12), dt(1, 14)),
+        ];
+        let stats = summarize(&shifts);
+        assert_eq!(stats.gap_count, 0);
+        assert_eq!(stats.median_duration, Some(Duration::hours(2)));
+    }
+
+    #[test]
+    fn summarize_empty_list() {
+        let stats = summarize(&[]);
+        assert_eq!(stats.count, 0);
+        assert_eq!(stats.total_duration, Duration::zero());
+        assert_eq!(stats.mean_duration, None);
+        assert_eq!(stats.median_duration, None);
+        assert_eq!(stats.span, None);
+        assert_eq!(stats.gap_count, 0);
+    }
+}
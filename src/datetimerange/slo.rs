@@ -0,0 +1,90 @@
+use crate::datetimerange::date_time_range::DateTimeRange;
+use crate::datetimerange::quota_window::rolling_window_ending_at;
+use crate::dateutils::date_utils::{add_months, first_day_of_month, merge_overlapping};
+use chrono::{Duration, NaiveDateTime};
+
+/// The window an SLO is evaluated over.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SloWindow {
+    /// The calendar month, from midnight on the first up to midnight on the first of the next.
+    CalendarMonth,
+    /// The window of the given length ending at the evaluation time.
+    Rolling(Duration),
+}
+
+impl SloWindow {
+    /// A rolling window of 28 days.
+    pub fn rolling_28_days() -> Self {
+        SloWindow::Rolling(Duration::days(28))
+    }
+
+    /// Get the window evaluated at a datetime.
+    pub fn window_at(&self, at: NaiveDateTime) -> DateTimeRange {
+        match self {
+            SloWindow::CalendarMonth => {
+                let start = first_day_of_month(at.date());
+                DateTimeRange::of(start.into(), add_months(start, 1).into())
+            }
+            SloWindow::Rolling(length) => rolling_window_ending_at(at, *length),
+        }
+    }
+}
+
+/// Get the downtime within a window. Overlapping downtime ranges are only counted once.
+pub fn downtime_within(downtime: &[DateTimeRange], window: &DateTimeRange) -> Duration {
+    merge_overlapping(downtime)
+        .iter()
+        .map(|range| window.overlap_duration(range))
+        .sum()
+}
+
+/// Get the percentage of a window that wasn't covered by downtime, from 0 to 100. An empty
+/// window is fully available.
+pub fn availability(downtime: &[DateTimeRange], window: &DateTimeRange) -> f64 {
+    let total = window.duration().num_milliseconds();
+    if total <= 0 {
+        return 100.0;
+    }
+    let down = downtime_within(downtime, window).num_milliseconds();
+    100.0 * (total - down) as f64 / total as f64
+}
+
+/// Get the availability percentage over an SLO window evaluated at a datetime.
+pub fn availability_at(downtime: &[DateTimeRange], slo_window: SloWindow, at: NaiveDateTime) -> f64 {
+    availability(downtime, &slo_window.window_at(at))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn dt(y: i32, m: u32, day: u32, h: u32, mi: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap().and_hms_opt(h, mi, 0).unwrap()
+    }
+
+    #[test]
+    fn calendar_month_window_is_midnight_to_midnight() {
+        let window = SloWindow::CalendarMonth.window_at(dt(2024, 2, 14, 9, 30));
+        assert_eq!((window.start(), window.end()), (dt(2024, 2, 1, 0, 0), dt(2024, 3, 1, 0, 0)));
+        assert_eq!(window.duration(), Duration::days(29));
+
+        let rolling = SloWindow::rolling_28_days().window_at(dt(2024, 3, 29, 0, 0));
+        assert_eq!(rolling.start(), dt(2024, 3, 1, 0, 0));
+    }
+
+    #[test]
+    fn availability_counts_overlapping_downtime_once_and_clips_to_window() {
+        let downtime = [
+            DateTimeRange::of(dt(2024, 1, 31, 23, 0), dt(2024, 2, 1, 1, 0)),
+            DateTimeRange::of(dt(2024, 2, 10, 12, 0), dt(2024, 2, 10, 13, 0)),
+            DateTimeRange::of(dt(2024, 2, 10, 12, 30), dt(2024, 2, 10, 13, 30)),
+        ];
+        let window = SloWindow::CalendarMonth.window_at(dt(2024, 2, 20, 0, 0));
+        assert_eq!(downtime_within(&downtime, &window), Duration::minutes(150));
+
+        let expected = 100.0 * (1.0 - 150.0 / (29.0 * 24.0 * 60.0));
+        assert!((availability(&downtime, &window) - expected).abs() < 1e-9);
+        assert_eq!(availability_at(&downtime, SloWindow::CalendarMonth, dt(2024, 4, 1, 0, 0)), 100.0);
+    }
+}
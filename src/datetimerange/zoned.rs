@@ -0,0 +1,133 @@
+use crate::datetimerange::date_time_range::DateTimeRange;
+use chrono::{DateTime, NaiveDate, TimeZone};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+/// A range between two instants, each carrying the time zone it is expressed in.
+///
+/// Any `chrono` time zone can be used, such as `Utc`, `FixedOffset` or a zone from an IANA
+/// database crate. Comparisons between zones are by instant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZonedDateTimeRange<Tz: TimeZone> {
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+}
+
+impl<Tz: TimeZone> ZonedDateTimeRange<Tz> {
+    pub fn of(start: DateTime<Tz>, end: DateTime<Tz>) -> Self {
+        Self { start, end }
+    }
+
+    /// Create a range from local wall-clock times in a zone. Returns None if either bound is
+    /// skipped or repeated by a transition in the zone, such as a daylight saving change.
+    pub fn from_local(range: &DateTimeRange, zone: &Tz) -> Option<Self> {
+        let start = zone.from_local_datetime(&range.start()).single()?;
+        let end = zone.from_local_datetime(&range.end()).single()?;
+        Some(Self::of(start, end))
+    }
+
+    pub fn start(&self) -> &DateTime<Tz> {
+        &self.start
+    }
+
+    pub fn end(&self) -> &DateTime<Tz> {
+        &self.end
+    }
+
+    /// Get the range as local wall-clock times in its own zone.
+    pub fn local(&self) -> DateTimeRange {
+        DateTimeRange::of(self.start.naive_local(), self.end.naive_local())
+    }
+
+    /// Get the range as naive UTC times.
+    pub fn to_utc(&self) -> DateTimeRange {
+        DateTimeRange::of(self.start.naive_utc(), self.end.naive_utc())
+    }
+
+    /// Get the same instants expressed in another zone.
+    pub fn with_zone<Tz2: TimeZone>(&self, zone: &Tz2) -> ZonedDateTimeRange<Tz2> {
+        ZonedDateTimeRange::of(self.start.with_timezone(zone), self.end.with_timezone(zone))
+    }
+
+    /// Get the local date the range starts on, which is the business date it is reported under.
+    pub fn business_date(&self) -> NaiveDate {
+        self.start.date_naive()
+    }
+}
+
+/// Express ranges from any number of zones in a single reference zone, preserving instants.
+pub fn normalize_to<Tz: TimeZone, Tz2: TimeZone>(
+    ranges: &[ZonedDateTimeRange<Tz>],
+    zone: &Tz2,
+) -> Vec<ZonedDateTimeRange<Tz2>> {
+    ranges.iter().map(|range| range.with_zone(zone)).collect()
+}
+
+/// Group ranges by site and then by the local business date they start on in the site's own
+/// zone. Ranges keep their order within each group.
+pub fn group_by_business_date<S: Ord + Clone, Tz: TimeZone>(
+    ranges: &[(S, ZonedDateTimeRange<Tz>)],
+) -> BTreeMap<S, BTreeMap<NaiveDate, Vec<ZonedDateTimeRange<Tz>>>> {
+    let mut groups: BTreeMap<S, BTreeMap<NaiveDate, Vec<ZonedDateTimeRange<Tz>>>> = BTreeMap::new();
+    for (site, range) in ranges {
+        groups
+            .entry(site.clone())
+            .or_default()
+            .entry(range.business_date())
+            .or_default()
+            .push(range.clone());
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, NaiveDateTime, Utc};
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    fn dt(y: i32, m: u32, day: u32, h: u32, mi: u32) -> NaiveDateTime {
+        d(y, m, day).and_hms_opt(h, mi, 0).unwrap()
+    }
+
+    fn zone(hours: i32) -> FixedOffset {
+        FixedOffset::east_opt(hours * 3600).unwrap()
+    }
+
+    #[test]
+    fn conversion_preserves_instants() {
+        let local = DateTimeRange::of(dt(2024, 3, 1, 22, 0), dt(2024, 3, 2, 6, 0));
+        let new_york = ZonedDateTimeRange::from_local(&local, &zone(-5)).unwrap();
+        assert_eq!(new_york.local(), local);
+        assert_eq!(new_york.to_utc(), DateTimeRange::of(dt(2024, 3, 2, 3, 0), dt(2024, 3, 2, 11, 0)));
+
+        let berlin = new_york.with_zone(&zone(1));
+        assert_eq!(berlin.local(), DateTimeRange::of(dt(2024, 3, 2, 4, 0), dt(2024, 3, 2, 12, 0)));
+        assert_eq!(berlin, new_york);
+
+        let in_utc = normalize_to(&[new_york.clone(), berlin], &Utc);
+        assert_eq!(in_utc[0].to_utc(), in_utc[1].to_utc());
+        assert_eq!(in_utc[0].local(), new_york.to_utc());
+    }
+
+    #[test]
+    fn grouping_uses_each_sites_local_date() {
+        let at = |offset: i32, start: NaiveDateTime, end: NaiveDateTime| {
+            ZonedDateTimeRange::from_local(&DateTimeRange::of(start, end), &zone(offset)).unwrap()
+        };
+        let shifts = [
+            ("nyc", at(-5, dt(2024, 3, 1, 22, 0), dt(2024, 3, 2, 6, 0))),
+            ("nyc", at(-5, dt(2024, 3, 2, 8, 0), dt(2024, 3, 2, 16, 0))),
+            ("ber", at(1, dt(2024, 3, 2, 4, 0), dt(2024, 3, 2, 12, 0))),
+        ];
+
+        let groups = group_by_business_date(&shifts);
+        assert_eq!(groups["nyc"].keys().copied().collect::<Vec<_>>(), vec![d(2024, 3, 1), d(2024, 3, 2)]);
+        assert_eq!(groups["ber"][&d(2024, 3, 2)].len(), 1);
+        // The first NYC shift and the Berlin shift are the same instants on different business dates.
+        assert_eq!(groups["nyc"][&d(2024, 3, 1)][0], groups["ber"][&d(2024, 3, 2)][0]);
+    }
+}
@@ -1,7 +1,15 @@
+use crate::daterange::date_range::DateRange;
+use crate::datetimerange::date_time_range::DateTimeRange;
+#[cfg(feature = "bigdecimal")]
 use bigdecimal::BigDecimal;
-use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Weekday};
+#[cfg(feature = "bigdecimal")]
 use num_traits::FromPrimitive;
-use std::cmp::{max, min};
+use num_traits::Float;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::{max, min};
 
 /// Get the first day of the month for the given date.
 pub fn first_day_of_month(date: NaiveDate) -> NaiveDate {
@@ -41,8 +49,15 @@ pub fn add_months(date: NaiveDate, months: i32) -> NaiveDate {
     NaiveDate::from_ymd_opt(year, month as u32, day).unwrap()
 }
 
-/// Subtract months from a date.
+/// Add months to a date, keeping the last day of a month on the last day of the new month,
+/// e.g. Jan 31 + 1 month = Feb 29 in 2024 and Feb 29 + 1 month = Mar 31. Other dates are
+/// clamped as in `add_months`.
+pub fn add_months_eom(date: NaiveDate, months: i32) -> NaiveDate {
+    let result = add_months(date, months);
+    if date == last_day_of_month(date) { last_day_of_month(result) } else { result }
+}
 
+/// Subtract months from a date.
 pub fn subtract_months(date: NaiveDate, months: i32) -> NaiveDate {
     add_months(date, -months)
 }
@@ -71,6 +86,34 @@ pub fn with_year_safe(date: NaiveDate, year: i32) -> NaiveDate {
     }
 }
 
+/// Where an anniversary of Feb 29 falls in a year without one.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LeapDayPolicy {
+    /// Use Feb 28, the last day of the same month.
+    #[default]
+    ClampToFeb28,
+    /// Use Mar 1, the day after Feb 28.
+    RollToMar1,
+}
+
+/// Add `years` to a date, moving Feb 29 according to the leap day policy when the new year
+/// is not a leap year.
+pub fn add_years_with_policy(date: NaiveDate, years: i32, policy: LeapDayPolicy) -> NaiveDate {
+    with_year_safe_with_policy(date, date.year() + years, policy)
+}
+
+/// Move a date to another year, moving Feb 29 according to the leap day policy when the
+/// year is not a leap year.
+pub fn with_year_safe_with_policy(date: NaiveDate, year: i32, policy: LeapDayPolicy) -> NaiveDate {
+    match NaiveDate::from_ymd_opt(year, date.month(), date.day()) {
+        Some(new_date) => new_date,
+        None => match policy {
+            LeapDayPolicy::ClampToFeb28 => with_year_safe(date, year),
+            LeapDayPolicy::RollToMar1 => NaiveDate::from_ymd_opt(year, 3, 1).unwrap(),
+        },
+    }
+}
+
 fn last_day_of_month_year(month: u32, year: i32) -> u32 {
     use chrono::NaiveDate;
     // Next month, day 0 is the last day of this month
@@ -81,6 +124,104 @@ fn last_day_of_month_year(month: u32, year: i32) -> u32 {
         .day()
 }
 
+/// Get the first day of a month on or after `date + after_days`. This is the usual
+/// "first of the month following N days" waiting-period rule: a hire on 2023-01-01 with a
+/// 60 day wait is eligible on 2023-04-01, while a wait ending exactly on the 1st is eligible that day.
+pub fn first_of_month_following(date: NaiveDate, after_days: i64) -> NaiveDate {
+    let waited = add_days(date, after_days);
+    if waited.day() == 1 {
+        waited
+    } else {
+        last_day_of_month(waited) + Duration::days(1)
+    }
+}
+
+/// A calendar span expressed as whole years, months and days.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct YearsMonthsDays {
+    pub years: u32,
+    pub months: u32,
+    pub days: u32,
+}
+
+/// Get the date of the nth anniversary of a date. Anniversaries of Feb 29 fall on Feb 28
+/// in non-leap years (the same clamping as `with_year_safe`).
+pub fn date_of_nth_anniversary(date: NaiveDate, n: i32) -> NaiveDate {
+    with_year_safe(date, date.year() + n)
+}
+
+/// Get the tenure between a start date and an as-of date as whole years, months and days.
+///
+/// Each month boundary is measured from the original start date, so a start on the 31st
+/// reaches its monthly milestones on the last day of shorter months. If `as_of` is before
+/// `start_date` the tenure is zero.
+pub fn tenure(start_date: NaiveDate, as_of: NaiveDate) -> YearsMonthsDays {
+    if as_of < start_date {
+        return YearsMonthsDays::default();
+    }
+
+    let mut total_months = (as_of.year() - start_date.year()) * 12 + as_of.month() as i32 - start_date.month() as i32;
+    if add_months(start_date, total_months) > as_of {
+        total_months -= 1;
+    }
+    let milestone = add_months(start_date, total_months);
+
+    YearsMonthsDays {
+        years: (total_months / 12) as u32,
+        months: (total_months % 12) as u32,
+        days: (as_of - milestone).num_days() as u32,
+    }
+}
+
+/// Format a date as an ISO 8601 week date, such as `2024-W15-3` for Wednesday of week 15.
+/// The year is the ISO week-numbering year, which can differ from the calendar year in the
+/// first and last days of January and December.
+pub fn format_iso_week_date(date: NaiveDate) -> String {
+    let week = date.iso_week();
+    format!("{:04}-W{:02}-{}", week.year(), week.week(), date.weekday().number_from_monday())
+}
+
+/// Parse an ISO 8601 week date in the extended `2024-W15-3` form. Returns None if the text is
+/// malformed or names a week the year doesn't have.
+pub fn parse_iso_week_date(text: &str) -> Option<NaiveDate> {
+    let bytes = text.as_bytes();
+    if !text.is_ascii() || bytes.len() != 10 || &bytes[4..6] != b"-W" || bytes[8] != b'-' {
+        return None;
+    }
+    let digits = |range: core::ops::Range<usize>| -> Option<u32> {
+        let part = &text[range];
+        if part.bytes().all(|b| b.is_ascii_digit()) { part.parse().ok() } else { None }
+    };
+    let day = match digits(9..10)? {
+        day @ 1..=7 => Weekday::try_from(day as u8 - 1).ok()?,
+        _ => return None,
+    };
+    NaiveDate::from_isoywd_opt(digits(0..4)? as i32, digits(6..8)?, day)
+}
+
+/// Whether a week is an "A" (even) or "B" (odd) week of an alternating schedule.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WeekParity {
+    Even,
+    Odd,
+}
+
+/// Get the parity of the week containing a date. Weeks are the 7-day blocks starting on the
+/// anchor date, and the anchor's week is even; weeks before the anchor alternate the same way.
+pub fn week_parity(date: NaiveDate, anchor: NaiveDate) -> WeekParity {
+    if (date - anchor).num_days().div_euclid(7) % 2 == 0 {
+        WeekParity::Even
+    } else {
+        WeekParity::Odd
+    }
+}
+
+/// Check if two dates fall in the same 14-day cycle of an alternating schedule, counting
+/// cycles from the anchor date.
+pub fn is_same_biweekly_cycle(date_a: NaiveDate, date_b: NaiveDate, anchor: NaiveDate) -> bool {
+    (date_a - anchor).num_days().div_euclid(14) == (date_b - anchor).num_days().div_euclid(14)
+}
+
 /// Return the earlier of two NaiveDateTime values.
 /// If equal, returns time1.
 pub fn earliest(time1: NaiveDateTime, time2: NaiveDateTime) -> NaiveDateTime {
@@ -118,6 +259,102 @@ pub fn latest_opt(time1: Option<NaiveDateTime>, time2: Option<NaiveDateTime>) ->
     }
 }
 
+/// Return the earliest of a list of optional NaiveDateTime values, skipping Nones.
+/// Returns None if the list is empty or every value is None.
+pub fn earliest_of(times: &[Option<NaiveDateTime>]) -> Option<NaiveDateTime> {
+    times.iter().copied().fold(None, earliest_opt)
+}
+
+/// Return the latest of a list of optional NaiveDateTime values, skipping Nones.
+/// Returns None if the list is empty or every value is None.
+pub fn latest_of(times: &[Option<NaiveDateTime>]) -> Option<NaiveDateTime> {
+    times.iter().copied().fold(None, latest_opt)
+}
+
+/// Return the earliest start of a list of ranges, or None if the list is empty.
+pub fn earliest_start(ranges: &[DateTimeRange]) -> Option<NaiveDateTime> {
+    ranges.iter().map(|r| r.start()).min()
+}
+
+/// Return the latest end of a list of ranges, or None if the list is empty.
+pub fn latest_end(ranges: &[DateTimeRange]) -> Option<NaiveDateTime> {
+    ranges.iter().map(|r| r.end()).max()
+}
+
+/// Return the range with the longest duration, or None if the list is empty.
+/// If several ranges share the longest duration, returns the first of them.
+pub fn longest(ranges: &[DateTimeRange]) -> Option<&DateTimeRange> {
+    ranges.iter().reduce(|best, r| if r.duration() > best.duration() { r } else { best })
+}
+
+/// Return the range with the shortest duration, or None if the list is empty.
+/// If several ranges share the shortest duration, returns the first of them.
+pub fn shortest(ranges: &[DateTimeRange]) -> Option<&DateTimeRange> {
+    ranges.iter().reduce(|best, r| if r.duration() < best.duration() { r } else { best })
+}
+
+/// Return the sum of the durations of a list of ranges. Overlapping time is counted once per range.
+pub fn total_duration(ranges: &[DateTimeRange]) -> Duration {
+    ranges.iter().fold(Duration::zero(), |total, r| total + r.duration())
+}
+
+/// Merge ranges that overlap or touch into a sorted list of disjoint ranges. Ranges that
+/// don't end after they start are dropped.
+pub fn merge_overlapping(ranges: &[DateTimeRange]) -> Vec<DateTimeRange> {
+    let mut sorted: Vec<&DateTimeRange> = ranges.iter().filter(|r| r.end() > r.start()).collect();
+    sorted.sort_by_key(|r| r.start());
+    let mut merged: Vec<DateTimeRange> = Vec::with_capacity(sorted.len());
+    for range in sorted {
+        match merged.last_mut() {
+            Some(last) if range.start() <= last.end() => {
+                *last = DateTimeRange::of(last.start(), max(last.end(), range.end()));
+            }
+            _ => merged.push(range.clone()),
+        }
+    }
+    merged
+}
+
+/// Move every range in a list by the same number of days. The shifted ranges are plain ranges:
+/// their prior and next ranges are found by shifting them by their own length.
+pub fn shift_all(ranges: &mut [DateRange], days: i64) {
+    let shift = Duration::days(days);
+    for range in ranges.iter_mut() {
+        *range = DateRange::new(range.start_date() + shift, range.end_date() + shift);
+    }
+}
+
+/// Return the part of each range that falls within a window, dropping ranges that are entirely
+/// outside it.
+pub fn clip_all(ranges: &[DateRange], window: &DateRange) -> Vec<DateRange> {
+    ranges
+        .iter()
+        .filter(|range| range.overlaps(window))
+        .map(|range| {
+            DateRange::new(
+                max(range.start_date(), window.start_date()),
+                min(range.end_date(), window.end_date()),
+            )
+        })
+        .collect()
+}
+
+/// Return the sum of the days in a list of ranges. Overlapping days are counted once per range.
+pub fn total_days(ranges: &[DateRange]) -> usize {
+    ranges.iter().map(DateRange::len).sum()
+}
+
+/// Returns the duration from start to end, or zero if end is before start.
+pub fn non_negative_duration(start: NaiveDateTime, end: NaiveDateTime) -> Duration {
+    max(end - start, Duration::zero())
+}
+
+/// Returns the duration of the part of a range that falls within a window. Returns zero if
+/// they don't overlap or if either ends before it starts.
+pub fn clamped_duration_within(range: &DateTimeRange, window: &DateTimeRange) -> Duration {
+    non_negative_duration(max(range.start(), window.start()), min(range.end(), window.end()))
+}
+
 /// Returns whole hours between start and end (truncating toward zero).
 pub fn duration_in_hours(start: NaiveDateTime, end: NaiveDateTime) -> i32 {
     let seconds = (end - start).num_seconds();
@@ -152,14 +389,15 @@ fn round_to_sig_figs(value: f64, sig_figs: u32) -> f64 {
     if value == 0.0 {
         return 0.0;
     }
-    let abs = value.abs();
-    let order = abs.log10().floor();
-    let scale = 10f64.powf((sig_figs as f64 - 1.0) - order);
-    (value * scale).round() / scale
+    let abs = Float::abs(value);
+    let order = Float::floor(Float::log10(abs));
+    let scale = Float::powf(10f64, (sig_figs as f64 - 1.0) - order);
+    Float::round(value * scale) / scale
 }
 
 /// Returns duration between start and end as fractional seconds (BigDecimal).
 /// Computed exactly from milliseconds: seconds = millis / 1000.
+#[cfg(feature = "bigdecimal")]
 pub fn duration_in_fractional_seconds_bd(start: NaiveDateTime, end: NaiveDateTime) -> BigDecimal {
     let millis = (end - start).num_milliseconds();
     let ms_bd = BigDecimal::from_i64(millis).unwrap();
@@ -168,20 +406,38 @@ pub fn duration_in_fractional_seconds_bd(start: NaiveDateTime, end: NaiveDateTim
 
 /// Returns duration between start and end as fractional hours (BigDecimal).
 /// Computed from whole seconds: hours = seconds / 3600.
+#[cfg(feature = "bigdecimal")]
 pub fn duration_in_fractional_hours_bd(start: NaiveDateTime, end: NaiveDateTime) -> BigDecimal {
     let secs = (end - start).num_seconds();
     let sec_bd = BigDecimal::from_i64(secs).unwrap();
     sec_bd / BigDecimal::from(3_600i32)
 }
 
+/// FNV-1a hash of the bytes. Unlike `std::hash::DefaultHasher` the result is the same on every
+/// platform, process and release, so it can be persisted.
+pub(crate) fn stable_hash(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(PRIME))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use bigdecimal::FromPrimitive as _;
     use chrono::NaiveDate;
+    #[cfg(feature = "bigdecimal")]
     use num_traits::ToPrimitive;
     use rstest::rstest;
 
+    #[rstest]
+    #[case(b"", 0xcbf2_9ce4_8422_2325)]
+    #[case(b"a", 0xaf63_dc4c_8601_ec8c)]
+    #[case(b"foobar", 0x8594_4171_f739_67e8)]
+    fn test_stable_hash(#[case] input: &[u8], #[case] expected: u64) {
+        assert_eq!(stable_hash(input), expected);
+    }
+
     #[test]
     fn test_first_day_of_month() {
         let date = first_day_of_month(NaiveDate::from_ymd_opt(2025, 8, 20).unwrap());
@@ -264,6 +520,27 @@ mod tests {
         assert_eq!(result.day(), expected_day, "Failed for {:?}", input);
     }
 
+    #[rstest]
+    #[case(NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(), 1, 2024, 2, 29)]
+    #[case(NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(), 2, 2024, 3, 31)]
+    #[case(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(), 1, 2024, 3, 31)]
+    #[case(NaiveDate::from_ymd_opt(2023, 2, 28).unwrap(), 4, 2023, 6, 30)]
+    #[case(NaiveDate::from_ymd_opt(2024, 6, 30).unwrap(), -4, 2024, 2, 29)]
+    #[case(NaiveDate::from_ymd_opt(2024, 2, 28).unwrap(), 1, 2024, 3, 28)]
+    #[case(NaiveDate::from_ymd_opt(2024, 1, 30).unwrap(), 1, 2024, 2, 29)]
+    fn test_add_months_eom(
+        #[case] input: NaiveDate,
+        #[case] months: i32,
+        #[case] expected_year: i32,
+        #[case] expected_month: u32,
+        #[case] expected_day: u32,
+    ) {
+        let result = add_months_eom(input, months);
+        assert_eq!(result.year(), expected_year, "Failed for {:?}", input);
+        assert_eq!(result.month(), expected_month, "Failed for {:?}", input);
+        assert_eq!(result.day(), expected_day, "Failed for {:?}", input);
+    }
+
     #[rstest]
     #[case(NaiveDate::from_ymd_opt(2025, 9, 20).unwrap(), 1, 2025, 8, 20)]
     #[case(NaiveDate::from_ymd_opt(2026, 2, 28).unwrap(), 2, 2025, 12, 28)]
@@ -337,6 +614,64 @@ mod tests {
         assert_eq!(y2030, NaiveDate::from_ymd_opt(2030, 8, 20).unwrap());
     }
 
+    #[rstest]
+    #[case((2028, 2, 29), 1, LeapDayPolicy::ClampToFeb28, (2029, 2, 28))]
+    #[case((2028, 2, 29), 1, LeapDayPolicy::RollToMar1, (2029, 3, 1))]
+    #[case((2028, 2, 29), -3, LeapDayPolicy::RollToMar1, (2025, 3, 1))]
+    #[case((2028, 2, 29), 4, LeapDayPolicy::RollToMar1, (2032, 2, 29))]
+    #[case((2025, 2, 28), 3, LeapDayPolicy::RollToMar1, (2028, 2, 28))]
+    fn test_add_years_with_policy(
+        #[case] input: (i32, u32, u32),
+        #[case] years: i32,
+        #[case] policy: LeapDayPolicy,
+        #[case] expected: (i32, u32, u32),
+    ) {
+        let date = NaiveDate::from_ymd_opt(input.0, input.1, input.2).unwrap();
+        let expected = NaiveDate::from_ymd_opt(expected.0, expected.1, expected.2).unwrap();
+        assert_eq!(add_years_with_policy(date, years, policy), expected);
+        assert_eq!(with_year_safe_with_policy(date, input.0 + years, policy), expected);
+    }
+
+    #[rstest]
+    #[case(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(), 60, NaiveDate::from_ymd_opt(2024, 3, 1).unwrap())]
+    #[case(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(), 59, NaiveDate::from_ymd_opt(2023, 3, 1).unwrap())]
+    #[case(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(), 60, NaiveDate::from_ymd_opt(2023, 4, 1).unwrap())]
+    #[case(NaiveDate::from_ymd_opt(2023, 12, 15).unwrap(), 30, NaiveDate::from_ymd_opt(2024, 2, 1).unwrap())]
+    #[case(NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(), 0, NaiveDate::from_ymd_opt(2023, 6, 1).unwrap())]
+    #[case(NaiveDate::from_ymd_opt(2023, 6, 2).unwrap(), 0, NaiveDate::from_ymd_opt(2023, 7, 1).unwrap())]
+    fn test_first_of_month_following(#[case] date: NaiveDate, #[case] after_days: i64, #[case] expected: NaiveDate) {
+        assert_eq!(first_of_month_following(date, after_days), expected);
+    }
+
+    #[rstest]
+    #[case(NaiveDate::from_ymd_opt(2020, 2, 29).unwrap(), 1, NaiveDate::from_ymd_opt(2021, 2, 28).unwrap())]
+    #[case(NaiveDate::from_ymd_opt(2020, 2, 29).unwrap(), 4, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())]
+    #[case(NaiveDate::from_ymd_opt(2019, 7, 15).unwrap(), 5, NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())]
+    fn test_date_of_nth_anniversary(#[case] start: NaiveDate, #[case] n: i32, #[case] expected: NaiveDate) {
+        assert_eq!(date_of_nth_anniversary(start, n), expected);
+    }
+
+    #[rstest]
+    #[case((2020, 1, 15), (2023, 4, 20), 3, 3, 5)]
+    #[case((2020, 1, 15), (2020, 1, 15), 0, 0, 0)]
+    #[case((2020, 1, 31), (2020, 2, 29), 0, 1, 0)]
+    #[case((2020, 1, 31), (2020, 3, 30), 0, 1, 30)]
+    #[case((2020, 1, 31), (2020, 3, 31), 0, 2, 0)]
+    #[case((2020, 2, 29), (2021, 2, 28), 1, 0, 0)]
+    #[case((2020, 2, 29), (2021, 2, 27), 0, 11, 29)]
+    #[case((2023, 5, 1), (2022, 5, 1), 0, 0, 0)]
+    fn test_tenure(
+        #[case] start: (i32, u32, u32),
+        #[case] as_of: (i32, u32, u32),
+        #[case] years: u32,
+        #[case] months: u32,
+        #[case] days: u32,
+    ) {
+        let start = NaiveDate::from_ymd_opt(start.0, start.1, start.2).unwrap();
+        let as_of = NaiveDate::from_ymd_opt(as_of.0, as_of.1, as_of.2).unwrap();
+        assert_eq!(tenure(start, as_of), YearsMonthsDays { years, months, days });
+    }
+
     #[test]
     fn test_earliest_and_latest() {
         use chrono::NaiveDate;
@@ -373,6 +708,134 @@ mod tests {
         assert_eq!(latest_opt(Some(t1), Some(t1)), Some(t1));
     }
 
+    #[test]
+    fn test_earliest_latest_of() {
+        let at = |h: u32| NaiveDate::from_ymd_opt(2025, 8, 20).unwrap().and_hms_opt(h, 0, 0).unwrap();
+        let punches = [None, Some(at(12)), Some(at(9)), None, Some(at(17))];
+        assert_eq!(earliest_of(&punches), Some(at(9)));
+        assert_eq!(latest_of(&punches), Some(at(17)));
+        assert_eq!(earliest_of(&[None, None]), None);
+        assert_eq!(latest_of(&[]), None);
+    }
+
+    #[test]
+    fn test_date_range_collection_helpers() {
+        let d = |m: u32, day: u32| NaiveDate::from_ymd_opt(2025, m, day).unwrap();
+        let mut ranges = [DateRange::new(d(1, 1), d(1, 7)), DateRange::new(d(1, 29), d(2, 4))];
+
+        shift_all(&mut ranges, 3);
+        assert_eq!(ranges, [DateRange::new(d(1, 4), d(1, 10)), DateRange::new(d(2, 1), d(2, 7))]);
+        assert_eq!(total_days(&ranges), 14);
+
+        let january = DateRange::new(d(1, 1), d(1, 31));
+        assert_eq!(clip_all(&ranges, &january), vec![DateRange::new(d(1, 4), d(1, 10))]);
+        let february = DateRange::new(d(2, 1), d(2, 28));
+        let late_january = DateRange::new(d(1, 25), d(2, 3));
+        assert_eq!(
+            clip_all(&ranges, &late_january),
+            vec![DateRange::new(d(2, 1), d(2, 3))]
+        );
+        assert_eq!(clip_all(&ranges, &february), vec![DateRange::new(d(2, 1), d(2, 7))]);
+        assert_eq!(total_days(&[]), 0);
+    }
+
+    #[test]
+    fn test_range_aggregates() {
+        let at = |h: u32| NaiveDate::from_ymd_opt(2025, 8, 20).unwrap().and_hms_opt(h, 0, 0).unwrap();
+        let morning = DateTimeRange::of(at(6), at(10));
+        let midday = DateTimeRange::of(at(9), at(11));
+        let evening = DateTimeRange::of(at(14), at(18));
+        let ranges = vec![morning.clone(), midday.clone(), evening.clone()];
+
+        assert_eq!(earliest_start(&ranges), Some(at(6)));
+        assert_eq!(latest_end(&ranges), Some(at(18)));
+        assert_eq!(longest(&ranges), Some(&morning)); // ties with evening, first wins
+        assert_eq!(shortest(&ranges), Some(&midday));
+        assert_eq!(total_duration(&ranges), Duration::hours(10));
+
+        assert_eq!(earliest_start(&[]), None);
+        assert_eq!(latest_end(&[]), None);
+        assert_eq!(longest(&[]), None);
+        assert_eq!(shortest(&[]), None);
+        assert_eq!(total_duration(&[]), Duration::zero());
+    }
+
+    #[rstest]
+    #[case(NaiveDate::from_ymd_opt(2024, 4, 10).unwrap(), "2024-W15-3")]
+    #[case(NaiveDate::from_ymd_opt(2024, 12, 30).unwrap(), "2025-W01-1")]
+    #[case(NaiveDate::from_ymd_opt(2021, 1, 3).unwrap(), "2020-W53-7")]
+    fn test_iso_week_date_round_trip(#[case] date: NaiveDate, #[case] text: &str) {
+        assert_eq!(format_iso_week_date(date), text);
+        assert_eq!(parse_iso_week_date(text), Some(date));
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("2024-W15")]
+    #[case("2024W153")]
+    #[case("2024-W15-0")]
+    #[case("2024-W15-8")]
+    #[case("2021-W53-1")]
+    #[case("2024-W+5-3")]
+    #[case("20x4-W15-3")]
+    #[case("2024-W15-é")]
+    fn test_parse_iso_week_date_rejects_malformed(#[case] text: &str) {
+        assert_eq!(parse_iso_week_date(text), None);
+    }
+
+    #[rstest]
+    #[case(NaiveDate::from_ymd_opt(2024, 3, 4).unwrap(), WeekParity::Even)]
+    #[case(NaiveDate::from_ymd_opt(2024, 3, 10).unwrap(), WeekParity::Even)]
+    #[case(NaiveDate::from_ymd_opt(2024, 3, 11).unwrap(), WeekParity::Odd)]
+    #[case(NaiveDate::from_ymd_opt(2024, 3, 18).unwrap(), WeekParity::Even)]
+    #[case(NaiveDate::from_ymd_opt(2024, 3, 3).unwrap(), WeekParity::Odd)]
+    #[case(NaiveDate::from_ymd_opt(2024, 2, 26).unwrap(), WeekParity::Odd)]
+    #[case(NaiveDate::from_ymd_opt(2024, 2, 25).unwrap(), WeekParity::Even)]
+    fn test_week_parity(#[case] date: NaiveDate, #[case] expected: WeekParity) {
+        let anchor = NaiveDate::from_ymd_opt(2024, 3, 4).unwrap();
+        assert_eq!(week_parity(date, anchor), expected);
+    }
+
+    #[test]
+    fn test_is_same_biweekly_cycle() {
+        let d = |day: u32| NaiveDate::from_ymd_opt(2024, 3, day).unwrap();
+        let anchor = d(4);
+        assert!(is_same_biweekly_cycle(d(4), d(17), anchor));
+        assert!(!is_same_biweekly_cycle(d(17), d(18), anchor));
+        assert!(is_same_biweekly_cycle(d(1), d(3), anchor));
+        assert!(!is_same_biweekly_cycle(d(3), d(4), anchor));
+    }
+
+    #[test]
+    fn test_merge_overlapping() {
+        let at = |h: u32| NaiveDate::from_ymd_opt(2025, 8, 20).unwrap().and_hms_opt(h, 0, 0).unwrap();
+        let ranges = vec![
+            DateTimeRange::of(at(14), at(18)),
+            DateTimeRange::of(at(9), at(11)),
+            DateTimeRange::of(at(6), at(10)),
+            DateTimeRange::of(at(11), at(12)),
+            DateTimeRange::of(at(20), at(20)),
+        ];
+        assert_eq!(
+            merge_overlapping(&ranges),
+            vec![DateTimeRange::of(at(6), at(12)), DateTimeRange::of(at(14), at(18))]
+        );
+        assert!(merge_overlapping(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_non_negative_and_clamped_durations() {
+        let at = |h: u32| NaiveDate::from_ymd_opt(2025, 8, 20).unwrap().and_hms_opt(h, 0, 0).unwrap();
+        assert_eq!(non_negative_duration(at(9), at(12)), Duration::hours(3));
+        assert_eq!(non_negative_duration(at(12), at(9)), Duration::zero());
+
+        let window = DateTimeRange::of(at(9), at(17));
+        assert_eq!(clamped_duration_within(&DateTimeRange::of(at(6), at(10)), &window), Duration::hours(1));
+        assert_eq!(clamped_duration_within(&DateTimeRange::of(at(10), at(12)), &window), Duration::hours(2));
+        assert_eq!(clamped_duration_within(&DateTimeRange::of(at(18), at(20)), &window), Duration::zero());
+        assert_eq!(clamped_duration_within(&DateTimeRange::of(at(12), at(10)), &window), Duration::zero());
+    }
+
     #[test]
     fn test_durations_whole_units() {
         use chrono::NaiveDate;
@@ -423,6 +886,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "bigdecimal")]
     fn test_bigdecimal_fractional_durations() {
         use chrono::NaiveDate;
         use chrono::NaiveDateTime;
@@ -0,0 +1,310 @@
+//! `extern "C"` functions over plain-value range structs, for callers outside Rust.
+//!
+//! Dates are passed as days since 1970-01-01 and datetimes as seconds since the unix epoch,
+//! treated as UTC. Functions that produce a range write it through an out pointer and return
+//! false, leaving the out value untouched, when the input is invalid or the result can't be
+//! represented. No function unwinds into the caller.
+
+use crate::daterange::date_range::DateRange;
+use crate::daterange::frequency::Frequency;
+use crate::datetimerange::date_time_range::DateTimeRange;
+use chrono::{Duration, NaiveDate};
+use std::panic::catch_unwind;
+
+/// Prior/next shift the range by its own length.
+pub const FREQUENCY_FIXED_LENGTH: u32 = 0;
+pub const FREQUENCY_SEMI_MONTHLY: u32 = 1;
+/// Monthly periods starting on `start_day`.
+pub const FREQUENCY_MONTHLY: u32 = 2;
+pub const FREQUENCY_QUARTERLY: u32 = 3;
+pub const FREQUENCY_SEMI_ANNUAL: u32 = 4;
+pub const FREQUENCY_ANNUAL: u32 = 5;
+
+/// A DateRange as plain values. `frequency` is one of the `FREQUENCY_*` constants and decides
+/// how prior and next ranges are found. `start_day` is only used by monthly ranges, where 0
+/// means the 1st.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FfiDateRange {
+    pub start_epoch_day: i64,
+    pub end_epoch_day: i64,
+    pub frequency: u32,
+    pub start_day: u32,
+}
+
+/// A DateTimeRange as plain values.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FfiDateTimeRange {
+    pub start_epoch_second: i64,
+    pub end_epoch_second: i64,
+}
+
+impl FfiDateRange {
+    fn to_date_range(self) -> Option<DateRange> {
+        let start = from_epoch_day(self.start_epoch_day)?;
+        let end = from_epoch_day(self.end_epoch_day)?;
+        if start > end {
+            return None;
+        }
+        let frequency = match self.frequency {
+            FREQUENCY_FIXED_LENGTH => return Some(DateRange::new(start, end)),
+            FREQUENCY_SEMI_MONTHLY => Frequency::SemiMonthly,
+            FREQUENCY_MONTHLY => Frequency::Monthly,
+            FREQUENCY_QUARTERLY => Frequency::Quarterly,
+            FREQUENCY_SEMI_ANNUAL => Frequency::SemiAnnual,
+            FREQUENCY_ANNUAL => Frequency::Annual,
+            _ => return None,
+        };
+        let range = DateRange::builder()
+            .frequency(frequency)
+            .start_day(self.start_day.max(1) as usize)
+            .containing(start)
+            .build()?;
+        (end <= range.end_date()).then_some(range)
+    }
+
+    fn with_bounds(self, range: &DateRange) -> Self {
+        Self {
+            start_epoch_day: to_epoch_day(range.start_date()),
+            end_epoch_day: to_epoch_day(range.end_date()),
+            ..self
+        }
+    }
+}
+
+impl FfiDateTimeRange {
+    fn to_date_time_range(self) -> Option<DateTimeRange> {
+        (self.start_epoch_second <= self.end_epoch_second)
+            .then(|| DateTimeRange::from_unix_seconds(self.start_epoch_second, self.end_epoch_second))
+            .flatten()
+    }
+}
+
+fn epoch() -> NaiveDate {
+    NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()
+}
+
+fn from_epoch_day(epoch_day: i64) -> Option<NaiveDate> {
+    epoch().checked_add_signed(Duration::try_days(epoch_day)?)
+}
+
+fn to_epoch_day(date: NaiveDate) -> i64 {
+    (date - epoch()).num_days()
+}
+
+/// Write a range to `out`, returning false if it can't be written.
+///
+/// # Safety
+/// `out` must be null or valid for writes.
+unsafe fn write_out(out: *mut FfiDateRange, value: Option<FfiDateRange>) -> bool {
+    match value {
+        Some(value) if !out.is_null() => {
+            // SAFETY: the caller guarantees that a non-null `out` is valid for writes.
+            unsafe { out.write(value) };
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Build a range from epoch days, snapping it to the period of `frequency` that contains the
+/// start. Fails if the end falls in a later period.
+///
+/// # Safety
+/// `out` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn date_range_new(
+    start_epoch_day: i64,
+    end_epoch_day: i64,
+    frequency: u32,
+    start_day: u32,
+    out: *mut FfiDateRange,
+) -> bool {
+    let input = FfiDateRange { start_epoch_day, end_epoch_day, frequency, start_day };
+    let value = catch_unwind(|| input.to_date_range().map(|range| input.with_bounds(&range))).ok().flatten();
+    // SAFETY: forwarded from the caller.
+    unsafe { write_out(out, value) }
+}
+
+/// Check if a range contains a date. Invalid ranges contain nothing.
+#[unsafe(no_mangle)]
+pub extern "C" fn date_range_contains(range: FfiDateRange, epoch_day: i64) -> bool {
+    catch_unwind(|| match (range.to_date_range(), from_epoch_day(epoch_day)) {
+        (Some(range), Some(date)) => range.contains_date(date),
+        _ => false,
+    })
+    .unwrap_or(false)
+}
+
+/// Check if two ranges share at least one date. Invalid ranges overlap nothing.
+#[unsafe(no_mangle)]
+pub extern "C" fn date_range_overlaps(a: FfiDateRange, b: FfiDateRange) -> bool {
+    catch_unwind(|| match (a.to_date_range(), b.to_date_range()) {
+        (Some(a), Some(b)) => a.overlaps(&b),
+        _ => false,
+    })
+    .unwrap_or(false)
+}
+
+/// Write the range before `range` to `out`.
+///
+/// # Safety
+/// `out` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn date_range_prior(range: FfiDateRange, out: *mut FfiDateRange) -> bool {
+    let value = catch_unwind(|| range.to_date_range().map(|r| range.with_bounds(&r.prior()))).ok().flatten();
+    // SAFETY: forwarded from the caller.
+    unsafe { write_out(out, value) }
+}
+
+/// Write the range after `range` to `out`.
+///
+/// # Safety
+/// `out` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn date_range_next(range: FfiDateRange, out: *mut FfiDateRange) -> bool {
+    let value = catch_unwind(|| range.to_date_range().map(|r| range.with_bounds(&r.next()))).ok().flatten();
+    // SAFETY: forwarded from the caller.
+    unsafe { write_out(out, value) }
+}
+
+/// Check if a datetime range contains a timestamp (inclusive). Invalid ranges contain nothing.
+#[unsafe(no_mangle)]
+pub extern "C" fn date_time_range_contains(range: FfiDateTimeRange, epoch_second: i64) -> bool {
+    range.to_date_time_range().is_some_and(|range| range.contains_unix(epoch_second))
+}
+
+/// Check if two datetime ranges overlap (inclusive). Invalid ranges overlap nothing.
+#[unsafe(no_mangle)]
+pub extern "C" fn date_time_range_overlaps(a: FfiDateTimeRange, b: FfiDateTimeRange) -> bool {
+    match (a.to_date_time_range(), b.to_date_time_range()) {
+        (Some(a), Some(b)) => a.overlaps(&b),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr;
+
+    fn epoch_day(y: i32, m: u32, day: u32) -> i64 {
+        to_epoch_day(NaiveDate::from_ymd_opt(y, m, day).unwrap())
+    }
+
+    fn range(start: i64, end: i64, frequency: u32, start_day: u32) -> Option<FfiDateRange> {
+        let mut out = FfiDateRange { start_epoch_day: 0, end_epoch_day: 0, frequency: 0, start_day: 0 };
+        unsafe { date_range_new(start, end, frequency, start_day, &mut out) }.then_some(out)
+    }
+
+    #[test]
+    fn new_snaps_to_frequency_and_validates_input() {
+        let month = range(epoch_day(2024, 2, 10), epoch_day(2024, 2, 29), FREQUENCY_MONTHLY, 1).unwrap();
+        assert_eq!(month.start_epoch_day, epoch_day(2024, 2, 1));
+        assert_eq!(month.end_epoch_day, epoch_day(2024, 2, 29));
+
+        assert!(range(epoch_day(2024, 2, 10), epoch_day(2024, 2, 1), FREQUENCY_FIXED_LENGTH, 0).is_none());
+        assert!(range(0, 6, 99, 0).is_none());
+        assert!(range(i64::MAX, i64::MAX, FREQUENCY_FIXED_LENGTH, 0).is_none());
+        assert!(!unsafe { date_range_new(0, 6, FREQUENCY_FIXED_LENGTH, 0, ptr::null_mut()) });
+    }
+
+    #[test]
+    fn new_snaps_mid_period_input_to_the_containing_period() {
+        let snapped = |start: i64, end: i64, frequency: u32, start_day: u32| {
+            let out = range(start, end, frequency, start_day)?;
+            assert!(date_range_contains(out, epoch_day(2024, 2, 12)));
+            Some((out.start_epoch_day, out.end_epoch_day))
+        };
+        let (feb_10, feb_12, feb_20) = (epoch_day(2024, 2, 10), epoch_day(2024, 2, 12), epoch_day(2024, 2, 20));
+
+        assert_eq!(snapped(feb_10, feb_20, FREQUENCY_FIXED_LENGTH, 0), Some((feb_10, feb_20)));
+        assert_eq!(
+            snapped(feb_10, feb_12, FREQUENCY_SEMI_MONTHLY, 0),
+            Some((epoch_day(2024, 2, 1), epoch_day(2024, 2, 15)))
+        );
+        assert_eq!(
+            snapped(feb_10, feb_20, FREQUENCY_MONTHLY, 0),
+            Some((epoch_day(2024, 2, 1), epoch_day(2024, 2, 29)))
+        );
+        assert_eq!(
+            snapped(feb_10, feb_12, FREQUENCY_MONTHLY, 16),
+            Some((epoch_day(2024, 1, 16), epoch_day(2024, 2, 15)))
+        );
+        assert_eq!(
+            snapped(feb_10, feb_20, FREQUENCY_QUARTERLY, 0),
+            Some((epoch_day(2024, 1, 1), epoch_day(2024, 3, 31)))
+        );
+        assert_eq!(
+            snapped(feb_10, feb_20, FREQUENCY_SEMI_ANNUAL, 0),
+            Some((epoch_day(2024, 1, 1), epoch_day(2024, 6, 30)))
+        );
+        assert_eq!(
+            snapped(feb_10, feb_20, FREQUENCY_ANNUAL, 0),
+            Some((epoch_day(2024, 1, 1), epoch_day(2024, 12, 31)))
+        );
+    }
+
+    #[test]
+    fn new_rejects_input_spanning_more_than_one_period() {
+        let (feb_10, feb_20) = (epoch_day(2024, 2, 10), epoch_day(2024, 2, 20));
+        assert!(range(feb_10, feb_20, FREQUENCY_SEMI_MONTHLY, 0).is_none());
+        assert!(range(feb_10, feb_20, FREQUENCY_MONTHLY, 16).is_none());
+        assert!(range(feb_10, epoch_day(2024, 3, 1), FREQUENCY_MONTHLY, 1).is_none());
+        assert!(range(feb_10, epoch_day(2024, 4, 1), FREQUENCY_QUARTERLY, 0).is_none());
+        assert!(range(feb_10, epoch_day(2024, 7, 1), FREQUENCY_SEMI_ANNUAL, 0).is_none());
+        assert!(range(feb_10, epoch_day(2025, 1, 1), FREQUENCY_ANNUAL, 0).is_none());
+        assert!(range(feb_10, feb_20, FREQUENCY_MONTHLY, 40).is_none());
+    }
+
+    #[test]
+    fn prior_next_contains_and_overlaps() {
+        let quarter = range(epoch_day(2024, 1, 1), epoch_day(2024, 3, 31), FREQUENCY_QUARTERLY, 0).unwrap();
+        let mut next = quarter;
+        assert!(unsafe { date_range_next(quarter, &mut next) });
+        assert_eq!((next.start_epoch_day, next.end_epoch_day), (epoch_day(2024, 4, 1), epoch_day(2024, 6, 30)));
+
+        let mut prior = quarter;
+        assert!(unsafe { date_range_prior(quarter, &mut prior) });
+        assert_eq!(prior.start_epoch_day, epoch_day(2023, 10, 1));
+
+        assert!(date_range_contains(quarter, epoch_day(2024, 2, 29)));
+        assert!(!date_range_contains(quarter, epoch_day(2024, 4, 1)));
+        assert!(!date_range_overlaps(quarter, next));
+        assert!(date_range_overlaps(quarter, range(epoch_day(2024, 3, 31), epoch_day(2024, 4, 6), 0, 0).unwrap()));
+    }
+
+    #[test]
+    fn prior_at_the_edge_of_the_calendar_fails_without_unwinding() {
+        let first = range(to_epoch_day(NaiveDate::MIN), to_epoch_day(NaiveDate::MIN) + 6, 0, 0).unwrap();
+        let mut out = first;
+        assert!(!unsafe { date_range_prior(first, &mut out) });
+        assert_eq!(out, first);
+    }
+
+    #[test]
+    fn contains_and_overlaps_at_the_edge_of_the_calendar_fail_without_unwinding() {
+        let last = to_epoch_day(NaiveDate::MAX);
+        let edge = FfiDateRange {
+            start_epoch_day: last - 30,
+            end_epoch_day: last,
+            frequency: FREQUENCY_MONTHLY,
+            start_day: 5,
+        };
+        assert!(!date_range_contains(edge, last));
+        assert!(!date_range_overlaps(edge, edge));
+    }
+
+    #[test]
+    fn date_time_ranges() {
+        let shift = FfiDateTimeRange { start_epoch_second: 1_000, end_epoch_second: 2_000 };
+        assert!(date_time_range_contains(shift, 2_000));
+        assert!(!date_time_range_contains(shift, 2_001));
+        let next_shift = FfiDateTimeRange { start_epoch_second: 2_000, end_epoch_second: 3_000 };
+        assert!(date_time_range_overlaps(shift, next_shift));
+
+        let reversed = FfiDateTimeRange { start_epoch_second: 2_000, end_epoch_second: 1_000 };
+        assert!(!date_time_range_contains(reversed, 1_500));
+    }
+}
@@ -0,0 +1 @@
+pub mod c_api;
@@ -1,9 +1,26 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+pub mod calendar;
 pub mod daterange;
 pub mod datetimerange;
 pub mod dateutils;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "prost")]
+pub mod protobuf;
+#[cfg(feature = "serde")]
+pub mod serde_formats;
+#[cfg(any(feature = "diesel", feature = "rusqlite", feature = "sqlx-postgres"))]
+pub mod sql;
 pub mod timerange;
+pub mod timeutils;
 
+pub use calendar::business_hours::BusinessHours;
+pub use calendar::holiday_calendar::HolidayCalendar;
 pub use daterange::date_range::DateRange;
 pub use datetimerange::date_time_range::DateTimeRange;
 pub use dateutils::date_utils::*;
 pub use timerange::time_range::TimeRange;
+pub use timeutils::time_utils::*;
@@ -0,0 +1,257 @@
+use crate::daterange::date_range::DateRange;
+use crate::datetimerange::date_time_range::DateTimeRange;
+use crate::protobuf::google_type::{Date, Interval};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime};
+use prost_types::Timestamp;
+use core::error::Error;
+use core::fmt;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Errors returned when converting from the protobuf types.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProtoError {
+    /// A `Date` was partial or not a day of the calendar.
+    InvalidDate(Date),
+    /// A `Timestamp` was outside the range of `NaiveDateTime`.
+    InvalidTimestamp(Timestamp),
+    /// An `Interval` had no start or no end time.
+    UnboundedInterval,
+    /// An `Interval` converted to a `DateRange` did not start and end at midnight UTC.
+    PartialDays(Interval),
+    /// The end was before the start.
+    EndBeforeStart,
+}
+
+impl fmt::Display for ProtoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProtoError::InvalidDate(date) => {
+                write!(f, "invalid date: {:04}-{:02}-{:02}", date.year, date.month, date.day)
+            }
+            ProtoError::InvalidTimestamp(timestamp) => write!(f, "timestamp out of range: {}", timestamp),
+            ProtoError::UnboundedInterval => write!(f, "interval has no start or no end"),
+            ProtoError::PartialDays(_) => write!(f, "interval does not start and end at midnight UTC"),
+            ProtoError::EndBeforeStart => write!(f, "the end of the range is before the start"),
+        }
+    }
+}
+
+impl Error for ProtoError {}
+
+impl From<NaiveDate> for Date {
+    fn from(date: NaiveDate) -> Self {
+        Date { year: date.year(), month: date.month() as i32, day: date.day() as i32 }
+    }
+}
+
+impl TryFrom<Date> for NaiveDate {
+    type Error = ProtoError;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        let (Ok(month), Ok(day)) = (u32::try_from(date.month), u32::try_from(date.day)) else {
+            return Err(ProtoError::InvalidDate(date));
+        };
+        match NaiveDate::from_ymd_opt(date.year, month, day) {
+            Some(naive) if date.year != 0 => Ok(naive),
+            _ => Err(ProtoError::InvalidDate(date)),
+        }
+    }
+}
+
+/// The first and last dates of `range`, both inclusive.
+pub fn date_range_dates(range: &DateRange) -> (Date, Date) {
+    (range.start_date().into(), range.end_date().into())
+}
+
+/// The range from `start` through `end`, both inclusive.
+pub fn date_range_from_dates(start: Date, end: Date) -> Result<DateRange, ProtoError> {
+    let (start, end): (NaiveDate, NaiveDate) = (start.try_into()?, end.try_into()?);
+    if end < start {
+        return Err(ProtoError::EndBeforeStart);
+    }
+    Ok(DateRange::new(start, end))
+}
+
+/// `date_time` as a `Timestamp`, reading it as UTC.
+pub fn timestamp_from_date_time(date_time: NaiveDateTime) -> Timestamp {
+    let utc = date_time.and_utc();
+    let mut timestamp = Timestamp { seconds: utc.timestamp(), nanos: utc.timestamp_subsec_nanos() as i32 };
+    // A leap second has more than a second of nanos.
+    timestamp.normalize();
+    timestamp
+}
+
+/// `timestamp` as a UTC `NaiveDateTime`.
+pub fn date_time_from_timestamp(timestamp: &Timestamp) -> Result<NaiveDateTime, ProtoError> {
+    let normalized = timestamp.normalized();
+    DateTime::from_timestamp(normalized.seconds, normalized.nanos as u32)
+        .map(|utc| utc.naive_utc())
+        .ok_or(ProtoError::InvalidTimestamp(*timestamp))
+}
+
+/// The whole days of the range, from midnight UTC on the first day to midnight UTC after the
+/// last day.
+impl From<DateRange> for Interval {
+    fn from(range: DateRange) -> Self {
+        let start = timestamp_from_date_time(range.start_date().and_time(NaiveTime::MIN));
+        // Counted in seconds so that a range ending on `NaiveDate::MAX` still has an end.
+        let mut end = timestamp_from_date_time(range.end_date().and_time(NaiveTime::MIN));
+        end.seconds += SECONDS_PER_DAY;
+        Interval { start_time: Some(start), end_time: Some(end) }
+    }
+}
+
+/// The days of an interval that starts and ends at midnight UTC. The day the interval ends on is
+/// not part of the range.
+impl TryFrom<&Interval> for DateRange {
+    type Error = ProtoError;
+
+    fn try_from(interval: &Interval) -> Result<Self, Self::Error> {
+        let (Some(start), Some(end)) = (interval.start_time, interval.end_time) else {
+            return Err(ProtoError::UnboundedInterval);
+        };
+        let (start, mut end) = (start.normalized(), end.normalized());
+        let midnight = |timestamp: &Timestamp| timestamp.nanos == 0 && timestamp.seconds % SECONDS_PER_DAY == 0;
+        if !midnight(&start) || !midnight(&end) {
+            return Err(ProtoError::PartialDays(*interval));
+        }
+        end.seconds -= SECONDS_PER_DAY;
+        let (start, end) = (date_time_from_timestamp(&start)?, date_time_from_timestamp(&end)?);
+        if end < start {
+            return Err(ProtoError::EndBeforeStart);
+        }
+        Ok(DateRange::new(start.date(), end.date()))
+    }
+}
+
+impl From<&DateTimeRange> for Interval {
+    fn from(range: &DateTimeRange) -> Self {
+        Interval {
+            start_time: Some(timestamp_from_date_time(range.start())),
+            end_time: Some(timestamp_from_date_time(range.end())),
+        }
+    }
+}
+
+impl TryFrom<&Interval> for DateTimeRange {
+    type Error = ProtoError;
+
+    fn try_from(interval: &Interval) -> Result<Self, Self::Error> {
+        let (Some(start), Some(end)) = (interval.start_time, interval.end_time) else {
+            return Err(ProtoError::UnboundedInterval);
+        };
+        let (start, end) = (date_time_from_timestamp(&start)?, date_time_from_timestamp(&end)?);
+        if end < start {
+            return Err(ProtoError::EndBeforeStart);
+        }
+        Ok(DateTimeRange::of(start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    fn ts(seconds: i64, nanos: i32) -> Timestamp {
+        Timestamp { seconds, nanos }
+    }
+
+    #[test]
+    fn dates_convert_and_partial_dates_are_rejected() {
+        assert_eq!(Date::from(d(2024, 2, 29)), Date { year: 2024, month: 2, day: 29 });
+        assert_eq!(NaiveDate::try_from(Date { year: 2024, month: 2, day: 29 }), Ok(d(2024, 2, 29)));
+        for date in [
+            Date { year: 0, month: 2, day: 29 },
+            Date { year: 2024, month: 0, day: 1 },
+            Date { year: 2024, month: 2, day: 0 },
+            Date { year: 2023, month: 2, day: 29 },
+            Date { year: 2024, month: -1, day: 1 },
+        ] {
+            assert_eq!(NaiveDate::try_from(date), Err(ProtoError::InvalidDate(date)));
+        }
+    }
+
+    #[test]
+    fn date_ranges_convert_to_inclusive_dates() {
+        let range = DateRange::new(d(2024, 3, 1), d(2024, 3, 31));
+        let (start, end) = date_range_dates(&range);
+        assert_eq!((start.day, end.day), (1, 31));
+        let round_trip = date_range_from_dates(start, end).unwrap();
+        assert_eq!((round_trip.start_date(), round_trip.end_date()), (d(2024, 3, 1), d(2024, 3, 31)));
+        assert_eq!(date_range_from_dates(end, start).unwrap_err(), ProtoError::EndBeforeStart);
+    }
+
+    #[test]
+    fn date_ranges_are_intervals_of_whole_days() {
+        let interval = Interval::from(DateRange::new(d(2024, 3, 1), d(2024, 3, 31)));
+        assert_eq!(interval.start_time, Some(ts(1_709_251_200, 0)));
+        assert_eq!(interval.end_time, Some(ts(1_711_929_600, 0)));
+        let range = DateRange::try_from(&interval).unwrap();
+        assert_eq!((range.start_date(), range.end_date()), (d(2024, 3, 1), d(2024, 3, 31)));
+
+        let day = DateRange::try_from(&Interval::from(DateRange::new(d(1969, 12, 31), d(1969, 12, 31)))).unwrap();
+        assert_eq!((day.start_date(), day.end_date()), (d(1969, 12, 31), d(1969, 12, 31)));
+
+        let last = DateRange::new(NaiveDate::MAX, NaiveDate::MAX);
+        let range = DateRange::try_from(&Interval::from(last)).unwrap();
+        assert_eq!((range.start_date(), range.end_date()), (NaiveDate::MAX, NaiveDate::MAX));
+    }
+
+    #[test]
+    fn intervals_that_are_not_whole_days_are_rejected() {
+        let midnight = ts(1_709_251_200, 0);
+        let partial = Interval { start_time: Some(midnight), end_time: Some(ts(1_709_251_200 + 3600, 0)) };
+        assert_eq!(DateRange::try_from(&partial), Err(ProtoError::PartialDays(partial)));
+        let partial = Interval { start_time: Some(midnight), end_time: Some(ts(1_709_337_600, 1)) };
+        assert_eq!(DateRange::try_from(&partial), Err(ProtoError::PartialDays(partial)));
+
+        let empty = Interval { start_time: Some(midnight), end_time: Some(midnight) };
+        assert_eq!(DateRange::try_from(&empty).unwrap_err(), ProtoError::EndBeforeStart);
+        let open = Interval { start_time: Some(midnight), end_time: None };
+        assert_eq!(DateRange::try_from(&open).unwrap_err(), ProtoError::UnboundedInterval);
+    }
+
+    #[test]
+    fn date_time_ranges_keep_their_bounds() {
+        let start = d(2024, 3, 10).and_hms_milli_opt(9, 0, 0, 250).unwrap();
+        let end = d(2024, 3, 10).and_hms_opt(17, 30, 0).unwrap();
+        let interval = Interval::from(&DateTimeRange::of(start, end));
+        assert_eq!(interval.start_time, Some(ts(1_710_061_200, 250_000_000)));
+        assert_eq!(interval.end_time, Some(ts(1_710_091_800, 0)));
+        let range = DateTimeRange::try_from(&interval).unwrap();
+        assert_eq!((range.start(), range.end()), (start, end));
+
+        let unnormalized = Interval { start_time: Some(ts(1_710_061_201, -750_000_000)), ..interval };
+        assert_eq!(DateTimeRange::try_from(&unnormalized).unwrap().start(), start);
+    }
+
+    #[test]
+    fn invalid_intervals_are_rejected() {
+        let start = ts(1_710_061_200, 0);
+        let inverted = Interval { start_time: Some(start), end_time: Some(ts(1_710_061_199, 0)) };
+        assert_eq!(DateTimeRange::try_from(&inverted).unwrap_err(), ProtoError::EndBeforeStart);
+        let open = Interval { start_time: None, end_time: Some(start) };
+        assert_eq!(DateTimeRange::try_from(&open).unwrap_err(), ProtoError::UnboundedInterval);
+        let far = ts(i64::MAX, 0);
+        let out_of_range = Interval { start_time: Some(start), end_time: Some(far) };
+        assert_eq!(DateTimeRange::try_from(&out_of_range).unwrap_err(), ProtoError::InvalidTimestamp(far));
+    }
+
+    #[test]
+    fn messages_use_the_google_type_field_numbers() {
+        assert_eq!(Date { year: 2024, month: 3, day: 1 }.encode_to_vec(), [0x08, 0xe8, 0x0f, 0x10, 0x03, 0x18, 0x01]);
+
+        let interval = Interval::from(&DateTimeRange::of(
+            d(1970, 1, 1).and_hms_opt(0, 0, 1).unwrap(),
+            d(1970, 1, 1).and_hms_opt(0, 0, 2).unwrap(),
+        ));
+        assert_eq!(interval.encode_to_vec(), [0x0a, 0x02, 0x08, 0x01, 0x12, 0x02, 0x08, 0x02]);
+        assert_eq!(Interval::decode(interval.encode_to_vec().as_slice()).unwrap(), interval);
+    }
+}
@@ -0,0 +1,23 @@
+use prost_types::Timestamp;
+
+/// `google.type.Date`: a calendar date. Zero fields mark a partial date, such as a birthday
+/// without a year.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, prost::Message)]
+pub struct Date {
+    #[prost(int32, tag = "1")]
+    pub year: i32,
+    #[prost(int32, tag = "2")]
+    pub month: i32,
+    #[prost(int32, tag = "3")]
+    pub day: i32,
+}
+
+/// `google.type.Interval`: the time from `start_time`, inclusive, to `end_time`, exclusive. A
+/// missing bound leaves that side unbounded.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, prost::Message)]
+pub struct Interval {
+    #[prost(message, optional, tag = "1")]
+    pub start_time: Option<Timestamp>,
+    #[prost(message, optional, tag = "2")]
+    pub end_time: Option<Timestamp>,
+}
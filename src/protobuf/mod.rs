@@ -0,0 +1,15 @@
+//! Conversions between the range types and the well-known protobuf types, behind the `prost`
+//! feature.
+//!
+//! `google.type.Date` and `google.type.Interval` are not part of `prost-types`, so
+//! `google_type` defines them with the same field numbers, ready to use from `prost-build`
+//! through `extern_path`. `google.protobuf.Timestamp` is `prost_types::Timestamp`.
+//!
+//! A `DateRange` is a pair of inclusive `Date`s, or an `Interval` from midnight UTC on its first
+//! day to midnight UTC after its last day, since an `Interval`'s end is exclusive. A
+//! `DateTimeRange` is an `Interval` with the same start and end, read as UTC, so the two have
+//! the same duration. Intervals without a start or an end, and dates with a zero year, month or
+//! day, have no range to convert to and are rejected.
+
+pub mod convert;
+pub mod google_type;
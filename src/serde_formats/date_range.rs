@@ -0,0 +1,104 @@
+use crate::daterange::date_range::DateRange;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for DateRange {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        fields::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DateRange {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        fields::deserialize(deserializer)
+    }
+}
+
+/// The default representation, `{"start": "2024-03-01", "end": "2024-03-31"}`.
+pub mod fields {
+    use crate::daterange::date_range::DateRange;
+    use chrono::NaiveDate;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Bounds {
+        start: NaiveDate,
+        end: NaiveDate,
+    }
+
+    pub fn serialize<S: Serializer>(range: &DateRange, serializer: S) -> Result<S::Ok, S::Error> {
+        Bounds { start: range.start_date(), end: range.end_date() }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateRange, D::Error> {
+        let Bounds { start, end } = Bounds::deserialize(deserializer)?;
+        if end < start {
+            return Err(D::Error::custom("the end of the range is before the start"));
+        }
+        Ok(DateRange::new(start, end))
+    }
+}
+
+/// An ISO 8601 interval string, `"2024-03-01/2024-03-31"`, as written by `Display` and read by
+/// `FromStr`.
+pub mod interval {
+    use crate::daterange::date_range::DateRange;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use alloc::string::String;
+
+    pub fn serialize<S: Serializer>(range: &DateRange, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(range)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateRange, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::daterange::date_range::DateRange;
+    use crate::daterange::monthly_date_range::MonthlyDateRange;
+    use chrono::NaiveDate;
+    use serde::{Deserialize, Serialize};
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Interval(#[serde(with = "super::interval")] DateRange);
+
+    #[test]
+    fn serializes_the_bounds() {
+        let march = MonthlyDateRange::with_end_date_on_first(d(2024, 3, 31));
+        let json = serde_json::to_string(&march).unwrap();
+        assert_eq!(json, r#"{"start":"2024-03-01","end":"2024-03-31"}"#);
+
+        let parsed: DateRange = serde_json::from_str(&json).unwrap();
+        assert!(parsed.same_bounds_as(&march));
+        assert_eq!(parsed.frequency(), None);
+    }
+
+    #[test]
+    fn rejects_inverted_and_malformed_bounds() {
+        assert!(serde_json::from_str::<DateRange>(r#"{"start":"2024-03-31","end":"2024-03-01"}"#).is_err());
+        assert!(serde_json::from_str::<DateRange>(r#"{"start":"2024-03-01"}"#).is_err());
+        assert!(serde_json::from_str::<DateRange>(r#"{"start":"2024-03-01","end":"March"}"#).is_err());
+        let day: DateRange = serde_json::from_str(r#"{"start":"2024-03-01","end":"2024-03-01"}"#).unwrap();
+        assert_eq!(day.len(), 1);
+    }
+
+    #[test]
+    fn interval_strings_round_trip() {
+        let week = Interval(DateRange::new(d(2024, 3, 4), d(2024, 3, 10)));
+        let json = serde_json::to_string(&week).unwrap();
+        assert_eq!(json, r#""2024-03-04/2024-03-10""#);
+        assert_eq!(serde_json::from_str::<Interval>(&json).unwrap(), week);
+
+        let error = serde_json::from_str::<Interval>(r#""2024-03-10/2024-03-04""#).unwrap_err();
+        assert!(error.to_string().starts_with("invalid interval: 2024-03-10/2024-03-04"), "{}", error);
+        assert!(serde_json::from_str::<Interval>(r#"{"start":"2024-03-04","end":"2024-03-10"}"#).is_err());
+    }
+}
@@ -0,0 +1,204 @@
+use crate::datetimerange::date_time_range::DateTimeRange;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for DateTimeRange {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        fields::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DateTimeRange {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        fields::deserialize(deserializer)
+    }
+}
+
+/// The default representation, `{"start": "2024-03-10T09:00:00", "end": "2024-03-10T17:30:00"}`.
+pub mod fields {
+    use crate::datetimerange::date_time_range::DateTimeRange;
+    use chrono::NaiveDateTime;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Bounds {
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+    }
+
+    pub fn serialize<S: Serializer>(range: &DateTimeRange, serializer: S) -> Result<S::Ok, S::Error> {
+        Bounds { start: range.start(), end: range.end() }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTimeRange, D::Error> {
+        let Bounds { start, end } = Bounds::deserialize(deserializer)?;
+        if end < start {
+            return Err(D::Error::custom("the end of the range is before the start"));
+        }
+        Ok(DateTimeRange::of(start, end))
+    }
+}
+
+/// An ISO 8601 interval string, `"2024-03-10T09:00:00/2024-03-10T17:30:00"`, with fractional
+/// seconds only when present.
+pub mod interval {
+    use crate::datetimerange::date_time_range::DateTimeRange;
+    use chrono::NaiveDateTime;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use alloc::string::String;
+
+    const ISO_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+    pub fn serialize<S: Serializer>(range: &DateTimeRange, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{}/{}", range.start().format(ISO_FORMAT), range.end().format(ISO_FORMAT)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTimeRange, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        let invalid = || D::Error::custom("expected an interval such as 2024-03-10T09:00:00/2024-03-10T17:30:00");
+        let (start, end) = value.split_once('/').ok_or_else(invalid)?;
+        let start: NaiveDateTime = start.parse().map_err(|_| invalid())?;
+        let end: NaiveDateTime = end.parse().map_err(|_| invalid())?;
+        if end < start {
+            return Err(D::Error::custom("the end of the range is before the start"));
+        }
+        Ok(DateTimeRange::of(start, end))
+    }
+}
+
+/// Unix timestamps in seconds, `{"start": 1710061200, "end": 1710090000}`, treating the range
+/// as UTC. Fractional seconds are truncated.
+pub mod epoch_seconds {
+    use crate::datetimerange::date_time_range::DateTimeRange;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Bounds {
+        start: i64,
+        end: i64,
+    }
+
+    pub fn serialize<S: Serializer>(range: &DateTimeRange, serializer: S) -> Result<S::Ok, S::Error> {
+        Bounds { start: range.start().and_utc().timestamp(), end: range.end().and_utc().timestamp() }
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTimeRange, D::Error> {
+        let Bounds { start, end } = Bounds::deserialize(deserializer)?;
+        if end < start {
+            return Err(D::Error::custom("the end of the range is before the start"));
+        }
+        DateTimeRange::from_unix_seconds(start, end).ok_or_else(|| D::Error::custom("timestamp out of range"))
+    }
+}
+
+/// Unix timestamps in milliseconds, `{"start": 1710061200000, "end": 1710090000250}`, treating
+/// the range as UTC. Sub-millisecond precision is truncated.
+pub mod epoch_millis {
+    use crate::datetimerange::date_time_range::DateTimeRange;
+    use chrono::DateTime;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Bounds {
+        start: i64,
+        end: i64,
+    }
+
+    pub fn serialize<S: Serializer>(range: &DateTimeRange, serializer: S) -> Result<S::Ok, S::Error> {
+        let (start, end) = range.to_unix_millis();
+        Bounds { start, end }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTimeRange, D::Error> {
+        let Bounds { start, end } = Bounds::deserialize(deserializer)?;
+        if end < start {
+            return Err(D::Error::custom("the end of the range is before the start"));
+        }
+        let out_of_range = || D::Error::custom("timestamp out of range");
+        let start = DateTime::from_timestamp_millis(start).ok_or_else(out_of_range)?.naive_utc();
+        let end = DateTime::from_timestamp_millis(end).ok_or_else(out_of_range)?.naive_utc();
+        Ok(DateTimeRange::of(start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::datetimerange::date_time_range::DateTimeRange;
+    use chrono::{Duration, NaiveDate, NaiveDateTime};
+    use serde::{Deserialize, Serialize};
+
+    fn dt(y: i32, m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, min, 0).unwrap()
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Interval(#[serde(with = "super::interval")] DateTimeRange);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Event {
+        #[serde(with = "super::epoch_seconds")]
+        seconds: DateTimeRange,
+        #[serde(with = "super::epoch_millis")]
+        millis: DateTimeRange,
+    }
+
+    #[test]
+    fn serializes_the_bounds_without_an_offset() {
+        let shift = DateTimeRange::of(dt(2024, 3, 10, 9, 0), dt(2024, 3, 10, 17, 30) + Duration::milliseconds(250));
+        let json = serde_json::to_string(&shift).unwrap();
+        assert_eq!(json, r#"{"start":"2024-03-10T09:00:00","end":"2024-03-10T17:30:00.250"}"#);
+        assert_eq!(serde_json::from_str::<DateTimeRange>(&json).unwrap(), shift);
+    }
+
+    #[test]
+    fn rejects_inverted_bounds() {
+        let json = r#"{"start":"2024-03-10T17:00:00","end":"2024-03-10T09:00:00"}"#;
+        assert!(serde_json::from_str::<DateTimeRange>(json).is_err());
+        assert!(serde_json::from_str::<DateTimeRange>(r#"{"start":"2024-03-10T17:00:00Z","end":"x"}"#).is_err());
+    }
+
+    #[test]
+    fn interval_strings_round_trip() {
+        let end = dt(2024, 3, 11, 6, 0) + Duration::milliseconds(5);
+        let shift = Interval(DateTimeRange::of(dt(2024, 3, 10, 22, 0), end));
+        let json = serde_json::to_string(&shift).unwrap();
+        assert_eq!(json, r#""2024-03-10T22:00:00/2024-03-11T06:00:00.005""#);
+        assert_eq!(serde_json::from_str::<Interval>(&json).unwrap(), shift);
+
+        let inverted = r#""2024-03-11T06:00:00/2024-03-10T22:00:00""#;
+        for bad in [inverted, r#""2024-03-10T22:00:00""#, r#""2024-03-10/2024-03-11""#] {
+            assert!(serde_json::from_str::<Interval>(bad).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn epoch_timestamps_round_trip_as_utc() {
+        let range = DateTimeRange::of(dt(2024, 3, 10, 9, 0), dt(2024, 3, 10, 17, 0) + Duration::milliseconds(250));
+        let event = Event { seconds: range.truncate_to_seconds(), millis: range.clone() };
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(
+            json,
+            r#"{"seconds":{"start":1710061200,"end":1710090000},"millis":{"start":1710061200000,"end":1710090000250}}"#
+        );
+        assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+
+        let fractional = Event { seconds: range.clone(), millis: range };
+        let parsed: Event = serde_json::from_str(&serde_json::to_string(&fractional).unwrap()).unwrap();
+        assert_eq!(parsed.seconds.end(), dt(2024, 3, 10, 17, 0));
+    }
+
+    #[test]
+    fn epoch_timestamps_reject_inverted_and_out_of_range_bounds() {
+        let millis = r#"{"start":0,"end":0}"#;
+        for seconds in [r#"{"start":10,"end":5}"#, r#"{"start":0,"end":9223372036854775807}"#] {
+            let json = format!(r#"{{"seconds":{},"millis":{}}}"#, seconds, millis);
+            assert!(serde_json::from_str::<Event>(&json).is_err(), "{}", seconds);
+        }
+        let json = r#"{"seconds":{"start":0,"end":0},"millis":{"start":5,"end":-5}}"#;
+        assert!(serde_json::from_str::<Event>(json).is_err());
+    }
+}
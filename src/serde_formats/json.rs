@@ -0,0 +1,65 @@
+use crate::daterange::date_range::DateRange;
+use crate::datetimerange::date_time_range::DateTimeRange;
+use serde::Deserialize;
+use serde_json::{Error, Value};
+
+impl DateRange {
+    /// Convert the range to its canonical JSON shape, such as
+    /// `{"start": "2024-03-01", "end": "2024-03-31"}`.
+    pub fn to_json_value(&self) -> Value {
+        // Bounds always serialize, so this can't fail.
+        serde_json::to_value(self).unwrap()
+    }
+
+    /// Read a range from its canonical JSON shape. Fails if a bound is missing or malformed, or
+    /// the end is before the start.
+    pub fn from_json_value(value: &Value) -> Result<DateRange, Error> {
+        DateRange::deserialize(value)
+    }
+}
+
+impl DateTimeRange {
+    /// Convert the range to its canonical JSON shape, such as
+    /// `{"start": "2024-03-10T09:00:00", "end": "2024-03-10T17:30:00"}`.
+    pub fn to_json_value(&self) -> Value {
+        // Bounds always serialize, so this can't fail.
+        serde_json::to_value(self).unwrap()
+    }
+
+    /// Read a range from its canonical JSON shape. Fails if a bound is missing or malformed, or
+    /// the end is before the start.
+    pub fn from_json_value(value: &Value) -> Result<DateTimeRange, Error> {
+        DateTimeRange::deserialize(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::daterange::date_range::DateRange;
+    use crate::datetimerange::date_time_range::DateTimeRange;
+    use chrono::NaiveDate;
+    use serde_json::json;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).expect("invalid date")
+    }
+
+    #[test]
+    fn date_ranges_round_trip_through_json_values() {
+        let range = DateRange::new(d(2024, 3, 5), d(2024, 3, 9));
+        let value = range.to_json_value();
+        assert_eq!(value, json!({"start": "2024-03-05", "end": "2024-03-09"}));
+        assert_eq!(DateRange::from_json_value(&value).unwrap(), range);
+        assert!(DateRange::from_json_value(&json!({"start": "2024-03-09", "end": "2024-03-05"})).is_err());
+        assert!(DateRange::from_json_value(&json!("2024-03-05/2024-03-09")).is_err());
+    }
+
+    #[test]
+    fn date_time_ranges_round_trip_through_json_values() {
+        let range = DateTimeRange::all_day(d(2024, 3, 5));
+        let value = range.to_json_value();
+        assert_eq!(value, json!({"start": "2024-03-05T00:00:00", "end": "2024-03-06T00:00:00"}));
+        assert_eq!(DateTimeRange::from_json_value(&value).unwrap(), range);
+        assert!(DateTimeRange::from_json_value(&json!({"start": "2024-03-05T00:00:00"})).is_err());
+    }
+}
@@ -0,0 +1,106 @@
+use crate::daterange::date_range::DateRange;
+use crate::datetimerange::date_time_range::DateTimeRange;
+use crate::timerange::time_range::TimeRange;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use alloc::borrow::Cow;
+
+impl JsonSchema for DateRange {
+    fn schema_name() -> Cow<'static, str> {
+        "DateRange".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "date_range::DateRange".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        bounds::<NaiveDate>(generator, "An inclusive range of dates. The end is not before the start.")
+    }
+}
+
+impl JsonSchema for DateTimeRange {
+    fn schema_name() -> Cow<'static, str> {
+        "DateTimeRange".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "date_range::DateTimeRange".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        bounds::<NaiveDateTime>(generator, "A range of datetimes without an offset. The end is not before the start.")
+    }
+}
+
+impl JsonSchema for TimeRange {
+    fn schema_name() -> Cow<'static, str> {
+        "TimeRange".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "date_range::TimeRange".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        bounds::<NaiveTime>(generator, "A range of times of day. An end before the start runs past midnight.")
+    }
+}
+
+/// The schema of the default `{"start": ..., "end": ...}` representation.
+fn bounds<T: JsonSchema>(generator: &mut SchemaGenerator, description: &str) -> Schema {
+    let bound = generator.subschema_for::<T>();
+    json_schema!({
+        "description": description,
+        "type": "object",
+        "properties": {
+            "start": bound,
+            "end": bound,
+        },
+        "required": ["start", "end"],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::daterange::date_range::DateRange;
+    use crate::datetimerange::date_time_range::DateTimeRange;
+    use crate::timerange::time_range::TimeRange;
+    use schemars::{JsonSchema, schema_for};
+    use serde_json::json;
+
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct Shift {
+        scheduled: TimeRange,
+        worked: DateTimeRange,
+        pay_period: DateRange,
+    }
+
+    #[test]
+    fn schemas_describe_the_default_representation() {
+        let schema = schema_for!(DateRange).to_value();
+        assert_eq!(schema["title"], "DateRange");
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["required"], json!(["start", "end"]));
+        assert_eq!(schema["properties"]["start"], json!({"type": "string", "format": "date"}));
+        assert_eq!(schema["properties"]["end"], schema["properties"]["start"]);
+
+        let schema = schema_for!(DateTimeRange).to_value();
+        assert_eq!(schema["properties"]["start"]["format"], "partial-date-time");
+        let schema = schema_for!(TimeRange).to_value();
+        assert_eq!(schema["properties"]["end"]["format"], "partial-time");
+    }
+
+    #[test]
+    fn ranges_are_embedded_as_definitions() {
+        let schema = schema_for!(Shift).to_value();
+        assert_eq!(schema["properties"]["pay_period"], json!({"$ref": "#/$defs/DateRange"}));
+        let definitions = schema["$defs"].as_object().unwrap();
+        let mut names: Vec<&String> = definitions.keys().collect();
+        names.sort();
+        assert_eq!(names, ["DateRange", "DateTimeRange", "TimeRange"]);
+        assert_eq!(definitions["TimeRange"]["required"], json!(["start", "end"]));
+        assert!(definitions.values().all(|definition| definition["description"].is_string()));
+    }
+}
@@ -0,0 +1,49 @@
+//! Serde support for the range types, behind the `serde` feature.
+//!
+//! Ranges serialize as objects holding their inclusive bounds, which is the canonical JSON
+//! shape shared by every service that exchanges periods:
+//!
+//! - `DateRange`: `{"start": "2024-03-01", "end": "2024-03-31"}`
+//! - `DateTimeRange`: `{"start": "2024-03-10T09:00:00", "end": "2024-03-10T17:30:00.250"}`, with
+//!   no offset and with fractional seconds only when present
+//! - `TimeRange`: `{"start": "09:00:00", "end": "17:00:00"}`
+//!
+//! Only the bounds are written, so a deserialized `DateRange` is a plain range without a
+//! frequency; use `period_id` to keep the frequency. Deserializing fails if the end is before
+//! the start, except for a `TimeRange`, which runs past midnight when its end is before its
+//! start. With the `serde_json` feature, `to_json_value` and `from_json_value` convert to and
+//! from these shapes, and with the `schemars` feature each type implements `JsonSchema` for
+//! them.
+//!
+//! The modules for each type hold other representations for `#[serde(with = "...")]`, so one
+//! struct can match the shape an upstream API expects:
+//!
+//! - `date_range::interval` and `date_time_range::interval`: ISO 8601 interval strings such as
+//!   `"2024-03-01/2024-03-31"`
+//! - `date_time_range::epoch_seconds` and `date_time_range::epoch_millis`: unix timestamps as
+//!   `{"start": 1710061200000, "end": 1710090000000}`, treating the range as UTC
+//! - `time_range::compact`: `"09:00-17:00"`
+//! - `fields` in each module: the default representation, for symmetry
+//!
+//! ```
+//! use date_range::{DateTimeRange, TimeRange};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Shift {
+//!     #[serde(with = "date_range::serde_formats::time_range::compact")]
+//!     scheduled: TimeRange,
+//!     #[serde(with = "date_range::serde_formats::date_time_range::interval")]
+//!     worked: DateTimeRange,
+//!     #[serde(with = "date_range::serde_formats::date_time_range::epoch_millis")]
+//!     recorded: DateTimeRange,
+//! }
+//! ```
+
+pub mod date_range;
+pub mod date_time_range;
+#[cfg(feature = "serde_json")]
+mod json;
+#[cfg(feature = "schemars")]
+mod json_schema;
+pub mod time_range;
@@ -0,0 +1,111 @@
+use crate::timerange::time_range::TimeRange;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for TimeRange {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        fields::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TimeRange {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        fields::deserialize(deserializer)
+    }
+}
+
+/// The default representation, `{"start": "09:00:00", "end": "17:00:00"}`. The end may be
+/// before the start for ranges that run past midnight.
+pub mod fields {
+    use crate::timerange::time_range::TimeRange;
+    use chrono::NaiveTime;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Bounds {
+        start: NaiveTime,
+        end: NaiveTime,
+    }
+
+    pub fn serialize<S: Serializer>(range: &TimeRange, serializer: S) -> Result<S::Ok, S::Error> {
+        Bounds { start: range.start(), end: range.end() }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TimeRange, D::Error> {
+        let Bounds { start, end } = Bounds::deserialize(deserializer)?;
+        Ok(TimeRange::of(start, end))
+    }
+}
+
+/// A compact string, `"09:00-17:00"`, with seconds and fractional seconds only when present.
+/// The end may be before the start for ranges that run past midnight, as in `"22:00-06:00"`.
+pub mod compact {
+    use crate::timerange::time_range::TimeRange;
+    use chrono::format::{DelayedFormat, StrftimeItems};
+    use chrono::{NaiveTime, Timelike};
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use alloc::string::String;
+
+    fn format(time: NaiveTime) -> DelayedFormat<StrftimeItems<'static>> {
+        if time.second() == 0 && time.nanosecond() == 0 { time.format("%H:%M") } else { time.format("%H:%M:%S%.f") }
+    }
+
+    pub fn serialize<S: Serializer>(range: &TimeRange, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{}-{}", format(range.start()), format(range.end())))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TimeRange, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        let invalid = || D::Error::custom("expected a time range such as 09:00-17:00");
+        let (start, end) = value.split_once('-').ok_or_else(invalid)?;
+        let start: NaiveTime = start.parse().map_err(|_| invalid())?;
+        let end: NaiveTime = end.parse().map_err(|_| invalid())?;
+        Ok(TimeRange::of(start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::timerange::time_range::TimeRange;
+    use chrono::NaiveTime;
+    use serde::{Deserialize, Serialize};
+
+    fn t(h: u32, m: u32, s: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, s).expect("invalid time")
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Shift {
+        #[serde(with = "super::compact")]
+        hours: TimeRange,
+        #[serde(with = "super::fields")]
+        break_time: TimeRange,
+    }
+
+    #[test]
+    fn serializes_the_bounds_by_default() {
+        let hours = TimeRange::of(t(9, 0, 0), t(17, 0, 0));
+        let json = serde_json::to_string(&hours).unwrap();
+        assert_eq!(json, r#"{"start":"09:00:00","end":"17:00:00"}"#);
+        assert_eq!(serde_json::from_str::<TimeRange>(&json).unwrap(), hours);
+    }
+
+    #[test]
+    fn helper_modules_select_the_representation_per_field() {
+        let shift = Shift {
+            hours: TimeRange::of(t(22, 0, 0), t(6, 0, 30)),
+            break_time: TimeRange::of(t(2, 0, 0), t(2, 30, 0)),
+        };
+        let json = serde_json::to_string(&shift).unwrap();
+        assert_eq!(json, r#"{"hours":"22:00-06:00:30","break_time":{"start":"02:00:00","end":"02:30:00"}}"#);
+        assert_eq!(serde_json::from_str::<Shift>(&json).unwrap(), shift);
+    }
+
+    #[test]
+    fn compact_strings_must_hold_two_times() {
+        for bad in [r#""09:00""#, r#""09:00-""#, r#""9am-5pm""#, r#""09:00/17:00""#] {
+            let json = format!(r#"{{"hours":{},"break_time":{{"start":"02:00:00","end":"02:30:00"}}}}"#, bad);
+            assert!(serde_json::from_str::<Shift>(&json).is_err(), "{}", bad);
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::daterange::date_range::DateRange;
+use crate::datetimerange::date_time_range::DateTimeRange;
+use crate::sql::pg_range::{
+    date_range_bounds, date_range_from_bounds, date_time_range_bounds, date_time_range_from_bounds,
+};
+use chrono::{NaiveDate, NaiveDateTime};
+use diesel::deserialize::{self, FromSql};
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::{Daterange, Tsrange};
+use core::ops::Bound;
+
+impl ToSql<Daterange, Pg> for DateRange {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        ToSql::<Daterange, Pg>::to_sql(&date_range_bounds(self), &mut out.reborrow())
+    }
+}
+
+impl FromSql<Daterange, Pg> for DateRange {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let (start, end) = <(Bound<NaiveDate>, Bound<NaiveDate>) as FromSql<Daterange, Pg>>::from_sql(value)?;
+        Ok(date_range_from_bounds(start, end)?)
+    }
+}
+
+impl ToSql<Tsrange, Pg> for DateTimeRange {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        ToSql::<Tsrange, Pg>::to_sql(&date_time_range_bounds(self), &mut out.reborrow())
+    }
+}
+
+impl FromSql<Tsrange, Pg> for DateTimeRange {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let (start, end) = <(Bound<NaiveDateTime>, Bound<NaiveDateTime>) as FromSql<Tsrange, Pg>>::from_sql(value)?;
+        Ok(date_time_range_from_bounds(start, end)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::deserialize::Queryable;
+    use diesel::prelude::*;
+
+    diesel::table! {
+        pay_periods (id) {
+            id -> Int4,
+            period -> Daterange,
+            worked -> Nullable<Tsrange>,
+        }
+    }
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    fn queryable<ST, T: Queryable<ST, Pg>>() {}
+
+    #[test]
+    fn ranges_bind_to_postgres_range_columns() {
+        queryable::<Daterange, DateRange>();
+        queryable::<Tsrange, DateTimeRange>();
+
+        let period = DateRange::new(d(2024, 3, 1), d(2024, 3, 15));
+        let worked = DateTimeRange::of(
+            d(2024, 3, 1).and_hms_opt(9, 0, 0).unwrap(),
+            d(2024, 3, 15).and_hms_opt(17, 0, 0).unwrap(),
+        );
+        let insert = diesel::insert_into(pay_periods::table)
+            .values((pay_periods::id.eq(1), pay_periods::period.eq(period), pay_periods::worked.eq(worked)));
+        let sql = diesel::debug_query::<Pg, _>(&insert).to_string();
+        assert!(sql.starts_with(r#"INSERT INTO "pay_periods" ("id", "period", "worked") VALUES ($1, $2, $3)"#));
+
+        let select = pay_periods::table.select(pay_periods::period).filter(pay_periods::period.eq(period));
+        assert!(diesel::debug_query::<Pg, _>(&select).to_string().contains(r#"WHERE ("pay_periods"."period" = $1)"#));
+    }
+}
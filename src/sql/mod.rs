@@ -0,0 +1,23 @@
+//! Database column mappings for the range types.
+//!
+//! - `sqlx-postgres`: `DateRange` is a Postgres `daterange` and `DateTimeRange` is a `tsrange`
+//!   in `sqlx` queries.
+//! - `diesel`: `DateRange` is a `Daterange` and `DateTimeRange` is a `Tsrange` in Diesel
+//!   queries against Postgres.
+//! - `rusqlite`: `DateRange` and `DateTimeRange` are ISO 8601 interval text in SQLite, and
+//!   `sqlite` has helpers for ranges stored as separate start and end columns.
+//!
+//! Postgres stores a `daterange` as `[start, end)`, so a range read back from Postgres may have
+//! different bounds in the database than were written, but the same dates. Decoding converts
+//! exclusive bounds to the inclusive bounds the range types use, by a day for dates and by a
+//! microsecond, the `timestamp` resolution, for datetimes. Empty and unbounded ranges fail to
+//! decode.
+
+#[cfg(feature = "diesel")]
+pub mod diesel_postgres;
+#[cfg(any(feature = "diesel", feature = "sqlx-postgres"))]
+mod pg_range;
+#[cfg(feature = "rusqlite")]
+pub mod sqlite;
+#[cfg(feature = "sqlx-postgres")]
+pub mod sqlx_postgres;
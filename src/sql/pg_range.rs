@@ -0,0 +1,122 @@
+use crate::daterange::date_range::DateRange;
+use crate::datetimerange::date_time_range::DateTimeRange;
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+use core::ops::Bound;
+
+/// Both bounds are written inclusive; Postgres canonicalizes the end to the following day.
+/// Writing `end + 1` here would overflow on `NaiveDate::MAX`.
+pub(super) fn date_range_bounds(range: &DateRange) -> (Bound<NaiveDate>, Bound<NaiveDate>) {
+    (Bound::Included(range.start_date()), Bound::Included(range.end_date()))
+}
+
+pub(super) fn date_range_from_bounds(
+    start: Bound<NaiveDate>,
+    end: Bound<NaiveDate>,
+) -> Result<DateRange, &'static str> {
+    let start = match start {
+        Bound::Included(start) => Some(start),
+        Bound::Excluded(start) => start.succ_opt(),
+        Bound::Unbounded => return Err("the range is empty or has no lower bound"),
+    };
+    let end = match end {
+        Bound::Included(end) => Some(end),
+        Bound::Excluded(end) => end.pred_opt(),
+        Bound::Unbounded => return Err("the range is empty or has no upper bound"),
+    };
+    match (start, end) {
+        (Some(start), Some(end)) if start <= end => Ok(DateRange::new(start, end)),
+        _ => Err("the range contains no dates"),
+    }
+}
+
+pub(super) fn date_time_range_bounds(range: &DateTimeRange) -> (Bound<NaiveDateTime>, Bound<NaiveDateTime>) {
+    (Bound::Included(range.start()), Bound::Included(range.end()))
+}
+
+pub(super) fn date_time_range_from_bounds(
+    start: Bound<NaiveDateTime>,
+    end: Bound<NaiveDateTime>,
+) -> Result<DateTimeRange, &'static str> {
+    let tick = Duration::microseconds(1);
+    let start = match start {
+        Bound::Included(start) => Some(start),
+        Bound::Excluded(start) => start.checked_add_signed(tick),
+        Bound::Unbounded => return Err("the range is empty or has no lower bound"),
+    };
+    let end = match end {
+        Bound::Included(end) => Some(end),
+        Bound::Excluded(end) => end.checked_sub_signed(tick),
+        Bound::Unbounded => return Err("the range is empty or has no upper bound"),
+    };
+    match (start, end) {
+        (Some(start), Some(end)) if start <= end => Ok(DateTimeRange::of(start, end)),
+        _ => Err("the range contains no instants"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ops::Bound::{Excluded, Included, Unbounded};
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    fn dt(y: i32, m: u32, day: u32, h: u32, min: u32) -> NaiveDateTime {
+        d(y, m, day).and_hms_opt(h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn canonical_daterange_bounds_become_inclusive() {
+        let range = date_range_from_bounds(Included(d(2024, 3, 1)), Excluded(d(2024, 4, 1))).unwrap();
+        assert_eq!((range.start_date(), range.end_date()), (d(2024, 3, 1), d(2024, 3, 31)));
+
+        let range = date_range_from_bounds(Excluded(d(2024, 2, 29)), Included(d(2024, 3, 31))).unwrap();
+        assert_eq!((range.start_date(), range.end_date()), (d(2024, 3, 1), d(2024, 3, 31)));
+
+        let (start, end) = date_range_bounds(&range);
+        assert_eq!((start, end), (Included(d(2024, 3, 1)), Included(d(2024, 3, 31))));
+        let round_trip = date_range_from_bounds(start, end).unwrap();
+        assert_eq!((round_trip.start_date(), round_trip.end_date()), (d(2024, 3, 1), d(2024, 3, 31)));
+
+        let max = DateRange::new(NaiveDate::MAX, NaiveDate::MAX);
+        assert_eq!(date_range_bounds(&max), (Included(NaiveDate::MAX), Included(NaiveDate::MAX)));
+    }
+
+    #[test]
+    fn empty_and_unbounded_dateranges_are_rejected() {
+        assert!(date_range_from_bounds(Unbounded, Unbounded).is_err());
+        assert!(date_range_from_bounds(Included(d(2024, 3, 1)), Unbounded).is_err());
+        assert!(date_range_from_bounds(Included(d(2024, 3, 1)), Excluded(d(2024, 3, 1))).is_err());
+        assert!(date_range_from_bounds(Excluded(d(2024, 3, 1)), Excluded(d(2024, 3, 1))).is_err());
+        assert!(date_range_from_bounds(Excluded(NaiveDate::MAX), Included(NaiveDate::MAX)).is_err());
+    }
+
+    #[test]
+    fn tsrange_exclusive_bounds_move_by_a_microsecond() {
+        let start = dt(2024, 3, 10, 9, 0);
+        let end = dt(2024, 3, 10, 17, 0);
+        let tick = Duration::microseconds(1);
+
+        let range = date_time_range_from_bounds(Included(start), Excluded(end)).unwrap();
+        assert_eq!((range.start(), range.end()), (start, end - tick));
+
+        let range = date_time_range_from_bounds(Excluded(start), Included(end)).unwrap();
+        assert_eq!((range.start(), range.end()), (start + tick, end));
+
+        let (lower, upper) = date_time_range_bounds(&DateTimeRange::of(start, end));
+        assert_eq!((lower, upper), (Included(start), Included(end)));
+        let round_trip = date_time_range_from_bounds(lower, upper).unwrap();
+        assert_eq!((round_trip.start(), round_trip.end()), (start, end));
+    }
+
+    #[test]
+    fn empty_and_unbounded_tsranges_are_rejected() {
+        let start = dt(2024, 3, 10, 9, 0);
+        assert!(date_time_range_from_bounds(Unbounded, Unbounded).is_err());
+        assert!(date_time_range_from_bounds(Unbounded, Excluded(start)).is_err());
+        assert!(date_time_range_from_bounds(Included(start), Excluded(start)).is_err());
+        assert!(date_time_range_from_bounds(Excluded(start), Excluded(start)).is_err());
+    }
+}
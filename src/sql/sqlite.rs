@@ -0,0 +1,182 @@
+//! A range is stored in one `TEXT` column as an ISO 8601 interval, `2024-03-01/2024-03-31` or
+//! `2024-03-10T09:00:00/2024-03-10T17:30:00.250`, so ranges with the same start sort together.
+//! For tables that keep the bounds in separate columns, the `*_columns` functions give the
+//! parameters to write and the `*_from_columns` functions read a row back, using rusqlite's
+//! `chrono` representation for each bound so SQLite's date functions still apply to them.
+
+use crate::daterange::date_range::DateRange;
+use crate::daterange::range_error::RangeError;
+use crate::datetimerange::date_time_range::DateTimeRange;
+use chrono::{NaiveDate, NaiveDateTime};
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, Type, ValueRef};
+use rusqlite::{Row, RowIndex};
+use alloc::format;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+
+const ISO_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+impl ToSql for DateRange {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_string()))
+    }
+}
+
+impl FromSql for DateRange {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        value.as_str()?.parse().map_err(|err| FromSqlError::Other(Box::new(err)))
+    }
+}
+
+impl ToSql for DateTimeRange {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(format!("{}/{}", self.start().format(ISO_FORMAT), self.end().format(ISO_FORMAT))))
+    }
+}
+
+impl FromSql for DateTimeRange {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let value = value.as_str()?;
+        let invalid = || FromSqlError::Other(Box::new(RangeError::InvalidInterval(value.to_string())));
+        let (start, end) = value.split_once('/').ok_or_else(invalid)?;
+        let start: NaiveDateTime = start.parse().map_err(|_| invalid())?;
+        let end: NaiveDateTime = end.parse().map_err(|_| invalid())?;
+        if end < start {
+            return Err(invalid());
+        }
+        Ok(DateTimeRange::of(start, end))
+    }
+}
+
+/// The start and end of `range`, to bind to separate columns.
+pub fn date_range_columns(range: &DateRange) -> (NaiveDate, NaiveDate) {
+    (range.start_date(), range.end_date())
+}
+
+/// Read a `DateRange` from separate start and end columns. Fails if the end is before the start.
+pub fn date_range_from_columns<I: RowIndex + Copy>(row: &Row<'_>, start: I, end: I) -> rusqlite::Result<DateRange> {
+    let (first, last): (NaiveDate, NaiveDate) = (row.get(start)?, row.get(end)?);
+    if last < first {
+        return Err(inverted(row, end, format!("{first}/{last}")));
+    }
+    Ok(DateRange::new(first, last))
+}
+
+/// The start and end of `range`, to bind to separate columns.
+pub fn date_time_range_columns(range: &DateTimeRange) -> (NaiveDateTime, NaiveDateTime) {
+    (range.start(), range.end())
+}
+
+/// Read a `DateTimeRange` from separate start and end columns. Fails if the end is before the
+/// start.
+pub fn date_time_range_from_columns<I: RowIndex + Copy>(
+    row: &Row<'_>,
+    start: I,
+    end: I,
+) -> rusqlite::Result<DateTimeRange> {
+    let (first, last): (NaiveDateTime, NaiveDateTime) = (row.get(start)?, row.get(end)?);
+    if last < first {
+        return Err(inverted(row, end, format!("{}/{}", first.format(ISO_FORMAT), last.format(ISO_FORMAT))));
+    }
+    Ok(DateTimeRange::of(first, last))
+}
+
+fn inverted<I: RowIndex>(row: &Row<'_>, end: I, interval: String) -> rusqlite::Error {
+    match end.idx(row.as_ref()) {
+        Ok(idx) => {
+            rusqlite::Error::FromSqlConversionFailure(idx, Type::Text, Box::new(RangeError::InvalidInterval(interval)))
+        }
+        Err(err) => err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::Connection;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    fn connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE periods (id INTEGER PRIMARY KEY, period TEXT, start TEXT, end TEXT);",
+        )
+        .unwrap();
+        conn
+    }
+
+    #[test]
+    fn ranges_round_trip_as_interval_text() {
+        let conn = connection();
+        let period = DateRange::new(d(2024, 3, 1), d(2024, 3, 31));
+        conn.execute("INSERT INTO periods (id, period) VALUES (1, ?1)", [&period]).unwrap();
+        let text: String = conn.query_row("SELECT period FROM periods", [], |row| row.get(0)).unwrap();
+        assert_eq!(text, "2024-03-01/2024-03-31");
+        let read: DateRange = conn.query_row("SELECT period FROM periods", [], |row| row.get(0)).unwrap();
+        assert_eq!((read.start_date(), read.end_date()), (d(2024, 3, 1), d(2024, 3, 31)));
+
+        let start = d(2024, 3, 10).and_hms_milli_opt(9, 0, 0, 250).unwrap();
+        let shift = DateTimeRange::of(start, d(2024, 3, 10).and_hms_opt(17, 30, 0).unwrap());
+        conn.execute("UPDATE periods SET period = ?1", [&shift]).unwrap();
+        let text: String = conn.query_row("SELECT period FROM periods", [], |row| row.get(0)).unwrap();
+        assert_eq!(text, "2024-03-10T09:00:00.250/2024-03-10T17:30:00");
+        let read: DateTimeRange = conn.query_row("SELECT period FROM periods", [], |row| row.get(0)).unwrap();
+        assert_eq!((read.start(), read.end()), (shift.start(), shift.end()));
+    }
+
+    #[test]
+    fn malformed_and_inverted_interval_text_is_rejected() {
+        let conn = connection();
+        for text in ["2024-03-31/2024-03-01", "2024-03-01", "march"] {
+            conn.execute("INSERT OR REPLACE INTO periods (id, period) VALUES (1, ?1)", [text]).unwrap();
+            let read = conn.query_row("SELECT period FROM periods", [], |row| row.get::<_, DateRange>(0));
+            assert!(matches!(read, Err(rusqlite::Error::FromSqlConversionFailure(0, Type::Text, _))), "{text}");
+            let read = conn.query_row("SELECT period FROM periods", [], |row| row.get::<_, DateTimeRange>(0));
+            assert!(matches!(read, Err(rusqlite::Error::FromSqlConversionFailure(0, Type::Text, _))), "{text}");
+        }
+    }
+
+    #[test]
+    fn ranges_round_trip_through_two_columns() {
+        let conn = connection();
+        let (start, end) = date_range_columns(&DateRange::new(d(2024, 3, 1), d(2024, 3, 31)));
+        conn.execute("INSERT INTO periods (id, start, end) VALUES (1, ?1, ?2)", (start, end)).unwrap();
+        let read = conn.query_row("SELECT start, end FROM periods", [], |row| date_range_from_columns(row, 0, 1));
+        let read = read.unwrap();
+        assert_eq!((read.start_date(), read.end_date()), (d(2024, 3, 1), d(2024, 3, 31)));
+        let days: i64 = conn.query_row("SELECT julianday(end) - julianday(start) FROM periods", [], |row| {
+            row.get::<_, f64>(0).map(|days| days as i64)
+        })
+        .unwrap();
+        assert_eq!(days, 30);
+
+        let shift = DateTimeRange::of(
+            d(2024, 3, 10).and_hms_opt(22, 0, 0).unwrap(),
+            d(2024, 3, 11).and_hms_opt(6, 0, 0).unwrap(),
+        );
+        let (start, end) = date_time_range_columns(&shift);
+        conn.execute("UPDATE periods SET start = ?1, end = ?2", (start, end)).unwrap();
+        let read = conn.query_row("SELECT start, end FROM periods", [], |row| {
+            date_time_range_from_columns(row, "start", "end")
+        });
+        let read = read.unwrap();
+        assert_eq!((read.start(), read.end()), (shift.start(), shift.end()));
+    }
+
+    #[test]
+    fn inverted_columns_report_the_end_column() {
+        let conn = connection();
+        conn.execute("INSERT INTO periods (id, start, end) VALUES (1, '2024-03-31', '2024-03-01')", []).unwrap();
+        let read = conn.query_row("SELECT id, start, end FROM periods", [], |row| {
+            date_range_from_columns(row, "start", "end")
+        });
+        assert!(matches!(read, Err(rusqlite::Error::FromSqlConversionFailure(2, Type::Text, _))));
+        let read = conn.query_row("SELECT id, start, end FROM periods", [], |row| {
+            date_range_from_columns(row, "start", "missing")
+        });
+        assert!(matches!(read, Err(rusqlite::Error::InvalidColumnName(_))));
+    }
+}
@@ -0,0 +1,91 @@
+use crate::daterange::date_range::DateRange;
+use crate::datetimerange::date_time_range::DateTimeRange;
+use crate::sql::pg_range::{
+    date_range_bounds, date_range_from_bounds, date_time_range_bounds, date_time_range_from_bounds,
+};
+use chrono::{NaiveDate, NaiveDateTime};
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::types::PgRange;
+use sqlx::postgres::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef};
+use sqlx::{Decode, Encode, Postgres, Type};
+
+impl Type<Postgres> for DateRange {
+    fn type_info() -> PgTypeInfo {
+        PgRange::<NaiveDate>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        PgRange::<NaiveDate>::compatible(ty)
+    }
+}
+
+impl PgHasArrayType for DateRange {
+    fn array_type_info() -> PgTypeInfo {
+        PgRange::<NaiveDate>::array_type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for DateRange {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        PgRange::from(date_range_bounds(self)).encode_by_ref(buf)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for DateRange {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let range = PgRange::<NaiveDate>::decode(value)?;
+        Ok(date_range_from_bounds(range.start, range.end)?)
+    }
+}
+
+impl Type<Postgres> for DateTimeRange {
+    fn type_info() -> PgTypeInfo {
+        PgRange::<NaiveDateTime>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        PgRange::<NaiveDateTime>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for DateTimeRange {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        PgRange::from(date_time_range_bounds(self)).encode_by_ref(buf)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for DateTimeRange {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let range = PgRange::<NaiveDateTime>::decode(value)?;
+        Ok(date_time_range_from_bounds(range.start, range.end)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    fn encoded<'q, T: Encode<'q, Postgres>>(value: &T) -> Vec<u8> {
+        let mut buf = PgArgumentBuffer::default();
+        assert!(matches!(value.encode_by_ref(&mut buf).unwrap(), IsNull::No));
+        buf.to_vec()
+    }
+
+    #[test]
+    fn ranges_encode_as_inclusive_postgres_ranges() {
+        assert_eq!(DateRange::type_info(), PgRange::<NaiveDate>::type_info());
+        assert_eq!(DateTimeRange::type_info(), PgRange::<NaiveDateTime>::type_info());
+
+        let range = DateRange::new(d(2024, 3, 1), d(2024, 3, 31));
+        assert_eq!(encoded(&range), encoded(&PgRange::from(d(2024, 3, 1)..=d(2024, 3, 31))));
+
+        let start = d(2024, 3, 10).and_hms_opt(9, 0, 0).unwrap();
+        let end = d(2024, 3, 10).and_hms_opt(17, 0, 0).unwrap();
+        assert_eq!(encoded(&DateTimeRange::of(start, end)), encoded(&PgRange::from(start..=end)));
+    }
+}
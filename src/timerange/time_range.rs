@@ -1,6 +1,8 @@
-use chrono::{Duration, NaiveTime};
-use std::cmp::Ordering;
-use std::hash::{Hash, Hasher};
+use crate::timeutils::time_utils::{minute_of_day, time_to_period_index};
+use chrono::{Duration, NaiveTime, Timelike};
+use core::ops::Range;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
 
 #[derive(Debug, Clone)]
 pub struct TimeRange {
@@ -25,6 +27,23 @@ impl TimeRange {
         self.end - self.start
     }
 
+    /// Get a copy of the range with the seconds and fractional seconds of both bounds set to zero.
+    pub fn truncate_to_minutes(&self) -> TimeRange {
+        let truncate = |t: NaiveTime| t.with_second(0).unwrap().with_nanosecond(0).unwrap();
+        Self::of(truncate(self.start), truncate(self.end))
+    }
+
+    /// Get the indexes of the periods of `period_minutes` from midnight that the range starts in
+    /// up to the one it ends in, exclusive. Ranges that end before their start run past
+    /// midnight, so their indexes continue past the end of the day. An empty range has none.
+    pub fn period_indexes(&self, period_minutes: i32) -> Range<i32> {
+        let mut end_minute = minute_of_day(self.end);
+        if self.end < self.start {
+            end_minute += 1440;
+        }
+        time_to_period_index(self.start, period_minutes)..end_minute / period_minutes
+    }
+
     pub fn overlaps(&self, other: &TimeRange) -> bool {
         let midnight = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
 
@@ -97,6 +116,27 @@ mod tests {
         assert_eq!(tr.duration(), Duration::minutes(150));
     }
 
+    #[test]
+    fn truncate_to_minutes_zeroes_seconds_and_fractions() {
+        let start = NaiveTime::from_hms_milli_opt(8, 59, 59, 500).unwrap();
+        let tr = TimeRange::of(start, t(17, 30, 15));
+        let truncated = tr.truncate_to_minutes();
+        assert_eq!(truncated, TimeRange::of(t(8, 59, 0), t(17, 30, 0)));
+    }
+
+    #[test]
+    fn period_indexes_continue_past_midnight() {
+        assert_eq!(TimeRange::of(t(9, 0, 0), t(10, 30, 0)).period_indexes(15), 36..42);
+        assert_eq!(TimeRange::of(t(22, 0, 0), t(6, 0, 0)).period_indexes(60), 22..30);
+        assert_eq!(TimeRange::of(t(22, 0, 0), t(0, 0, 0)).period_indexes(30), 44..48);
+    }
+
+    #[test]
+    fn period_indexes_of_an_empty_range_are_empty() {
+        assert!(TimeRange::of(t(9, 0, 0), t(9, 0, 0)).period_indexes(15).is_empty());
+        assert!(TimeRange::of(t(0, 0, 0), t(0, 0, 0)).period_indexes(15).is_empty());
+    }
+
     #[test]
     fn overlaps_basic_true_when_intervals_intersect() {
         let a = TimeRange::of(t(9, 0, 0), t(12, 0, 0));
@@ -168,7 +208,7 @@ mod tests {
 
     #[test]
     fn ordering_is_by_start_then_end() {
-        let mut v = vec![
+        let mut v = [
             TimeRange::of(t(9, 0, 0), t(10, 0, 0)),
             TimeRange::of(t(8, 0, 0), t(9, 0, 0)),
             TimeRange::of(t(9, 0, 0), t(9, 30, 0)),
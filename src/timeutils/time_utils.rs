@@ -0,0 +1,56 @@
+use chrono::{Duration, NaiveTime, Timelike};
+
+const MINUTES_PER_HOUR: i32 = 60;
+const MINUTES_PER_DAY: i32 = 1440;
+
+/// Get the number of whole minutes since midnight.
+pub fn minute_of_day(time: NaiveTime) -> i32 {
+    time.hour() as i32 * MINUTES_PER_HOUR + time.minute() as i32
+}
+
+/// Get the index of the period containing a time, counting periods of `period_minutes` from
+/// midnight. For 15-minute periods 00:00 is index 0 and 09:20 is index 37.
+pub fn time_to_period_index(time: NaiveTime, period_minutes: i32) -> i32 {
+    minute_of_day(time) / period_minutes
+}
+
+/// Get the time a period starts, counting periods of `period_minutes` from midnight. Indexes
+/// past the end of the day wrap around to the following days.
+pub fn period_index_to_time(index: i32, period_minutes: i32) -> NaiveTime {
+    let minutes = (index as i64 * period_minutes as i64).rem_euclid(MINUTES_PER_DAY as i64);
+    NaiveTime::MIN + Duration::minutes(minutes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn t(h: u32, m: u32, s: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, s).unwrap()
+    }
+
+    #[rstest]
+    #[case(t(0, 0, 0), 15, 0)]
+    #[case(t(9, 20, 0), 15, 37)]
+    #[case(t(9, 29, 59), 30, 18)]
+    #[case(t(23, 59, 0), 60, 23)]
+    fn test_time_to_period_index(#[case] time: NaiveTime, #[case] period_minutes: i32, #[case] expected: i32) {
+        assert_eq!(time_to_period_index(time, period_minutes), expected);
+    }
+
+    #[rstest]
+    #[case(0, 15, t(0, 0, 0))]
+    #[case(37, 15, t(9, 15, 0))]
+    #[case(48, 30, t(0, 0, 0))]
+    #[case(50, 30, t(1, 0, 0))]
+    #[case(-1, 15, t(23, 45, 0))]
+    fn test_period_index_to_time(#[case] index: i32, #[case] period_minutes: i32, #[case] expected: NaiveTime) {
+        assert_eq!(period_index_to_time(index, period_minutes), expected);
+    }
+
+    #[test]
+    fn test_minute_of_day() {
+        assert_eq!(minute_of_day(t(8, 15, 30)), 495);
+    }
+}